@@ -10,6 +10,7 @@ use crate::cli_backend::{OutputFormat, PromptMode};
 use nix::sys::signal::{Signal, kill};
 #[cfg(unix)]
 use nix::unistd::Pid;
+use std::collections::HashMap;
 use std::io::Write;
 use std::process::Stdio;
 use std::time::Duration;
@@ -34,12 +35,22 @@ pub struct ExecutionResult {
 #[derive(Debug)]
 pub struct CliExecutor {
     backend: CliBackend,
+    env: HashMap<String, String>,
 }
 
 impl CliExecutor {
     /// Creates a new executor with the given backend.
     pub fn new(backend: CliBackend) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            env: HashMap::new(),
+        }
+    }
+
+    /// Sets extra environment variables for the spawned backend process.
+    pub fn with_env(mut self, env: HashMap<String, String>) -> Self {
+        self.env = env;
+        self
     }
 
     /// Executes a prompt and streams output to the provided writer.
@@ -63,6 +74,7 @@ impl CliExecutor {
 
         let mut command = Command::new(&cmd);
         command.args(&args);
+        command.envs(&self.env);
         command.stdout(Stdio::piped());
         command.stderr(Stdio::piped());
 
@@ -258,6 +270,29 @@ mod tests {
         assert!(result.output.contains("hello world"));
     }
 
+    #[tokio::test]
+    async fn test_execute_sets_env_overrides() {
+        let backend = CliBackend {
+            command: "sh".to_string(),
+            args: vec!["-c".to_string()],
+            prompt_mode: PromptMode::Arg,
+            prompt_flag: None,
+            output_format: OutputFormat::Text,
+        };
+
+        let executor = CliExecutor::new(backend).with_env(HashMap::from([(
+            "RALPH_TEST_PORT".to_string(),
+            "8081".to_string(),
+        )]));
+        let result = executor
+            .execute_capture("echo port=$RALPH_TEST_PORT")
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert!(result.output.contains("port=8081"));
+    }
+
     #[tokio::test]
     async fn test_execute_stdin() {
         // Use cat to test stdin mode
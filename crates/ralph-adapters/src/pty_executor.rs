@@ -27,6 +27,7 @@ use nix::sys::signal::{Signal, kill};
 #[cfg(unix)]
 use nix::unistd::Pid;
 use portable_pty::{CommandBuilder, PtyPair, PtySize, native_pty_system};
+use std::collections::HashMap;
 use std::io::{self, Read, Write};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -178,6 +179,8 @@ impl Default for CtrlCState {
 pub struct PtyExecutor {
     backend: CliBackend,
     config: PtyConfig,
+    // Extra environment variables for the spawned backend process
+    env: HashMap<String, String>,
     // Channel ends for TUI integration
     output_tx: mpsc::UnboundedSender<Vec<u8>>,
     output_rx: Option<mpsc::UnboundedReceiver<Vec<u8>>>,
@@ -205,6 +208,7 @@ impl PtyExecutor {
         Self {
             backend,
             config,
+            env: HashMap::new(),
             output_tx,
             output_rx: Some(output_rx),
             input_tx: Some(input_tx),
@@ -240,6 +244,11 @@ impl PtyExecutor {
         self.backend = backend;
     }
 
+    /// Sets extra environment variables for the spawned backend process.
+    pub fn set_env(&mut self, env: HashMap<String, String>) {
+        self.env = env;
+    }
+
     /// Returns a handle for TUI integration.
     ///
     /// Can only be called once - panics if called multiple times.
@@ -292,6 +301,9 @@ impl PtyExecutor {
 
         // Set up environment for PTY
         cmd_builder.env("TERM", "xterm-256color");
+        for (key, value) in &self.env {
+            cmd_builder.env(key, value);
+        }
         let child = pair
             .slave
             .spawn_command(cmd_builder)
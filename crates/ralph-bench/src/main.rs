@@ -12,8 +12,8 @@ use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
 use ralph_adapters::{CliBackend, CliExecutor, detect_backend};
 use ralph_core::{
-    CleanupPolicy, CliCapture, EventLoop, PlayerConfig, RalphConfig, ReplayMode, SessionPlayer,
-    TaskSuite, TerminationReason, WorkspaceManager,
+    CleanupPolicy, CliCapture, EventLoop, PlayerConfig, RalphConfig, ReplayColorMode, ReplayMode,
+    SessionPlayer, TaskSuite, TerminationReason, WorkspaceManager,
 };
 use ralph_proto::FrameCapture;
 use std::fs::{self, File};
@@ -61,7 +61,7 @@ enum Commands {
         #[arg(long)]
         task: Option<String>,
 
-        /// Cleanup policy: rotate, on_success, always, never
+        /// Cleanup policy: rotate, on_success, always, never, on_age, on_disk_pressure, larger_than, idle_longer_than
         #[arg(long, default_value = "on_success")]
         cleanup: String,
 
@@ -79,6 +79,10 @@ enum Commands {
         #[arg(long, value_enum, default_value = "terminal")]
         ux_mode: UxMode,
 
+        /// Color handling in terminal mode: preserve, strip, ansi256
+        #[arg(long, value_enum, default_value = "preserve")]
+        color: UxColor,
+
         /// Playback speed multiplier (e.g., 2.0 for 2x speed)
         #[arg(long, default_value = "1.0")]
         speed: f32,
@@ -122,6 +126,27 @@ impl From<UxMode> for ReplayMode {
     }
 }
 
+/// Color handling for terminal replay
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum UxColor {
+    /// Keep colors as recorded
+    Preserve,
+    /// Remove color codes
+    Strip,
+    /// Downsample truecolor to 256 colors
+    Ansi256,
+}
+
+impl From<UxColor> for ReplayColorMode {
+    fn from(color: UxColor) -> Self {
+        match color {
+            UxColor::Preserve => ReplayColorMode::Preserve,
+            UxColor::Strip => ReplayColorMode::Strip,
+            UxColor::Ansi256 => ReplayColorMode::Ansi256,
+        }
+    }
+}
+
 /// What to list
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 enum ListTarget {
@@ -165,10 +190,11 @@ async fn main() -> Result<()> {
         Commands::Replay {
             session,
             ux_mode,
+            color,
             speed,
             step,
             filter,
-        } => cmd_replay(session, ux_mode, speed, step, filter),
+        } => cmd_replay(session, ux_mode, color, speed, step, filter),
         Commands::List { what, dir } => cmd_list(what, dir),
     }
 }
@@ -268,10 +294,12 @@ async fn cmd_run(
             );
         } else {
             tracing::warn!(
-                "Task '{}' verification: {}\nstderr: {}",
+                "Task '{}' verification: {}\noutput:\n{}",
                 task.name,
                 verification_result.summary(),
-                verification_result.stderr.trim()
+                verification_result
+                    .failed_step()
+                    .map_or("", |step| step.output_tail.as_str())
             );
         }
 
@@ -537,6 +565,7 @@ fn format_termination_reason(reason: &TerminationReason) -> String {
 fn cmd_replay(
     session_path: PathBuf,
     ux_mode: UxMode,
+    color: UxColor,
     speed: f32,
     step: bool,
     filter: Option<String>,
@@ -558,6 +587,7 @@ fn cmd_replay(
     // Configure playback
     let mut config = PlayerConfig::default();
     config.replay_mode = ux_mode.into();
+    config.color_mode = color.into();
     config.speed = speed;
     config.step_mode = step;
 
@@ -758,4 +788,20 @@ mod tests {
         assert_eq!(ReplayMode::from(UxMode::Terminal), ReplayMode::Terminal);
         assert_eq!(ReplayMode::from(UxMode::Text), ReplayMode::Text);
     }
+
+    #[test]
+    fn test_ux_color_conversion() {
+        assert_eq!(
+            ReplayColorMode::from(UxColor::Preserve),
+            ReplayColorMode::Preserve
+        );
+        assert_eq!(
+            ReplayColorMode::from(UxColor::Strip),
+            ReplayColorMode::Strip
+        );
+        assert_eq!(
+            ReplayColorMode::from(UxColor::Ansi256),
+            ReplayColorMode::Ansi256
+        );
+    }
 }
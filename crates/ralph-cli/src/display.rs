@@ -373,6 +373,7 @@ mod tests {
             triggered: None,
             payload,
             blocked_count: None,
+            success: None,
         };
 
         print_events_table(&[record], false);
@@ -390,6 +391,7 @@ mod tests {
             triggered: None,
             payload: "ok".to_string(),
             blocked_count: None,
+            success: None,
         };
 
         print_events_table(&[record], false);
@@ -240,7 +240,7 @@ fn push_backend_check<F, G>(
     let label = match status {
         CheckStatus::Pass => format!("{backend} CLI available ({command})"),
         CheckStatus::Warn => format!("{backend} CLI missing (optional for auto)"),
-        CheckStatus::Fail => format!("{backend} CLI missing"),
+        CheckStatus::Fail | CheckStatus::TimedOut => format!("{backend} CLI missing"),
     };
 
     let message = if available {
@@ -500,11 +500,16 @@ fn report_from_checks(checks: Vec<CheckResult>) -> PreflightReport {
         .iter()
         .filter(|check| check.status == CheckStatus::Fail)
         .count();
+    let timed_out = checks
+        .iter()
+        .filter(|check| check.status == CheckStatus::TimedOut)
+        .count();
 
     PreflightReport {
-        passed: failures == 0,
+        passed: failures == 0 && timed_out == 0,
         warnings,
         failures,
+        timed_out,
         checks,
     }
 }
@@ -567,6 +572,7 @@ fn print_check_line(check: &CheckResult, name_width: usize, use_colors: bool) {
         CheckStatus::Pass => ("OK", colors::GREEN),
         CheckStatus::Warn => ("WARN", colors::YELLOW),
         CheckStatus::Fail => ("FAIL", colors::RED),
+        CheckStatus::TimedOut => ("TIME", colors::RED),
     };
 
     let status_padded = format!("{status_text:<4}");
@@ -611,6 +617,7 @@ mod tests {
             backend,
             default_publishes: None,
             max_activations: None,
+            priority: 0,
         }
     }
 
@@ -10,12 +10,13 @@ use ralph_adapters::{
     PrettyStreamHandler, PtyConfig, PtyExecutor, QuietStreamHandler, TuiStreamHandler,
 };
 use ralph_core::{
-    CompletionAction, EventLogger, EventLoop, EventParser, EventRecord, LoopCompletionHandler,
-    LoopContext, LoopHistory, LoopRegistry, MergeQueue, RalphConfig, Record, SessionRecorder,
-    SummaryWriter, TerminationReason,
+    CompletionAction, EventLogger, EventLoop, EventParser, EventRecord, LandingConfig,
+    LoopCompletionHandler, LoopContext, LoopHistory, LoopRegistry, MergeQueue, RalphConfig, Record,
+    SessionRecorder, SummaryWriter, TerminationReason,
 };
 use ralph_proto::{Event, HatId};
 use ralph_tui::Tui;
+use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::fs::{self, File};
 use std::io::{BufWriter, IsTerminal, stdin, stdout};
@@ -99,10 +100,15 @@ pub async fn run_loop_impl(
 
     // Create or use provided loop context for path resolution
     // This ensures events are written to the correct location for worktree loops
-    let ctx = loop_context
+    let mut ctx = loop_context
         .clone()
         .unwrap_or_else(|| LoopContext::primary(config.core.workspace_root.clone()));
 
+    // Restore per-loop environment overrides from a previous run, then persist
+    // the merged set so the next restart sees the same environment.
+    let restarted = std::env::var_os(RESTARTED_ENV).is_some();
+    restore_env_overrides(&mut ctx, resume || restarted);
+
     // Write loop ID to marker file for task ownership tracking.
     // For worktree loops, use the loop_id; for primary loops, generate one.
     // This file is read by `ralph tools task add` to tag new tasks.
@@ -129,6 +135,8 @@ pub async fn run_loop_impl(
 
         debug!("Created events file for this run: {}", relative_events_path);
 
+        clear_stale_loop_state(&ctx, restarted)?;
+
         // Clear scratchpad for fresh objective start
         // Stale content from previous runs can confuse the agent about current task state
         let scratchpad_path = ctx.scratchpad_path();
@@ -230,7 +238,9 @@ pub async fn run_loop_impl(
             workspace_root: config.core.workspace_root.clone(),
             ..PtyConfig::from_env()
         };
-        Some(PtyExecutor::new(backend.clone(), pty_config))
+        let mut executor = PtyExecutor::new(backend.clone(), pty_config);
+        executor.set_env(ctx.env_overrides().clone());
+        Some(executor)
     } else {
         None
     };
@@ -392,6 +402,8 @@ pub async fn run_loop_impl(
         }
     }
 
+    let landing_config = LandingConfig::with_identity(&config.features.landing);
+
     // Helper closure to handle termination (writes summary, prints status, records history)
     let handle_termination = |reason: &TerminationReason,
                               state: &ralph_core::LoopState,
@@ -511,7 +523,8 @@ pub async fn run_loop_impl(
         // Per spec: merge loops do NOT enqueue themselves, even if run in worktree context
         if let Some(ctx) = context {
             if merge_loop_id.is_none() && matches!(reason, TerminationReason::CompletionPromise) {
-                let handler = LoopCompletionHandler::new(auto_merge);
+                let handler = LoopCompletionHandler::new(auto_merge)
+                    .with_landing_config(landing_config.clone());
                 match handler.handle_completion(ctx, prompt) {
                     Ok(CompletionAction::None) => {
                         debug!("Loop completed, no action needed");
@@ -601,11 +614,11 @@ pub async fn run_loop_impl(
                 let _ = killpg(pgid, Signal::SIGKILL);
             }
             let reason = TerminationReason::Interrupted;
-            let terminate_event = event_loop.publish_terminate_event(&reason);
+            let terminate_events = event_loop.publish_terminate_event(&reason);
             log_terminate_event(
                 &mut event_logger,
                 event_loop.state().iteration,
-                &terminate_event,
+                &terminate_events,
             );
             handle_termination(
                 &reason,
@@ -676,11 +689,11 @@ pub async fn run_loop_impl(
         // Check termination before execution
         if let Some(reason) = event_loop.check_termination() {
             // Per spec: Publish loop.terminate event to observers
-            let terminate_event = event_loop.publish_terminate_event(&reason);
+            let terminate_events = event_loop.publish_terminate_event(&reason);
             log_terminate_event(
                 &mut event_logger,
                 event_loop.state().iteration,
-                &terminate_event,
+                &terminate_events,
             );
             handle_termination(
                 &reason,
@@ -717,11 +730,11 @@ pub async fn run_loop_impl(
                         MAX_FALLBACK_ATTEMPTS
                     );
                     let reason = TerminationReason::Stopped;
-                    let terminate_event = event_loop.publish_terminate_event(&reason);
+                    let terminate_events = event_loop.publish_terminate_event(&reason);
                     log_terminate_event(
                         &mut event_logger,
                         event_loop.state().iteration,
-                        &terminate_event,
+                        &terminate_events,
                     );
                     handle_termination(
                         &reason,
@@ -752,11 +765,11 @@ pub async fn run_loop_impl(
                 warn!("No hats with pending events and fallback not available, terminating");
                 let reason = TerminationReason::Stopped;
                 // Per spec: Publish loop.terminate event to observers
-                let terminate_event = event_loop.publish_terminate_event(&reason);
+                let terminate_events = event_loop.publish_terminate_event(&reason);
                 log_terminate_event(
                     &mut event_logger,
                     event_loop.state().iteration,
-                    &terminate_event,
+                    &terminate_events,
                 );
                 handle_termination(
                     &reason,
@@ -946,10 +959,12 @@ pub async fn run_loop_impl(
                     interrupt_rx_for_pty,
                     verbosity,
                     tui_lines_for_pty,
+                    ctx.env_overrides(),
                 )
                 .await
             } else {
-                let executor = CliExecutor::new(effective_backend.clone());
+                let executor = CliExecutor::new(effective_backend.clone())
+                    .with_env(ctx.env_overrides().clone());
                 let result = executor
                     .execute(&prompt, stdout(), timeout, verbosity == Verbosity::Verbose)
                     .await?;
@@ -979,8 +994,8 @@ pub async fn run_loop_impl(
                 }
 
                 let reason = TerminationReason::Interrupted;
-                let terminate_event = event_loop.publish_terminate_event(&reason);
-                log_terminate_event(&mut event_logger, event_loop.state().iteration, &terminate_event);
+                let terminate_events = event_loop.publish_terminate_event(&reason);
+                log_terminate_event(&mut event_logger, event_loop.state().iteration, &terminate_events);
                 handle_termination(&reason, event_loop.state(), &config.core.scratchpad, &loop_history, &loop_context, auto_merge, &prompt_content);
                 // Signal TUI to exit immediately on interrupt
                 let _ = terminated_tx.send(true);
@@ -989,11 +1004,11 @@ pub async fn run_loop_impl(
         };
 
         if let Some(reason) = outcome.termination {
-            let terminate_event = event_loop.publish_terminate_event(&reason);
+            let terminate_events = event_loop.publish_terminate_event(&reason);
             log_terminate_event(
                 &mut event_logger,
                 event_loop.state().iteration,
-                &terminate_event,
+                &terminate_events,
             );
             handle_termination(
                 &reason,
@@ -1026,6 +1041,7 @@ pub async fn run_loop_impl(
             iteration,
             &hat_id,
             &output,
+            success,
             event_loop.registry(),
         );
 
@@ -1039,11 +1055,11 @@ pub async fn run_loop_impl(
                 );
             }
             // Per spec: Publish loop.terminate event to observers
-            let terminate_event = event_loop.publish_terminate_event(&reason);
+            let terminate_events = event_loop.publish_terminate_event(&reason);
             log_terminate_event(
                 &mut event_logger,
                 event_loop.state().iteration,
-                &terminate_event,
+                &terminate_events,
             );
             handle_termination(
                 &reason,
@@ -1077,11 +1093,11 @@ pub async fn run_loop_impl(
                 config.event_loop.completion_promise
             );
 
-            let terminate_event = event_loop.publish_terminate_event(&reason);
+            let terminate_events = event_loop.publish_terminate_event(&reason);
             log_terminate_event(
                 &mut event_logger,
                 event_loop.state().iteration,
-                &terminate_event,
+                &terminate_events,
             );
             handle_termination(
                 &reason,
@@ -1159,6 +1175,50 @@ fn convert_termination_type(
     }
 }
 
+/// Set on the re-exec'd process after a `RestartRequested` termination so the
+/// restarted loop keeps its persisted counters.
+pub(crate) const RESTARTED_ENV: &str = "RALPH_RESTARTED";
+
+/// Removes loop state left behind by an earlier or crashed run.
+///
+/// A fresh objective must start its iteration, cost, and runtime counters at
+/// zero; only a restarted loop resumes them.
+fn clear_stale_loop_state(ctx: &LoopContext, restarted: bool) -> Result<()> {
+    let state_path = ctx.loop_state_path();
+    if restarted || !state_path.exists() {
+        return Ok(());
+    }
+    fs::remove_file(&state_path)
+        .with_context(|| format!("Failed to clear stale loop state: {:?}", state_path))?;
+    debug!(
+        "Cleared stale loop state for fresh objective: {:?}",
+        state_path
+    );
+    Ok(())
+}
+
+/// Restores persisted per-loop environment overrides and saves the merged set.
+///
+/// Overrides are only restored for worktree loops and resumed or restarted
+/// runs: a fresh primary run must not silently inherit another run's
+/// environment. Restored keys are logged so the inheritance is visible.
+fn restore_env_overrides(ctx: &mut LoopContext, resumed: bool) {
+    if !ctx.is_primary() || resumed {
+        match ctx.load_env_overrides() {
+            Ok(restored) if !restored.is_empty() => info!(
+                keys = ?restored,
+                path = %ctx.loop_env_path().display(),
+                "Restored loop environment overrides"
+            ),
+            Ok(_) => {}
+            Err(e) => warn!(error = %e, "Failed to restore loop environment overrides"),
+        }
+    }
+    if let Err(e) = ctx.save_env_overrides() {
+        warn!(error = %e, "Failed to persist loop environment overrides");
+    }
+}
+
 /// Resolves the active timestamped events JSONL file path for this run.
 ///
 /// The authoritative source is `.ralph/current-events`, which contains a
@@ -1204,6 +1264,7 @@ async fn execute_pty(
     interrupt_rx: tokio::sync::watch::Receiver<bool>,
     verbosity: Verbosity,
     tui_lines: Option<Arc<std::sync::Mutex<Vec<ratatui::text::Line<'static>>>>>,
+    env: &HashMap<String, String>,
 ) -> Result<ExecutionOutcome> {
     use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
 
@@ -1230,6 +1291,7 @@ async fn execute_pty(
             ..PtyConfig::from_env()
         };
         temp_executor = PtyExecutor::new(backend.clone(), pty_config);
+        temp_executor.set_env(env.clone());
         &mut temp_executor
     };
 
@@ -1330,13 +1392,15 @@ async fn execute_pty(
 
 /// Logs events parsed from output to the event history file.
 ///
-/// When an event has no subscriber (orphan), also logs an `event.orphaned`
-/// system event to help Ralph understand the misconfiguration.
+/// Each record carries the iteration's backend outcome so a replay can rebuild
+/// the failure streak. When an event has no subscriber (orphan), also logs an
+/// `event.orphaned` system event to help Ralph understand the misconfiguration.
 fn log_events_from_output(
     logger: &mut EventLogger,
     iteration: u32,
     hat_id: &HatId,
     output: &str,
+    success: bool,
     registry: &ralph_core::HatRegistry,
 ) {
     let parser = EventParser::new();
@@ -1361,7 +1425,7 @@ fn log_events_from_output(
                 .all()
                 .flat_map(|hat| hat.subscriptions.iter())
                 .map(|t| t.as_str().to_string())
-                .filter(|t| t != "*")
+                .filter(|t| t != "*" && !t.starts_with('!'))
                 .collect::<std::collections::HashSet<_>>()
                 .into_iter()
                 .collect();
@@ -1388,7 +1452,8 @@ fn log_events_from_output(
             }
         }
 
-        let record = EventRecord::new(iteration, hat_id.to_string(), &event, triggered);
+        let record = EventRecord::new(iteration, hat_id.to_string(), &event, triggered)
+            .with_success(success);
 
         if let Err(e) = logger.log(&record) {
             warn!("Failed to log event {}: {}", event.topic, e);
@@ -1399,13 +1464,15 @@ fn log_events_from_output(
 /// Logs the loop.terminate system event to the event history.
 ///
 /// Per spec: loop.terminate is an observer-only event published on loop exit.
-fn log_terminate_event(logger: &mut EventLogger, iteration: u32, event: &Event) {
-    // loop.terminate is published by the orchestrator, not a hat
-    // No hat can trigger on it (it's observer-only)
-    let record = EventRecord::new(iteration, "loop", event, None::<&HatId>);
+fn log_terminate_event(logger: &mut EventLogger, iteration: u32, events: &[Event]) {
+    // loop.terminate (and its JSON summary) is published by the orchestrator,
+    // not a hat. No hat can trigger on it (it's observer-only)
+    for event in events {
+        let record = EventRecord::new(iteration, "loop", event, None::<&HatId>);
 
-    if let Err(e) = logger.log(&record) {
-        warn!("Failed to log loop.terminate event: {}", e);
+        if let Err(e) = logger.log(&record) {
+            warn!("Failed to log {} event: {}", event.topic, e);
+        }
     }
 }
 
@@ -2128,7 +2195,7 @@ mod tests {
 <event topic=\"unknown.event\">oops</event>";
         let hat_id = HatId::new("tester");
 
-        log_events_from_output(&mut logger, 1, &hat_id, output, &registry);
+        log_events_from_output(&mut logger, 1, &hat_id, output, false, &registry);
 
         let content = std::fs::read_to_string(&log_path).expect("read events");
         let records: Vec<EventRecord> = content
@@ -2149,6 +2216,34 @@ mod tests {
         assert_eq!(triggered.as_deref(), Some("planner"));
     }
 
+    #[test]
+    fn test_log_events_from_output_records_iteration_outcome() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let log_path = temp_dir.path().join("events.jsonl");
+        let mut logger = EventLogger::new(&log_path);
+        let registry = HatRegistry::new();
+        let hat_id = HatId::new("builder");
+
+        log_events_from_output(
+            &mut logger,
+            2,
+            &hat_id,
+            "<event topic=\"build.done\">done</event>",
+            false,
+            &registry,
+        );
+
+        let records = ralph_core::EventHistory::new(&log_path)
+            .read_all()
+            .expect("read events");
+        let outcomes: Vec<_> = records
+            .iter()
+            .filter(|record| record.hat == "builder")
+            .map(|record| (record.iteration, record.success))
+            .collect();
+        assert_eq!(outcomes, vec![(2, Some(false))]);
+    }
+
     #[test]
     fn test_log_terminate_event_writes_record() {
         let temp_dir = tempfile::tempdir().expect("temp dir");
@@ -2156,7 +2251,7 @@ mod tests {
         let mut logger = EventLogger::new(&log_path);
 
         let event = Event::new("loop.terminate", "done");
-        log_terminate_event(&mut logger, 7, &event);
+        log_terminate_event(&mut logger, 7, &[event]);
 
         let content = std::fs::read_to_string(&log_path).expect("read events");
         let records: Vec<EventRecord> = content
@@ -2170,6 +2265,101 @@ mod tests {
         assert_eq!(records[0].iteration, 7);
     }
 
+    #[test]
+    fn test_terminate_json_summary_is_logged() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let log_path = temp_dir.path().join("events.jsonl");
+        let mut logger = EventLogger::new(&log_path);
+
+        let mut event_loop = EventLoop::new(RalphConfig::default());
+        let events = event_loop.publish_terminate_event(&TerminationReason::CompletionPromise);
+        log_terminate_event(&mut logger, 3, &events);
+
+        let content = std::fs::read_to_string(&log_path).expect("read events");
+        let records: Vec<EventRecord> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("record"))
+            .collect();
+        let topics: Vec<&str> = records.iter().map(|r| r.topic.as_str()).collect();
+        assert_eq!(topics, vec!["loop.terminate", "loop.terminate.json"]);
+
+        let summary: ralph_core::TerminationSummary =
+            serde_json::from_str(&records[1].payload).expect("summary payload");
+        assert_eq!(summary.reason, "completed");
+    }
+
+    #[test]
+    fn test_stale_loop_state_does_not_affect_fresh_run() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let ctx = ralph_core::LoopContext::primary(temp_dir.path().to_path_buf());
+        std::fs::create_dir_all(ctx.ralph_dir()).expect("create .ralph");
+
+        let mut stale = ralph_core::LoopState::new();
+        stale.iteration = 99;
+        stale.cumulative_cost = 42.0;
+        stale.verify_passed_seen = true;
+        stale
+            .save_to(&ctx.loop_state_path())
+            .expect("save stale state");
+
+        clear_stale_loop_state(&ctx, false).expect("clear stale state");
+
+        let mut config = RalphConfig::default();
+        config.core.workspace_root = temp_dir.path().to_path_buf();
+        let event_loop = EventLoop::with_context(config, ctx.clone());
+        assert_eq!(event_loop.state().iteration, 0);
+        assert!(event_loop.state().cumulative_cost.abs() < f64::EPSILON);
+        assert!(!event_loop.state().verify_passed_seen);
+    }
+
+    #[test]
+    fn test_restarted_run_keeps_loop_state() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let ctx = ralph_core::LoopContext::primary(temp_dir.path().to_path_buf());
+        std::fs::create_dir_all(ctx.ralph_dir()).expect("create .ralph");
+
+        let mut state = ralph_core::LoopState::new();
+        state.iteration = 7;
+        state.save_to(&ctx.loop_state_path()).expect("save state");
+
+        clear_stale_loop_state(&ctx, true).expect("keep state");
+
+        let mut config = RalphConfig::default();
+        config.core.workspace_root = temp_dir.path().to_path_buf();
+        let event_loop = EventLoop::with_context(config, ctx);
+        assert_eq!(event_loop.state().iteration, 7);
+    }
+
+    #[test]
+    fn test_env_overrides_only_restored_for_resumed_or_worktree_loops() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        ralph_core::LoopContext::primary(temp_dir.path().to_path_buf())
+            .with_env(HashMap::from([("PORT".to_string(), "8081".to_string())]))
+            .save_env_overrides()
+            .expect("save overrides");
+
+        let mut fresh = ralph_core::LoopContext::primary(temp_dir.path().to_path_buf());
+        restore_env_overrides(&mut fresh, false);
+        assert!(fresh.env_overrides().is_empty());
+
+        let mut resumed = ralph_core::LoopContext::primary(temp_dir.path().to_path_buf());
+        restore_env_overrides(&mut resumed, true);
+        assert_eq!(resumed.env_overrides()["PORT"], "8081");
+
+        let worktree_path = temp_dir.path().join(".worktrees/loop-1");
+        ralph_core::LoopContext::worktree("loop-1", worktree_path.clone(), temp_dir.path().into())
+            .with_env(HashMap::from([("PORT".to_string(), "9090".to_string())]))
+            .save_env_overrides()
+            .expect("save worktree overrides");
+        let mut worktree = ralph_core::LoopContext::worktree(
+            "loop-1",
+            worktree_path.clone(),
+            temp_dir.path().into(),
+        );
+        restore_env_overrides(&mut worktree, false);
+        assert_eq!(worktree.env_overrides()["PORT"], "9090");
+    }
+
     #[test]
     fn test_check_planning_session_responses_publishes_user_response() {
         let temp_dir = tempfile::tempdir().expect("temp dir");
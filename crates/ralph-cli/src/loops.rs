@@ -20,7 +20,7 @@ use anyhow::{Context, Result, bail};
 use clap::{Parser, Subcommand};
 
 use ralph_core::worktree::{list_ralph_worktrees, remove_worktree};
-use ralph_core::{LoopRegistry, MergeButtonState, MergeQueue, MergeState, merge_button_state};
+use ralph_core::{LoopRegistry, MergeQueue, MergeState, merge_button_state};
 
 /// Manage parallel loops.
 #[derive(Parser, Debug)]
@@ -198,11 +198,9 @@ fn get_merge_button_state(args: MergeButtonStateArgs) -> Result<()> {
     let cwd = std::env::current_dir()?;
     let state = merge_button_state(&cwd, &args.loop_id)?;
 
-    let json = match state {
-        MergeButtonState::Active => serde_json::json!({ "state": "active" }),
-        MergeButtonState::Blocked { reason } => {
-            serde_json::json!({ "state": "blocked", "reason": reason })
-        }
+    let json = match state.blocked_reason() {
+        None => serde_json::json!({ "state": "active" }),
+        Some(reason) => serde_json::json!({ "state": "blocked", "reason": reason }),
     };
 
     println!("{}", serde_json::to_string(&json)?);
@@ -346,8 +344,8 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
             // Get merge button state for queued entries
             let merge_status = if entry.state == MergeState::Queued {
                 match merge_button_state(&cwd, &entry.loop_id) {
-                    Ok(MergeButtonState::Active) => Some("ready".to_string()),
-                    Ok(MergeButtonState::Blocked { .. }) => Some("blocked".to_string()),
+                    Ok(state) if state.is_active() => Some("ready".to_string()),
+                    Ok(_) => Some("blocked".to_string()),
                     Err(_) => None,
                 }
             } else {
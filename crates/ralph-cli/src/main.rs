@@ -867,6 +867,7 @@ fn format_preflight_summary(report: &PreflightReport) -> String {
                 CheckStatus::Pass => "✓",
                 CheckStatus::Warn => "⚠",
                 CheckStatus::Fail => "✗",
+                CheckStatus::TimedOut => "⏱",
             };
             format!("{icon} {}", check.name)
         })
@@ -884,6 +885,8 @@ fn format_preflight_summary(report: &PreflightReport) -> String {
             report.failures,
             if report.failures == 1 { "" } else { "s" }
         )
+    } else if report.timed_out > 0 {
+        format!(" ({} timed out)", report.timed_out)
     } else if report.warnings > 0 {
         format!(
             " ({} warning{})",
@@ -930,7 +933,10 @@ async fn run_auto_preflight(
         return Ok(None);
     }
 
-    let runner = PreflightRunner::default_checks();
+    let runner = PreflightRunner::default_checks().with_persistent_cache(
+        config.core.resolve_path(ralph_core::PREFLIGHT_CACHE_FILE),
+        ralph_core::DEFAULT_PREFLIGHT_CACHE_TTL,
+    );
     let mut report = if config.features.preflight.skip.is_empty() {
         runner.run_all(config).await
     } else {
@@ -942,7 +948,7 @@ async fn run_auto_preflight(
             .map(|name| name.to_lowercase())
             .collect();
         let selected: Vec<String> = runner
-            .check_names()
+            .default_check_names()
             .into_iter()
             .filter(|name| !skip_lower.contains(&name.to_lowercase()))
             .map(|name| name.to_string())
@@ -951,9 +957,9 @@ async fn run_auto_preflight(
     };
 
     let effective_passed = if config.features.preflight.strict {
-        report.failures == 0 && report.warnings == 0
+        report.passed && report.warnings == 0
     } else {
-        report.failures == 0
+        report.passed
     };
     report.passed = effective_passed;
 
@@ -995,7 +1001,7 @@ fn print_preflight_summary(
     };
 
     for check in &report.checks {
-        if check.status == CheckStatus::Fail
+        if matches!(check.status, CheckStatus::Fail | CheckStatus::TimedOut)
             && let Some(message) = &check.message
         {
             emit(format!("  ✗ {}: {}", check.name, message));
@@ -1462,7 +1468,10 @@ async fn run_command(
         {
             use std::os::unix::process::CommandExt;
             let args: Vec<String> = std::env::args().collect();
-            let err = std::process::Command::new(&args[0]).args(&args[1..]).exec();
+            let err = std::process::Command::new(&args[0])
+                .args(&args[1..])
+                .env(loop_runner::RESTARTED_ENV, "1")
+                .exec();
             // exec() only returns on error
             anyhow::bail!("Failed to exec-replace process: {}", err);
         }
@@ -2717,6 +2726,7 @@ core:
             passed: false,
             warnings: 1,
             failures: 1,
+            timed_out: 0,
             checks: vec![
                 ralph_core::CheckResult::pass("config", "Config"),
                 ralph_core::CheckResult::warn("backend", "Backend", "Missing"),
@@ -2738,6 +2748,7 @@ core:
             passed: true,
             warnings: 0,
             failures: 0,
+            timed_out: 0,
             checks: Vec::new(),
         };
 
@@ -2752,6 +2763,7 @@ core:
             passed: false,
             warnings: 2,
             failures: 1,
+            timed_out: 0,
             checks: Vec::new(),
         };
 
@@ -2850,6 +2862,7 @@ core:
             passed: false,
             warnings: 1,
             failures: 1,
+            timed_out: 0,
             checks: vec![
                 ralph_core::CheckResult::pass("config", "Config"),
                 ralph_core::CheckResult::warn("backend", "Backend", "Missing"),
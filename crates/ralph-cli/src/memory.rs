@@ -12,7 +12,7 @@
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
 use ralph_core::{MarkdownMemoryStore, Memory, MemoryType};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// ANSI color codes for terminal output.
 mod colors {
@@ -215,7 +215,7 @@ pub struct InitArgs {
 /// Execute a memory command.
 pub fn execute(args: MemoryArgs, use_colors: bool) -> Result<()> {
     let root = args.root.unwrap_or_else(|| PathBuf::from("."));
-    let store = MarkdownMemoryStore::with_default_path(&root);
+    let store = resolve_store(&root);
 
     match args.command {
         MemoryCommands::Add(add_args) => add_command(&store, add_args, use_colors),
@@ -228,6 +228,18 @@ pub fn execute(args: MemoryArgs, use_colors: bool) -> Result<()> {
     }
 }
 
+/// Opens the memory store the event loop injects from.
+///
+/// Honors `memories.path` in the workspace config so memories added here are
+/// the ones injected into prompts.
+fn resolve_store(root: &Path) -> MarkdownMemoryStore {
+    let config = crate::skill_cli::load_workspace_config(root);
+    match config.memories.resolve_path(root) {
+        Some(path) => MarkdownMemoryStore::new(path),
+        None => MarkdownMemoryStore::with_default_path(root),
+    }
+}
+
 fn add_command(store: &MarkdownMemoryStore, args: AddArgs, use_colors: bool) -> Result<()> {
     // Parse tags
     let tags: Vec<String> = args
@@ -755,6 +767,32 @@ mod tests {
     use super::*;
     use chrono::{Duration, NaiveDate};
 
+    #[test]
+    fn test_resolve_store_honors_configured_memories_path() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        std::fs::write(
+            temp.path().join("ralph.yml"),
+            "memories:\n  path: notes/memories.md\n",
+        )
+        .expect("write config");
+
+        let store = resolve_store(temp.path());
+        assert_eq!(store.path(), temp.path().join("notes/memories.md"));
+
+        let mut config = ralph_core::RalphConfig::default();
+        config.core.workspace_root = temp.path().to_path_buf();
+        config.memories.path = Some(PathBuf::from("notes/memories.md"));
+        let event_loop = ralph_core::EventLoop::new(config);
+        assert_eq!(store.path(), event_loop.memories_path());
+    }
+
+    #[test]
+    fn test_resolve_store_defaults_without_config() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let store = resolve_store(temp.path());
+        assert_eq!(store.path(), temp.path().join(".ralph/agent/memories.md"));
+    }
+
     fn fixed_today() -> NaiveDate {
         NaiveDate::from_ymd_opt(2026, 1, 31).expect("valid date")
     }
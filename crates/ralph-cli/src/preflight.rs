@@ -18,7 +18,7 @@ pub struct PreflightArgs {
     #[arg(long)]
     pub strict: bool,
 
-    /// Run only specific check(s)
+    /// Run only specific check(s), including opt-in checks like `topology`
     #[arg(long, value_name = "NAME", action = ArgAction::Append)]
     pub check: Vec<String>,
 }
@@ -27,6 +27,7 @@ pub struct PreflightArgs {
 pub enum PreflightFormat {
     Human,
     Json,
+    Markdown,
 }
 
 pub async fn execute(
@@ -48,15 +49,18 @@ pub async fn execute(
     };
 
     let effective_passed = if args.strict {
-        report.failures == 0 && report.warnings == 0
+        report.passed && report.warnings == 0
     } else {
-        report.failures == 0
+        report.passed
     };
     report.passed = effective_passed;
 
     match args.format {
         PreflightFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&report)?);
+            println!("{}", report.to_json()?);
+        }
+        PreflightFormat::Markdown => {
+            print!("{}", report.to_markdown());
         }
         PreflightFormat::Human => {
             print_human_report(&report, &source_label, use_colors, args.strict);
@@ -127,6 +131,9 @@ fn print_human_report(report: &PreflightReport, source: &str, use_colors: bool,
     if report.failures > 0 {
         details.push(format!("{} failure(s)", report.failures));
     }
+    if report.timed_out > 0 {
+        details.push(format!("{} timed out", report.timed_out));
+    }
     if report.warnings > 0 {
         details.push(format!("{} warning(s)", report.warnings));
     }
@@ -164,6 +171,7 @@ fn print_check_line(check: &CheckResult, name_width: usize, use_colors: bool) {
         CheckStatus::Pass => ("OK", colors::GREEN),
         CheckStatus::Warn => ("WARN", colors::YELLOW),
         CheckStatus::Fail => ("FAIL", colors::RED),
+        CheckStatus::TimedOut => ("TIME", colors::RED),
     };
 
     let status_padded = format!("{status_text:<4}");
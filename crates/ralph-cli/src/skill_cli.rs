@@ -269,8 +269,9 @@ fn resolve_configured_skills_dir(root: &Path, dir: &Path) -> PathBuf {
     candidate
 }
 
-/// Load config from workspace root, falling back to defaults.
-fn load_config(root: &Path) -> RalphConfig {
+/// Load config from the workspace root's `ralph.yml`/`ralph.yaml`, falling
+/// back to defaults.
+pub(crate) fn load_workspace_config(root: &Path) -> RalphConfig {
     // Try standard config file names
     let candidates = ["ralph.yml", "ralph.yaml"];
     let mut config = None;
@@ -286,6 +287,12 @@ fn load_config(root: &Path) -> RalphConfig {
 
     let mut config = config.unwrap_or_default();
     config.normalize();
+    config
+}
+
+/// Load config from workspace root with skill directories resolved.
+fn load_config(root: &Path) -> RalphConfig {
+    let mut config = load_workspace_config(root);
 
     if config.skills.dirs.is_empty() {
         if let Some(default_dir) = find_default_skills_dir(root) {
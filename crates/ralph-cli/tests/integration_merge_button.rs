@@ -133,8 +133,8 @@ fn test_merge_button_state_active_when_primary_idle() -> Result<()> {
     // Then: Button should be active (can merge now)
     assert_eq!(
         state,
-        ralph_core::MergeButtonState::Active,
-        "Merge button should be Active when primary loop is idle"
+        ralph_core::MergeButtonState::Ready,
+        "Merge button should be Ready when primary loop is idle"
     );
 
     Ok(())
@@ -159,8 +159,8 @@ fn test_merge_button_state_blocked_when_primary_running() -> Result<()> {
 
     // Then: Button should be blocked with reason
     assert!(
-        matches!(state, ralph_core::MergeButtonState::Blocked { ref reason } if reason.contains("primary")),
-        "Merge button should be Blocked when primary loop is running. Got: {:?}",
+        matches!(state, ralph_core::MergeButtonState::Idle { ref reason } if reason.contains("primary")),
+        "Merge button should be Idle when primary loop is running. Got: {:?}",
         state
     );
 
@@ -186,19 +186,58 @@ fn test_merge_button_state_blocked_reason_shows_primary_prompt() -> Result<()> {
 
     // Then: Blocked reason should explain what primary is doing (for tooltip)
     match state {
-        ralph_core::MergeButtonState::Blocked { reason } => {
+        ralph_core::MergeButtonState::Idle { reason } => {
             assert!(
                 reason.contains("authentication") || reason.contains("Implementing"),
                 "Blocked reason should show primary loop's prompt for tooltip. Got: {}",
                 reason
             );
         }
-        ralph_core::MergeButtonState::Active => panic!("Expected Blocked state, got: {:?}", state),
+        other => panic!("Expected Idle state, got: {:?}", other),
     }
 
     Ok(())
 }
 
+#[test]
+fn test_merge_button_state_needs_review_blocked_when_primary_running() -> Result<()> {
+    let temp_dir = setup_workspace()?;
+    let temp_path = temp_dir.path();
+
+    // Given: A worktree loop whose merge failed and needs review
+    let _worktree_path = create_worktree_with_commits(temp_path, "test-loop-005", 1)?;
+
+    let queue = ralph_core::MergeQueue::new(temp_path);
+    queue.enqueue("test-loop-005", "Feature Z")?;
+    queue.mark_merging("test-loop-005", 99999)?;
+    queue.mark_needs_review("test-loop-005", "conflicts in src/lib.rs")?;
+
+    // And: Primary loop IS running
+    write_loop_lock(temp_path, std::process::id(), "Refactoring the parser")?;
+
+    // When: Checking merge button state
+    let state = ralph_core::merge_button_state(temp_path, "test-loop-005")?;
+
+    // Then: The retry must not be offered while the primary holds the lock
+    assert!(
+        matches!(state, ralph_core::MergeButtonState::Idle { ref reason } if reason.contains("primary")),
+        "Merge button should be Idle while primary loop is running. Got: {:?}",
+        state
+    );
+    assert!(!state.is_active());
+
+    // And: Once the primary loop is gone, the failure is surfaced again
+    fs::remove_file(temp_path.join(".ralph/loop.lock"))?;
+    let state = ralph_core::merge_button_state(temp_path, "test-loop-005")?;
+    assert!(
+        matches!(state, ralph_core::MergeButtonState::Failed { ref reason } if reason.contains("conflicts")),
+        "Merge button should be Failed once primary loop is idle. Got: {:?}",
+        state
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_merge_button_state_blocked_when_merge_already_running() -> Result<()> {
     let temp_dir = setup_workspace()?;
@@ -218,8 +257,11 @@ fn test_merge_button_state_blocked_when_merge_already_running() -> Result<()> {
 
     // Then: Button should be blocked (merge in progress)
     assert!(
-        matches!(state, ralph_core::MergeButtonState::Blocked { ref reason } if reason.contains("progress") || reason.contains("merging")),
-        "Merge button should be Blocked when merge is in progress. Got: {:?}",
+        state == ralph_core::MergeButtonState::Executing
+            && state
+                .blocked_reason()
+                .is_some_and(|reason| reason.contains("progress")),
+        "Merge button should be Executing when merge is in progress. Got: {:?}",
         state
     );
 
@@ -659,3 +701,90 @@ fn test_merge_execution_summary_includes_stats() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_merge_steering_for_large_diff() -> Result<()> {
+    let temp_dir = setup_workspace()?;
+    let temp_path = temp_dir.path();
+
+    // Given: A worktree that adds a 50-line file
+    let worktree_path = temp_path.join(".worktrees").join("test-loop-011");
+    Command::new("git")
+        .args(["worktree", "add", "-b", "ralph/test-loop-011"])
+        .arg(&worktree_path)
+        .current_dir(temp_path)
+        .output()?;
+    let body = "line\n".repeat(50);
+    fs::write(worktree_path.join("big.txt"), body)?;
+    Command::new("git")
+        .args(["add", "big.txt"])
+        .current_dir(&worktree_path)
+        .output()?;
+    Command::new("git")
+        .args(["commit", "-m", "Add big file"])
+        .current_dir(&worktree_path)
+        .output()?;
+
+    // When: Checking steering with a 20-line limit
+    let config = ralph_core::MergeSteeringConfig {
+        max_diff_lines: Some(20),
+        ..Default::default()
+    };
+    let decision = ralph_core::merge_needs_steering_with(temp_path, "test-loop-011", &config)?;
+
+    // Then: Steering is requested with the size reason recorded
+    assert!(decision.needs_input);
+    assert_eq!(decision.reasons.len(), 1);
+    assert!(
+        decision.reason.contains("Diff exceeds 20 lines"),
+        "Got: {}",
+        decision.reason
+    );
+
+    // And the default heuristics leave the clean addition alone
+    let default = ralph_core::merge_needs_steering(temp_path, "test-loop-011")?;
+    assert!(!default.needs_input);
+
+    Ok(())
+}
+
+#[test]
+fn test_merge_steering_for_protected_path() -> Result<()> {
+    let temp_dir = setup_workspace()?;
+    let temp_path = temp_dir.path();
+
+    // Given: A worktree that adds a CI workflow
+    let worktree_path = temp_path.join(".worktrees").join("test-loop-012");
+    Command::new("git")
+        .args(["worktree", "add", "-b", "ralph/test-loop-012"])
+        .arg(&worktree_path)
+        .current_dir(temp_path)
+        .output()?;
+    fs::create_dir_all(worktree_path.join(".github/workflows"))?;
+    fs::write(worktree_path.join(".github/workflows/ci.yml"), "on: push\n")?;
+    Command::new("git")
+        .args(["add", ".github"])
+        .current_dir(&worktree_path)
+        .output()?;
+    Command::new("git")
+        .args(["commit", "-m", "Add CI"])
+        .current_dir(&worktree_path)
+        .output()?;
+
+    // When: Checking steering with .github/ protected
+    let config = ralph_core::MergeSteeringConfig {
+        protected_paths: vec![".github/".to_string()],
+        ..Default::default()
+    };
+    let decision = ralph_core::merge_needs_steering_with(temp_path, "test-loop-012", &config)?;
+
+    // Then: Steering is requested naming the protected file
+    assert!(decision.needs_input);
+    assert_eq!(
+        decision.reasons,
+        vec!["Touches protected paths: .github/workflows/ci.yml".to_string()]
+    );
+    assert!(!decision.options.is_empty());
+
+    Ok(())
+}
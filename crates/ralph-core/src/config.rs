@@ -469,7 +469,8 @@ impl RalphConfig {
         if !self.hats.is_empty() {
             let mut trigger_to_hat: HashMap<&str, &str> = HashMap::new();
             for (hat_id, hat_config) in &self.hats {
-                for trigger in &hat_config.triggers {
+                // Negated triggers (`!pattern`) exclude topics rather than claim them
+                for trigger in hat_config.triggers.iter().filter(|t| !t.starts_with('!')) {
                     if let Some(existing_hat) = trigger_to_hat.get(trigger.as_str()) {
                         return Err(ConfigError::AmbiguousRouting {
                             trigger: trigger.clone(),
@@ -554,6 +555,10 @@ pub struct EventLoopConfig {
     #[serde(default = "default_completion_promise")]
     pub completion_promise: String,
 
+    /// Event topic an agent emits to ask the human a planning question.
+    #[serde(default = "default_user_prompt_topic")]
+    pub user_prompt_topic: String,
+
     /// Maximum number of iterations before timeout.
     #[serde(default = "default_max_iterations")]
     pub max_iterations: u32,
@@ -565,6 +570,11 @@ pub struct EventLoopConfig {
     /// Maximum cost in USD before stopping.
     pub max_cost_usd: Option<f64>,
 
+    /// Cost in USD at which a one-time `budget.warning` event asks the agent
+    /// to wrap up. Unlike `max_cost_usd`, crossing it does not terminate.
+    #[serde(default)]
+    pub cost_soft_limit_usd: Option<f64>,
+
     /// Stop after this many consecutive failures.
     #[serde(default = "default_max_failures")]
     pub max_consecutive_failures: u32,
@@ -588,6 +598,13 @@ pub struct EventLoopConfig {
     /// event from the hat topology.
     pub starting_event: Option<String>,
 
+    /// Topics whose payloads are wrapped in `<top-level-prompt>` tags in prompts.
+    ///
+    /// Defaults to `task.start` and `task.resume`; add custom starting events
+    /// so they are presented as the user's original request too.
+    #[serde(default = "default_top_level_topics")]
+    pub top_level_topics: Vec<String>,
+
     /// Warn when mutation testing score drops below this percentage (0-100).
     ///
     /// Warning-only: build.done is still accepted even if below threshold.
@@ -602,6 +619,170 @@ pub struct EventLoopConfig {
     /// max_cost), consecutive failures, or explicit interrupt/stop.
     #[serde(default)]
     pub persistent: bool,
+
+    /// Where in a JSONL batch the completion event is honored.
+    ///
+    /// `last_only` (default) ignores a completion event followed by other
+    /// events in the same batch; `any` accepts it anywhere in the batch.
+    #[serde(default)]
+    pub completion_position: CompletionPosition,
+
+    /// Only accept the completion promise after a passing `verify.passed`.
+    ///
+    /// A `verify.passed` counts once it survives quality-threshold
+    /// backpressure. Until then completion is rejected and `task.resume` is
+    /// published so the loop keeps going.
+    #[serde(default)]
+    pub require_verify_before_completion: bool,
+
+    /// What to do with an event that no hat subscribes to.
+    ///
+    /// `route_to_ralph` (default) lets Ralph absorb it silently, `warn` also
+    /// logs a warning, and `error` drops it and publishes `event.orphaned`
+    /// so typos in topic names surface instead of being swallowed. Topics the
+    /// loop handles itself (such as `human.interact` and `build.blocked`),
+    /// events synthesized by validation, and configs without hats always
+    /// route to Ralph.
+    #[serde(default)]
+    pub on_orphan_event: OrphanEventMode,
+
+    /// Maximum pending events each hat contributes to one multi-hat prompt.
+    ///
+    /// The cap is counted per recipient hat. Excess events stay queued for
+    /// later iterations, so a flood of events for one hat cannot crowd the
+    /// other hats' events out of the prompt. `None` (default) takes every
+    /// pending event; a cap of 0 is treated as 1.
+    #[serde(default)]
+    pub max_events_per_hat: Option<usize>,
+
+    /// Topics whose events must carry a non-empty payload.
+    ///
+    /// An event on one of these topics with an empty or whitespace-only
+    /// payload is rejected and replaced with `event.rejected`. Other topics
+    /// accept empty payloads.
+    #[serde(default)]
+    pub require_payload_topics: Vec<String>,
+
+    /// How often loop state and diagnostics are flushed to disk.
+    ///
+    /// Diagnostics logs are buffered between flushes and are always flushed
+    /// when the loop terminates.
+    ///
+    /// ```yaml
+    /// event_loop:
+    ///   persistence_interval:
+    ///     iterations: 5   # every 5 iterations (0 disables this trigger)
+    ///     seconds: 60     # or once a minute, whichever comes first
+    /// ```
+    #[serde(default)]
+    pub persistence_interval: PersistenceInterval,
+
+    /// How `verify.failed` events without a quality report are handled.
+    ///
+    /// `lenient` (default) passes them through with a warning; `strict`
+    /// appends a note asking for the report so the failure is actionable.
+    #[serde(default)]
+    pub verify_failed_report: QualityReportMode,
+
+    /// Maximum complexity score accepted on `build.done` and `verify.passed`.
+    ///
+    /// Defaults to 10.0 when unset.
+    #[serde(default)]
+    pub complexity_threshold: Option<f64>,
+
+    /// Minimum coverage percentage accepted on `verify.passed` and for a
+    /// numeric `coverage` in a JSON `build.done` payload (default 80).
+    #[serde(default)]
+    pub coverage_threshold: Option<f64>,
+
+    /// Minimum mutation score percentage accepted on `verify.passed` (default 70).
+    #[serde(default)]
+    pub mutation_threshold: Option<f64>,
+
+    /// Malformed JSONL lines tolerated before terminating with
+    /// `ValidationFailure` (default 3). Set to 0 to disable.
+    #[serde(default = "default_max_malformed_events")]
+    pub max_malformed_events: usize,
+
+    /// Abandoned-task redispatches tolerated before terminating with
+    /// `LoopThrashing` (default 3). Set to 0 to disable.
+    #[serde(default = "default_max_abandoned_redispatches")]
+    pub max_abandoned_redispatches: usize,
+
+    /// Valid events needed to forgive one malformed line.
+    ///
+    /// `0` (default) resets the malformed counter on any valid event.
+    #[serde(default)]
+    pub malformed_decay_window: usize,
+
+    /// Recent diagnostics events kept in memory for NDJSON export when
+    /// diagnostics are enabled (default 10000). Older events are dropped from
+    /// memory but stay in the session's JSONL logs; `0` disables the buffer.
+    #[serde(default = "default_diagnostics_buffer_size")]
+    pub diagnostics_buffer_size: usize,
+}
+
+/// Handling for `verify.failed` events that lack a quality report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualityReportMode {
+    /// Pass the event through unchanged and log a warning.
+    #[default]
+    Lenient,
+    /// Append a note to the payload requesting the quality report.
+    Strict,
+}
+
+/// Cadence for persisting loop state during a run.
+///
+/// State is written when either trigger fires. The default writes after
+/// every iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersistenceInterval {
+    /// Persist after this many iterations since the last write (0 disables).
+    #[serde(default = "default_persistence_iterations")]
+    pub iterations: u32,
+
+    /// Persist when this many seconds have passed since the last write.
+    #[serde(default)]
+    pub seconds: Option<u64>,
+}
+
+fn default_persistence_iterations() -> u32 {
+    1
+}
+
+impl Default for PersistenceInterval {
+    fn default() -> Self {
+        Self {
+            iterations: default_persistence_iterations(),
+            seconds: None,
+        }
+    }
+}
+
+/// Where a completion event may appear within a batch of emitted events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompletionPosition {
+    /// Only honor completion when it is the last event in the batch.
+    #[default]
+    LastOnly,
+    /// Honor completion wherever it appears in the batch.
+    Any,
+}
+
+/// Handling for events whose topic has no hat subscriber.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrphanEventMode {
+    /// Deliver the event to Ralph without comment.
+    #[default]
+    RouteToRalph,
+    /// Deliver the event to Ralph and log a warning.
+    Warn,
+    /// Drop the event and publish `event.orphaned` instead.
+    Error,
 }
 
 fn default_prompt_file() -> String {
@@ -612,6 +793,14 @@ fn default_completion_promise() -> String {
     "LOOP_COMPLETE".to_string()
 }
 
+fn default_top_level_topics() -> Vec<String> {
+    vec!["task.start".to_string(), "task.resume".to_string()]
+}
+
+fn default_user_prompt_topic() -> String {
+    "user.prompt".to_string()
+}
+
 fn default_max_iterations() -> u32 {
     100
 }
@@ -624,21 +813,50 @@ fn default_max_failures() -> u32 {
     5
 }
 
+fn default_max_malformed_events() -> usize {
+    3
+}
+
+fn default_max_abandoned_redispatches() -> usize {
+    3
+}
+
+fn default_diagnostics_buffer_size() -> usize {
+    crate::diagnostics::DEFAULT_EVENT_BUFFER_CAPACITY
+}
+
 impl Default for EventLoopConfig {
     fn default() -> Self {
         Self {
             prompt: None,
             prompt_file: default_prompt_file(),
             completion_promise: default_completion_promise(),
+            user_prompt_topic: default_user_prompt_topic(),
             max_iterations: default_max_iterations(),
             max_runtime_seconds: default_max_runtime(),
             max_cost_usd: None,
+            cost_soft_limit_usd: None,
             max_consecutive_failures: default_max_failures(),
             cooldown_delay_seconds: 0,
             starting_hat: None,
             starting_event: None,
+            top_level_topics: default_top_level_topics(),
             mutation_score_warn_threshold: None,
             persistent: false,
+            completion_position: CompletionPosition::default(),
+            require_verify_before_completion: false,
+            on_orphan_event: OrphanEventMode::default(),
+            require_payload_topics: Vec::new(),
+            max_events_per_hat: None,
+            persistence_interval: PersistenceInterval::default(),
+            verify_failed_report: QualityReportMode::default(),
+            complexity_threshold: None,
+            coverage_threshold: None,
+            mutation_threshold: None,
+            max_malformed_events: default_max_malformed_events(),
+            max_abandoned_redispatches: default_max_abandoned_redispatches(),
+            malformed_decay_window: 0,
+            diagnostics_buffer_size: default_diagnostics_buffer_size(),
         }
     }
 }
@@ -662,6 +880,16 @@ pub struct CoreConfig {
     #[serde(default = "default_guardrails")]
     pub guardrails: Vec<String>,
 
+    /// Replaces the guardrails list entirely when set (e.g. localized policy text).
+    ///
+    /// Takes precedence over `guardrails`.
+    #[serde(default)]
+    pub guardrails_override: Option<Vec<String>>,
+
+    /// Extra guardrails appended after the base (or overridden) list.
+    #[serde(default)]
+    pub guardrails_append: Vec<String>,
+
     /// Root directory for workspace-relative paths (.ralph/, specs, etc.).
     ///
     /// All relative paths (scratchpad, specs_dir, memories) are resolved relative
@@ -670,6 +898,69 @@ pub struct CoreConfig {
     /// This is especially important for E2E tests that run in isolated workspaces.
     #[serde(skip)]
     pub workspace_root: std::path::PathBuf,
+
+    /// Which optional prompt sections to assemble.
+    #[serde(default)]
+    pub prompt_sections: PromptSectionsConfig,
+
+    /// Token budget for scratchpad injection (~4 chars per token).
+    #[serde(default = "default_scratchpad_budget_tokens")]
+    pub scratchpad_budget_tokens: usize,
+
+    /// Which part of an over-budget scratchpad to keep.
+    #[serde(default)]
+    pub scratchpad_truncate: TruncateMode,
+}
+
+/// Which part of an over-budget document is kept when truncating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TruncateMode {
+    /// Keep the end (most recent entries).
+    #[default]
+    Tail,
+    /// Keep the beginning.
+    Head,
+    /// Keep the beginning and end, dropping the middle.
+    Middle,
+}
+
+fn default_scratchpad_budget_tokens() -> usize {
+    4000
+}
+
+/// Optional prompt sections that can be omitted to shrink prompts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PromptSection {
+    /// The `### GUARDRAILS` list.
+    Guardrails,
+    /// The `## HATS` topology and hat instructions.
+    Hats,
+    /// The skill index table.
+    Skills,
+}
+
+/// Allowlist/denylist of prompt sections.
+///
+/// An empty `include` means every section is allowed; `exclude` is applied
+/// afterwards, so a section listed in both is omitted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PromptSectionsConfig {
+    /// Sections to include. Empty means all.
+    pub include: Vec<PromptSection>,
+
+    /// Sections to omit.
+    pub exclude: Vec<PromptSection>,
+}
+
+impl PromptSectionsConfig {
+    /// Returns true if the section should be assembled into prompts.
+    pub fn is_enabled(&self, section: PromptSection) -> bool {
+        (self.include.is_empty() || self.include.contains(&section))
+            && !self.exclude.contains(&section)
+    }
 }
 
 fn default_scratchpad() -> String {
@@ -696,16 +987,33 @@ impl Default for CoreConfig {
             scratchpad: default_scratchpad(),
             specs_dir: default_specs_dir(),
             guardrails: default_guardrails(),
+            guardrails_override: None,
+            guardrails_append: Vec::new(),
             workspace_root: std::env::var("RALPH_WORKSPACE_ROOT")
                 .map(std::path::PathBuf::from)
                 .unwrap_or_else(|_| {
                     std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."))
                 }),
+            prompt_sections: PromptSectionsConfig::default(),
+            scratchpad_budget_tokens: default_scratchpad_budget_tokens(),
+            scratchpad_truncate: TruncateMode::default(),
         }
     }
 }
 
 impl CoreConfig {
+    /// Returns the guardrails to render: `guardrails_override` if set,
+    /// otherwise `guardrails`, followed by `guardrails_append`.
+    pub fn effective_guardrails(&self) -> Vec<String> {
+        self.guardrails_override
+            .as_ref()
+            .unwrap_or(&self.guardrails)
+            .iter()
+            .chain(&self.guardrails_append)
+            .cloned()
+            .collect()
+    }
+
     /// Sets the workspace root for resolving relative paths.
     ///
     /// This is used by E2E tests to point to their isolated test workspace.
@@ -828,6 +1136,17 @@ impl std::fmt::Display for InjectMode {
     }
 }
 
+/// How the memories injection budget is enforced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemoryBudgetMode {
+    /// Cut the rendered markdown at the budget (may split a memory).
+    #[default]
+    Truncate,
+    /// Drop whole memories, oldest first, until the rest fits the budget.
+    WholeMemories,
+}
+
 /// Memories configuration.
 ///
 /// Controls the persistent learning system that allows Ralph to accumulate
@@ -842,6 +1161,8 @@ impl std::fmt::Display for InjectMode {
 ///   enabled: true
 ///   inject: auto
 ///   budget: 2000
+///   budget_mode: whole_memories
+///   path: ".ralph/agent/memories.md"
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MemoriesConfig {
@@ -861,9 +1182,20 @@ pub struct MemoriesConfig {
     #[serde(default)]
     pub budget: usize,
 
+    /// How the budget is applied when memories exceed it.
+    #[serde(default)]
+    pub budget_mode: MemoryBudgetMode,
+
     /// Filter configuration for memory injection.
     #[serde(default)]
     pub filter: MemoriesFilter,
+
+    /// Override for the memories file location.
+    ///
+    /// Relative paths are resolved against the workspace root. When unset,
+    /// the loop context's memories path (or `.ralph/agent/memories.md`) is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
 }
 
 impl Default for MemoriesConfig {
@@ -872,11 +1204,28 @@ impl Default for MemoriesConfig {
             enabled: true, // Memories enabled by default
             inject: InjectMode::Auto,
             budget: 0,
+            budget_mode: MemoryBudgetMode::default(),
             filter: MemoriesFilter::default(),
+            path: None,
         }
     }
 }
 
+impl MemoriesConfig {
+    /// Resolves the configured `path` against `workspace`.
+    ///
+    /// Returns `None` when no override is configured.
+    pub fn resolve_path(&self, workspace: &Path) -> Option<PathBuf> {
+        self.path.as_ref().map(|path| {
+            if path.is_absolute() {
+                path.clone()
+            } else {
+                workspace.join(path)
+            }
+        })
+    }
+}
+
 /// Filter configuration for memory injection.
 ///
 /// Controls which memories are included when priming context.
@@ -887,6 +1236,9 @@ pub struct MemoriesFilter {
     pub types: Vec<String>,
 
     /// Filter by tags (empty = all tags).
+    ///
+    /// When set, only memories sharing a tag with this list, or with keywords
+    /// from the loop objective, are injected.
     #[serde(default)]
     pub tags: Vec<String>,
 
@@ -906,6 +1258,8 @@ pub struct MemoriesFilter {
 /// ```yaml
 /// tasks:
 ///   enabled: true
+///   ready_task_order: priority
+///   max_injected_tasks: 20
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TasksConfig {
@@ -914,16 +1268,47 @@ pub struct TasksConfig {
     /// When true, tasks are used for loop completion verification.
     #[serde(default = "default_true")]
     pub enabled: bool,
+
+    /// Order in which ready tasks are listed in the prompt.
+    #[serde(default, alias = "sort")]
+    pub ready_task_order: ReadyTaskOrder,
+
+    /// Maximum ready tasks listed in `<ready-tasks>` (0 = unlimited).
+    ///
+    /// Tasks beyond the cap are summarized as an `...and N more` line.
+    #[serde(default)]
+    pub max_injected_tasks: usize,
 }
 
 impl Default for TasksConfig {
     fn default() -> Self {
         Self {
             enabled: true, // Tasks enabled by default
+            ready_task_order: ReadyTaskOrder::default(),
+            max_injected_tasks: 0,
         }
     }
 }
 
+/// Ordering of ready tasks in the `<ready-tasks>` prompt section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadyTaskOrder {
+    /// Store (insertion) order.
+    #[default]
+    Fifo,
+    /// Highest priority (lowest number) first; ties keep store order.
+    Priority,
+    /// Tasks that unblock the longest chain of open work first; ties by priority.
+    DependencyDepth,
+    /// Highest priority first; ties by task ID.
+    PriorityThenId,
+    /// Oldest `created` timestamp first.
+    CreatedAt,
+    /// Highest priority first; ties by oldest `created` timestamp.
+    PriorityThenCreated,
+}
+
 /// Skills configuration.
 ///
 /// Controls the skill discovery and injection system that makes tool
@@ -1029,6 +1414,8 @@ pub struct PreflightConfig {
 ///   loop_naming:
 ///     format: human-readable  # or "timestamp" for legacy format
 ///     max_length: 50
+///   landing:
+///     commit_author: { name: "Ralph Bot", email: "ralph@example.com" }
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeaturesConfig {
@@ -1058,6 +1445,24 @@ pub struct FeaturesConfig {
     /// Preflight check configuration.
     #[serde(default)]
     pub preflight: PreflightConfig,
+
+    /// Git identities for commits made when a loop lands.
+    #[serde(default)]
+    pub landing: LandingIdentityConfig,
+}
+
+/// Git identities used for landing commits.
+///
+/// Unset identities fall back to the ambient git configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LandingIdentityConfig {
+    /// Author for landing commits.
+    #[serde(default)]
+    pub commit_author: Option<crate::git_ops::CommitIdentity>,
+
+    /// Committer for landing commits.
+    #[serde(default)]
+    pub commit_committer: Option<crate::git_ops::CommitIdentity>,
 }
 
 impl Default for FeaturesConfig {
@@ -1067,6 +1472,7 @@ impl Default for FeaturesConfig {
             auto_merge: false, // Auto-merge disabled by default for safety
             loop_naming: crate::loop_name::LoopNamingConfig::default(),
             preflight: PreflightConfig::default(),
+            landing: LandingIdentityConfig::default(),
         }
     }
 }
@@ -1252,6 +1658,12 @@ pub struct HatConfig {
     /// When the limit is exceeded, the orchestrator publishes `<hat_id>.exhausted`
     /// instead of activating the hat again.
     pub max_activations: Option<u32>,
+
+    /// Scheduling priority when several hats have pending events.
+    ///
+    /// Higher values are served first; ties break by hat id. Defaults to 0.
+    #[serde(default)]
+    pub priority: i32,
 }
 
 impl HatConfig {
@@ -2294,6 +2706,131 @@ agent: claude
         assert!(config.skills.overrides.is_empty());
     }
 
+    #[test]
+    fn test_memories_config_path_override() {
+        let yaml = r#"
+memories:
+  enabled: true
+  path: "docs/memories.md"
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            config.memories.path,
+            Some(std::path::PathBuf::from("docs/memories.md"))
+        );
+        assert!(RalphConfig::default().memories.path.is_none());
+    }
+
+    #[test]
+    fn test_completion_position_parses_and_defaults() {
+        let yaml = "
+event_loop:
+  completion_position: any
+";
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            config.event_loop.completion_position,
+            CompletionPosition::Any
+        );
+        assert_eq!(
+            RalphConfig::default().event_loop.completion_position,
+            CompletionPosition::LastOnly
+        );
+    }
+
+    #[test]
+    fn test_persistence_interval_parses_and_defaults() {
+        let yaml = "
+event_loop:
+  persistence_interval:
+    iterations: 5
+    seconds: 60
+";
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.event_loop.persistence_interval.iterations, 5);
+        assert_eq!(config.event_loop.persistence_interval.seconds, Some(60));
+
+        let default = RalphConfig::default().event_loop.persistence_interval;
+        assert_eq!(default.iterations, 1);
+        assert_eq!(default.seconds, None);
+    }
+
+    #[test]
+    fn test_verify_failed_report_mode_parses_and_defaults() {
+        let yaml = "
+event_loop:
+  verify_failed_report: strict
+";
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            config.event_loop.verify_failed_report,
+            QualityReportMode::Strict
+        );
+        assert_eq!(
+            RalphConfig::default().event_loop.verify_failed_report,
+            QualityReportMode::Lenient
+        );
+    }
+
+    #[test]
+    fn test_backpressure_thresholds_parse() {
+        let yaml = "
+event_loop:
+  complexity_threshold: 15
+  coverage_threshold: 60.5
+";
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.event_loop.complexity_threshold, Some(15.0));
+        assert_eq!(config.event_loop.coverage_threshold, Some(60.5));
+        assert_eq!(config.event_loop.mutation_threshold, None);
+    }
+
+    #[test]
+    fn test_failure_tolerances_parse_and_default() {
+        let yaml = "
+event_loop:
+  max_malformed_events: 10
+  malformed_decay_window: 5
+";
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.event_loop.max_malformed_events, 10);
+        assert_eq!(config.event_loop.max_abandoned_redispatches, 3);
+        assert_eq!(config.event_loop.malformed_decay_window, 5);
+
+        let defaults = RalphConfig::default().event_loop;
+        assert_eq!(defaults.max_malformed_events, 3);
+        assert_eq!(defaults.malformed_decay_window, 0);
+    }
+
+    #[test]
+    fn test_ready_task_order_parses_and_defaults() {
+        let yaml = "
+tasks:
+  ready_task_order: dependency_depth
+";
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            config.tasks.ready_task_order,
+            ReadyTaskOrder::DependencyDepth
+        );
+        assert_eq!(
+            RalphConfig::default().tasks.ready_task_order,
+            ReadyTaskOrder::Fifo
+        );
+
+        let yaml = "
+tasks:
+  sort: priority_then_created
+  max_injected_tasks: 20
+";
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            config.tasks.ready_task_order,
+            ReadyTaskOrder::PriorityThenCreated
+        );
+        assert_eq!(config.tasks.max_injected_tasks, 20);
+    }
+
     #[test]
     fn test_skills_config_deserializes_all_fields() {
         let yaml = r#"
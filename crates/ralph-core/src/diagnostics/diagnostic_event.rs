@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+/// Which diagnostics log a [`DiagnosticEvent`] came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticCategory {
+    Orchestration,
+    Performance,
+    Error,
+}
+
+/// How important a [`DiagnosticEvent`] is, ordered from least to most severe.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Debug,
+    #[default]
+    Info,
+    Warn,
+    Error,
+}
+
+/// A diagnostic record in the common export envelope.
+///
+/// Serializes as `{ts, iteration, hat, category, severity, kind, payload}`, where `kind`
+/// is the snake_case event, metric, or error type and `payload` holds the
+/// remaining fields of the original record.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiagnosticEvent {
+    pub ts: String,
+    pub iteration: u32,
+    pub hat: String,
+    pub category: DiagnosticCategory,
+    #[serde(default)]
+    pub severity: Severity,
+    pub kind: String,
+    pub payload: serde_json::Value,
+}
+
+impl DiagnosticEvent {
+    /// Returns true if this event is at least as severe as `min`.
+    pub fn is_at_least(&self, min: Severity) -> bool {
+        self.severity >= min
+    }
+
+    /// Builds an event from an internally tagged (`type` key) record.
+    pub(crate) fn from_tagged(
+        iteration: u32,
+        hat: &str,
+        category: DiagnosticCategory,
+        severity: Severity,
+        record: &impl Serialize,
+    ) -> Self {
+        let mut payload = serde_json::to_value(record).unwrap_or_default();
+        let kind = payload
+            .as_object_mut()
+            .and_then(|fields| fields.remove("type"))
+            .and_then(|kind| kind.as_str().map(str::to_string))
+            .unwrap_or_default();
+        Self::new(iteration, hat, category, severity, kind, payload)
+    }
+
+    pub(crate) fn new(
+        iteration: u32,
+        hat: &str,
+        category: DiagnosticCategory,
+        severity: Severity,
+        kind: String,
+        payload: serde_json::Value,
+    ) -> Self {
+        Self {
+            ts: chrono::Utc::now().to_rfc3339(),
+            iteration,
+            hat: hat.to_string(),
+            category,
+            severity,
+            kind,
+            payload,
+        }
+    }
+}
@@ -1,9 +1,15 @@
 use chrono::Utc;
 use serde::Serialize;
+use serde::ser::{SerializeMap, Serializer};
 use std::fs::{File, OpenOptions};
 use std::io::{self, BufWriter, Write};
 use std::path::Path;
 
+/// A single line in `errors.jsonl`.
+///
+/// Fields serialize in declaration order: `ts`, `iteration`, `hat`,
+/// `error_type`, `message`, `context`. The `context` object keeps the
+/// per-variant key order documented on [`DiagnosticError`].
 #[derive(Debug, Serialize)]
 pub struct ErrorEntry {
     ts: String,
@@ -11,9 +17,36 @@ pub struct ErrorEntry {
     hat: String,
     error_type: String,
     message: String,
-    context: serde_json::Value,
+    context: ErrorContext,
 }
 
+/// Error-specific context, serialized as a JSON object in insertion order.
+///
+/// `serde_json::Value` objects reorder keys depending on whether serde_json's
+/// `preserve_order` feature is enabled anywhere in the build; an explicit
+/// serializer keeps log lines byte-stable either way.
+#[derive(Debug)]
+struct ErrorContext(Vec<(&'static str, serde_json::Value)>);
+
+impl Serialize for ErrorContext {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in &self.0 {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+/// An error recorded by the diagnostics collector.
+///
+/// Context keys are written in this order per variant:
+/// - `ParseError`: `source`, `input`
+/// - `ValidationFailure`: `rule`, `evidence`
+/// - `BackendError`: `backend`
+/// - `Timeout`: `operation`, `duration_ms`
+/// - `MalformedEvent`: `line`
+/// - `TelegramSendError`: `operation`, `retry_count`
 #[derive(Debug)]
 pub enum DiagnosticError {
     ParseError {
@@ -68,48 +101,63 @@ impl DiagnosticError {
         }
     }
 
-    fn context(&self) -> serde_json::Value {
-        match self {
+    fn context(&self) -> ErrorContext {
+        use serde_json::json;
+
+        let fields = match self {
             Self::ParseError {
                 source,
                 message: _,
                 input,
-            } => serde_json::json!({
-                "source": source,
-                "input": input,
-            }),
+            } => vec![("source", json!(source)), ("input", json!(input))],
             Self::ValidationFailure {
                 rule,
                 message: _,
                 evidence,
-            } => serde_json::json!({
-                "rule": rule,
-                "evidence": evidence,
-            }),
+            } => vec![("rule", json!(rule)), ("evidence", json!(evidence))],
             Self::BackendError {
                 backend,
                 message: _,
-            } => serde_json::json!({
-                "backend": backend,
-            }),
+            } => vec![("backend", json!(backend))],
             Self::Timeout {
                 operation,
                 duration_ms,
-            } => serde_json::json!({
-                "operation": operation,
-                "duration_ms": duration_ms,
-            }),
-            Self::MalformedEvent { line, error: _ } => serde_json::json!({
-                "line": line,
-            }),
+            } => vec![
+                ("operation", json!(operation)),
+                ("duration_ms", json!(duration_ms)),
+            ],
+            Self::MalformedEvent { line, error: _ } => vec![("line", json!(line))],
             Self::TelegramSendError {
                 operation,
                 error: _,
                 retry_count,
-            } => serde_json::json!({
-                "operation": operation,
-                "retry_count": retry_count,
-            }),
+            } => vec![
+                ("operation", json!(operation)),
+                ("retry_count", json!(retry_count)),
+            ],
+        };
+        ErrorContext(fields)
+    }
+
+    /// Returns the error type and a `{message, context}` payload for export.
+    pub(crate) fn export_parts(&self) -> (String, serde_json::Value) {
+        let payload = serde_json::json!({
+            "message": self.message(),
+            "context": serde_json::to_value(self.context()).unwrap_or_default(),
+        });
+        (self.error_type().to_string(), payload)
+    }
+}
+
+impl ErrorEntry {
+    fn new(ts: String, iteration: u32, hat: &str, error: &DiagnosticError) -> Self {
+        Self {
+            ts,
+            iteration,
+            hat: hat.to_string(),
+            error_type: error.error_type().to_string(),
+            message: error.message(),
+            context: error.context(),
         }
     }
 }
@@ -141,18 +189,10 @@ impl ErrorLogger {
     }
 
     pub fn log(&mut self, error: DiagnosticError) {
-        let entry = ErrorEntry {
-            ts: Utc::now().to_rfc3339(),
-            iteration: self.iteration,
-            hat: self.hat.clone(),
-            error_type: error.error_type().to_string(),
-            message: error.message(),
-            context: error.context(),
-        };
+        let entry = ErrorEntry::new(Utc::now().to_rfc3339(), self.iteration, &self.hat, &error);
 
         if let Ok(json) = serde_json::to_string(&entry) {
             let _ = writeln!(self.file, "{}", json);
-            let _ = self.file.flush();
         }
     }
 
@@ -215,6 +255,7 @@ mod tests {
             logger.log(error);
         }
 
+        logger.flush();
         let file_path = session_dir.join("errors.jsonl");
         let content = fs::read_to_string(file_path).unwrap();
         let lines: Vec<&str> = content.lines().collect();
@@ -253,6 +294,7 @@ mod tests {
             input: "<event>".to_string(),
         });
 
+        logger.flush();
         let file_path = session_dir.join("errors.jsonl");
         let content = fs::read_to_string(file_path).unwrap();
         let lines: Vec<&str> = content.lines().collect();
@@ -269,4 +311,20 @@ mod tests {
         assert_eq!(second.get("hat").unwrap(), "validator");
         assert_eq!(second.get("error_type").unwrap(), "parse_error");
     }
+
+    #[test]
+    fn test_error_entry_serialization_is_byte_stable() {
+        let error = DiagnosticError::TelegramSendError {
+            operation: "send_question".to_string(),
+            error: "network down".to_string(),
+            retry_count: 3,
+        };
+
+        let expected = r#"{"ts":"2026-01-01T00:00:00+00:00","iteration":4,"hat":"ralph","error_type":"telegram_send_error","message":"network down","context":{"operation":"send_question","retry_count":3}}"#;
+        for _ in 0..3 {
+            let entry =
+                ErrorEntry::new("2026-01-01T00:00:00+00:00".to_string(), 4, "ralph", &error);
+            assert_eq!(serde_json::to_string(&entry).unwrap(), expected);
+        }
+    }
 }
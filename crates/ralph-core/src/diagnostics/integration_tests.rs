@@ -118,6 +118,7 @@ mod tests {
         // Write event to JSONL file
         write_event_to_jsonl(&events_path, "build.start", "Starting build");
         let _ = event_loop.process_events_from_jsonl();
+        drop(event_loop);
 
         let diagnostics_dir = temp_dir.path().join(".ralph").join("diagnostics");
         let session_dirs: Vec<_> = std::fs::read_dir(&diagnostics_dir)
@@ -155,6 +156,7 @@ mod tests {
         // Write build.done event without backpressure evidence
         write_event_to_jsonl(&events_path, "build.done", "Done");
         let _ = event_loop.process_events_from_jsonl();
+        drop(event_loop);
 
         let diagnostics_dir = temp_dir.path().join(".ralph").join("diagnostics");
         let session_dirs: Vec<_> = std::fs::read_dir(&diagnostics_dir)
@@ -206,6 +208,7 @@ mod tests {
 
         let _ = event_loop.process_events_from_jsonl();
         let _ = event_loop.check_completion_event();
+        drop(event_loop);
 
         let diagnostics_dir = temp_dir.path().join(".ralph").join("diagnostics");
         let session_dirs: Vec<_> = std::fs::read_dir(&diagnostics_dir)
@@ -4,6 +4,7 @@
 //! and errors to structured JSONL files when `RALPH_DIAGNOSTICS=1` is set.
 
 mod agent_output;
+mod diagnostic_event;
 mod errors;
 mod log_rotation;
 mod orchestration;
@@ -15,6 +16,7 @@ mod trace_layer;
 mod integration_tests;
 
 pub use agent_output::{AgentOutputContent, AgentOutputEntry, AgentOutputLogger};
+pub use diagnostic_event::{DiagnosticCategory, DiagnosticEvent, Severity};
 pub use errors::{DiagnosticError, ErrorLogger};
 pub use log_rotation::{create_log_file, rotate_logs};
 pub use orchestration::{OrchestrationEvent, OrchestrationLogger};
@@ -23,22 +25,38 @@ pub use stream_handler::DiagnosticStreamHandler;
 pub use trace_layer::{DiagnosticTraceLayer, TraceEntry};
 
 use chrono::Local;
+use std::collections::VecDeque;
 use std::fs;
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
 use std::sync::{Arc, Mutex};
 
 /// Central coordinator for diagnostic logging.
 ///
 /// Checks `RALPH_DIAGNOSTICS` environment variable and creates a timestamped
 /// session directory if enabled.
+///
+/// Enabled collectors also keep the most recent logged events in memory for
+/// [`export_ndjson`](Self::export_ndjson), up to
+/// [`DEFAULT_EVENT_BUFFER_CAPACITY`] unless changed with
+/// [`with_event_buffer_capacity`](Self::with_event_buffer_capacity). Channels
+/// registered with [`subscribe`](Self::subscribe) receive events whether or
+/// not file logging is enabled.
 pub struct DiagnosticsCollector {
     enabled: bool,
     session_dir: Option<PathBuf>,
     orchestration_logger: Option<Arc<Mutex<orchestration::OrchestrationLogger>>>,
     performance_logger: Option<Arc<Mutex<performance::PerformanceLogger>>>,
     error_logger: Option<Arc<Mutex<errors::ErrorLogger>>>,
+    events: Option<Mutex<VecDeque<DiagnosticEvent>>>,
+    event_capacity: usize,
+    subscribers: Mutex<Vec<Sender<DiagnosticEvent>>>,
 }
 
+/// Default number of recent events an enabled collector keeps in memory.
+pub const DEFAULT_EVENT_BUFFER_CAPACITY: usize = 10_000;
+
 impl DiagnosticsCollector {
     /// Creates a new diagnostics collector.
     ///
@@ -80,6 +98,9 @@ impl DiagnosticsCollector {
             orchestration_logger,
             performance_logger,
             error_logger,
+            events: enabled.then(|| Mutex::new(VecDeque::new())),
+            event_capacity: DEFAULT_EVENT_BUFFER_CAPACITY,
+            subscribers: Mutex::new(Vec::new()),
         })
     }
 
@@ -91,7 +112,25 @@ impl DiagnosticsCollector {
             orchestration_logger: None,
             performance_logger: None,
             error_logger: None,
+            events: None,
+            event_capacity: DEFAULT_EVENT_BUFFER_CAPACITY,
+            subscribers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Sets how many recent events are kept in memory for export.
+    ///
+    /// Older events are dropped first; they remain in the session's JSONL
+    /// logs. A capacity of 0 disables the buffer.
+    pub fn with_event_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.event_capacity = capacity;
+        if let Some(events) = &self.events
+            && let Ok(mut events) = events.lock()
+        {
+            let excess = events.len().saturating_sub(capacity);
+            events.drain(..excess);
         }
+        self
     }
 
     /// Returns whether diagnostics are enabled.
@@ -125,6 +164,15 @@ impl DiagnosticsCollector {
     ///
     /// Does nothing if diagnostics are disabled.
     pub fn log_orchestration(&self, iteration: u32, hat: &str, event: OrchestrationEvent) {
+        self.record(|| {
+            DiagnosticEvent::from_tagged(
+                iteration,
+                hat,
+                DiagnosticCategory::Orchestration,
+                event.severity(),
+                &event,
+            )
+        });
         if let Some(logger) = &self.orchestration_logger
             && let Ok(mut logger) = logger.lock()
         {
@@ -136,6 +184,15 @@ impl DiagnosticsCollector {
     ///
     /// Does nothing if diagnostics are disabled.
     pub fn log_performance(&self, iteration: u32, hat: &str, metric: PerformanceMetric) {
+        self.record(|| {
+            DiagnosticEvent::from_tagged(
+                iteration,
+                hat,
+                DiagnosticCategory::Performance,
+                metric.severity(),
+                &metric,
+            )
+        });
         if let Some(logger) = &self.performance_logger
             && let Ok(mut logger) = logger.lock()
         {
@@ -147,6 +204,17 @@ impl DiagnosticsCollector {
     ///
     /// Does nothing if diagnostics are disabled.
     pub fn log_error(&self, iteration: u32, hat: &str, error: DiagnosticError) {
+        self.record(|| {
+            let (kind, payload) = error.export_parts();
+            DiagnosticEvent::new(
+                iteration,
+                hat,
+                DiagnosticCategory::Error,
+                Severity::Error,
+                kind,
+                payload,
+            )
+        });
         if let Some(logger) = &self.error_logger
             && let Ok(mut logger) = logger.lock()
         {
@@ -154,6 +222,94 @@ impl DiagnosticsCollector {
             logger.log(error);
         }
     }
+
+    /// Streams every subsequently logged event to `tx`, in logging order.
+    ///
+    /// The subscription ends when the receiver is dropped.
+    pub fn subscribe(&self, tx: Sender<DiagnosticEvent>) {
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            subscribers.push(tx);
+        }
+    }
+
+    /// Writes the buffered (most recent) events as newline-delimited JSON.
+    ///
+    /// Writes nothing if diagnostics are disabled.
+    pub fn export_ndjson(&self, mut writer: impl Write) -> io::Result<()> {
+        let Some(events) = &self.events else {
+            return Ok(());
+        };
+        let events = events
+            .lock()
+            .map_err(|_| io::Error::other("diagnostics event buffer poisoned"))?;
+        for event in events.iter() {
+            serde_json::to_writer(&mut writer, event)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()
+    }
+
+    /// Returns buffered events at or above `min` severity, in logging order.
+    ///
+    /// Returns an empty list if diagnostics are disabled.
+    pub fn events_at_least(&self, min: Severity) -> Vec<DiagnosticEvent> {
+        self.events
+            .as_ref()
+            .and_then(|events| events.lock().ok())
+            .map(|events| {
+                events
+                    .iter()
+                    .filter(|event| event.is_at_least(min))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Buffers the event (when enabled) and forwards it to live subscribers.
+    ///
+    /// The event is only built if someone will receive it.
+    fn record(&self, build: impl FnOnce() -> DiagnosticEvent) {
+        let Ok(mut subscribers) = self.subscribers.lock() else {
+            return;
+        };
+        if self.events.is_none() && subscribers.is_empty() {
+            return;
+        }
+
+        let event = build();
+        subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+        if let Some(events) = &self.events
+            && self.event_capacity > 0
+            && let Ok(mut events) = events.lock()
+        {
+            if events.len() >= self.event_capacity {
+                events.pop_front();
+            }
+            events.push_back(event);
+        }
+    }
+
+    /// Flushes all diagnostic log files to disk.
+    ///
+    /// Does nothing if diagnostics are disabled.
+    pub fn flush(&self) {
+        if let Some(logger) = &self.orchestration_logger
+            && let Ok(mut logger) = logger.lock()
+        {
+            let _ = logger.flush();
+        }
+        if let Some(logger) = &self.performance_logger
+            && let Ok(mut logger) = logger.lock()
+        {
+            let _ = logger.flush();
+        }
+        if let Some(logger) = &self.error_logger
+            && let Ok(mut logger) = logger.lock()
+        {
+            logger.flush();
+        }
+    }
 }
 
 #[cfg(test)]
@@ -225,6 +381,8 @@ mod tests {
             },
         );
 
+        collector.flush();
+
         // Verify file exists
         let perf_file = collector.session_dir().unwrap().join("performance.jsonl");
         assert!(perf_file.exists(), "performance.jsonl should exist");
@@ -240,6 +398,189 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_export_ndjson_round_trips_events() {
+        let temp = TempDir::new().unwrap();
+        let collector = DiagnosticsCollector::with_enabled(temp.path(), true).unwrap();
+
+        collector.log_orchestration(
+            1,
+            "ralph",
+            OrchestrationEvent::BackpressureTriggered {
+                reason: "tests failed".to_string(),
+            },
+        );
+        collector.log_performance(
+            1,
+            "builder",
+            PerformanceMetric::AgentLatency { duration_ms: 800 },
+        );
+        collector.log_error(
+            2,
+            "builder",
+            DiagnosticError::BackendError {
+                backend: "claude".to_string(),
+                message: "exit 1".to_string(),
+            },
+        );
+
+        let mut out = Vec::new();
+        collector.export_ndjson(&mut out).unwrap();
+        let events: Vec<DiagnosticEvent> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].category, DiagnosticCategory::Orchestration);
+        assert_eq!(events[0].kind, "backpressure_triggered");
+        assert_eq!(events[0].payload["reason"], "tests failed");
+        assert_eq!(events[1].category, DiagnosticCategory::Performance);
+        assert_eq!(events[1].kind, "agent_latency");
+        assert_eq!(events[1].payload["duration_ms"], 800);
+        assert_eq!(events[2].category, DiagnosticCategory::Error);
+        assert_eq!(events[2].kind, "backend_error");
+        assert_eq!(events[2].iteration, 2);
+        assert_eq!(events[2].payload["context"]["backend"], "claude");
+    }
+
+    #[test]
+    fn test_event_buffer_keeps_most_recent_events() {
+        let temp = TempDir::new().unwrap();
+        let collector = DiagnosticsCollector::with_enabled(temp.path(), true)
+            .unwrap()
+            .with_event_buffer_capacity(2);
+
+        for iteration in 1..=3 {
+            collector.log_orchestration(iteration, "ralph", OrchestrationEvent::IterationStarted);
+        }
+
+        let iterations: Vec<u32> = collector
+            .events_at_least(Severity::Debug)
+            .iter()
+            .map(|event| event.iteration)
+            .collect();
+        assert_eq!(iterations, vec![2, 3]);
+
+        let unbuffered = DiagnosticsCollector::with_enabled(temp.path(), true)
+            .unwrap()
+            .with_event_buffer_capacity(0);
+        unbuffered.log_orchestration(1, "ralph", OrchestrationEvent::IterationStarted);
+        let mut out = Vec::new();
+        unbuffered.export_ndjson(&mut out).unwrap();
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_subscribers_receive_events_in_order() {
+        let collector = DiagnosticsCollector::disabled();
+        let (tx, rx) = std::sync::mpsc::channel();
+        collector.subscribe(tx);
+
+        collector.log_orchestration(1, "ralph", OrchestrationEvent::IterationStarted);
+        collector.log_orchestration(
+            1,
+            "ralph",
+            OrchestrationEvent::EventPublished {
+                topic: "build.task".to_string(),
+            },
+        );
+        collector.log_orchestration(
+            1,
+            "ralph",
+            OrchestrationEvent::LoopTerminated {
+                reason: "completed".to_string(),
+            },
+        );
+
+        let kinds: Vec<String> = rx.try_iter().map(|event| event.kind).collect();
+        assert_eq!(
+            kinds,
+            vec!["iteration_started", "event_published", "loop_terminated"]
+        );
+    }
+
+    fn log_mixed_severities(collector: &DiagnosticsCollector) {
+        collector.log_orchestration(
+            1,
+            "ralph",
+            OrchestrationEvent::EventPublished {
+                topic: "build.task".to_string(),
+            },
+        );
+        collector.log_orchestration(1, "ralph", OrchestrationEvent::IterationStarted);
+        collector.log_orchestration(
+            1,
+            "ralph",
+            OrchestrationEvent::BackpressureTriggered {
+                reason: "lint failed".to_string(),
+            },
+        );
+        collector.log_performance(
+            1,
+            "ralph",
+            PerformanceMetric::IterationDuration { duration_ms: 10 },
+        );
+        collector.log_orchestration(
+            2,
+            "ralph",
+            OrchestrationEvent::TaskAbandoned {
+                reason: "too many failures".to_string(),
+            },
+        );
+        collector.log_error(
+            2,
+            "ralph",
+            DiagnosticError::Timeout {
+                operation: "agent".to_string(),
+                duration_ms: 1000,
+            },
+        );
+    }
+
+    #[test]
+    fn test_events_at_least_filters_by_severity() {
+        let temp = TempDir::new().unwrap();
+        let collector = DiagnosticsCollector::with_enabled(temp.path(), true).unwrap();
+        log_mixed_severities(&collector);
+
+        let important = collector.events_at_least(Severity::Warn);
+        let kinds: Vec<&str> = important.iter().map(|e| e.kind.as_str()).collect();
+        assert_eq!(
+            kinds,
+            vec!["backpressure_triggered", "task_abandoned", "timeout"]
+        );
+        assert_eq!(important[0].severity, Severity::Warn);
+        assert_eq!(important[1].severity, Severity::Error);
+        assert_eq!(important[2].severity, Severity::Error);
+
+        assert_eq!(collector.events_at_least(Severity::Debug).len(), 6);
+        assert!(
+            DiagnosticsCollector::disabled()
+                .events_at_least(Severity::Debug)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_subscribers_can_filter_by_severity() {
+        let collector = DiagnosticsCollector::disabled();
+        let (tx, rx) = std::sync::mpsc::channel();
+        collector.subscribe(tx);
+        log_mixed_severities(&collector);
+
+        let kinds: Vec<String> = rx
+            .try_iter()
+            .filter(|event| event.is_at_least(Severity::Warn))
+            .map(|event| event.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec!["backpressure_triggered", "task_abandoned", "timeout"]
+        );
+    }
+
     #[test]
     fn test_error_logger_integration() {
         let temp = TempDir::new().unwrap();
@@ -265,6 +606,8 @@ mod tests {
             },
         );
 
+        collector.flush();
+
         // Verify file exists
         let error_file = collector.session_dir().unwrap().join("errors.jsonl");
         assert!(error_file.exists(), "errors.jsonl should exist");
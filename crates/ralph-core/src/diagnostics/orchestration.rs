@@ -1,8 +1,13 @@
+use super::Severity;
 use serde::{Deserialize, Serialize};
 use std::fs::{File, OpenOptions};
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
+/// A single line in `orchestration.jsonl`.
+///
+/// Fields serialize in declaration order: `timestamp`, `iteration`, `hat`,
+/// `event`. Keep this order stable; CI snapshot diffs rely on it.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrchestrationEntry {
     pub timestamp: String,
@@ -11,6 +16,10 @@ pub struct OrchestrationEntry {
     pub event: OrchestrationEvent,
 }
 
+/// An orchestration decision recorded by the diagnostics collector.
+///
+/// Serialized internally tagged: the `type` key comes first, followed by
+/// the variant's fields in declaration order.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum OrchestrationEvent {
@@ -18,10 +27,26 @@ pub enum OrchestrationEvent {
     HatSelected { hat: String, reason: String },
     EventPublished { topic: String },
     BackpressureTriggered { reason: String },
+    EventAnnotated { topic: String, note: String },
     LoopTerminated { reason: String },
     TaskAbandoned { reason: String },
 }
 
+impl OrchestrationEvent {
+    /// Default severity for this event kind.
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::EventPublished { .. } => Severity::Debug,
+            Self::IterationStarted
+            | Self::HatSelected { .. }
+            | Self::EventAnnotated { .. }
+            | Self::LoopTerminated { .. } => Severity::Info,
+            Self::BackpressureTriggered { .. } => Severity::Warn,
+            Self::TaskAbandoned { .. } => Severity::Error,
+        }
+    }
+}
+
 pub struct OrchestrationLogger {
     writer: BufWriter<File>,
 }
@@ -51,9 +76,12 @@ impl OrchestrationLogger {
         };
         serde_json::to_writer(&mut self.writer, &entry)?;
         self.writer.write_all(b"\n")?;
-        self.writer.flush()?;
         Ok(())
     }
+
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
 }
 
 #[cfg(test)]
@@ -76,6 +104,10 @@ mod tests {
             OrchestrationEvent::BackpressureTriggered {
                 reason: "tests failed".to_string(),
             },
+            OrchestrationEvent::EventAnnotated {
+                topic: "verify.failed".to_string(),
+                note: "quality report missing".to_string(),
+            },
             OrchestrationEvent::LoopTerminated {
                 reason: "completion_promise".to_string(),
             },
@@ -118,18 +150,39 @@ mod tests {
     }
 
     #[test]
-    fn test_immediate_flush() {
+    fn test_writes_are_buffered_until_flush() {
         let temp_dir = TempDir::new().unwrap();
         let mut logger = OrchestrationLogger::new(temp_dir.path()).unwrap();
+        let path = temp_dir.path().join("orchestration.jsonl");
 
         logger
             .log(1, "ralph", OrchestrationEvent::IterationStarted)
             .unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "");
 
-        // Don't drop logger - verify file has content immediately
-        let file = File::open(temp_dir.path().join("orchestration.jsonl")).unwrap();
+        // Don't drop logger - an explicit flush makes the entry visible
+        logger.flush().unwrap();
+        let file = File::open(&path).unwrap();
         let reader = BufReader::new(file);
         let lines: Vec<_> = reader.lines().collect();
         assert_eq!(lines.len(), 1);
     }
+
+    #[test]
+    fn test_entry_serialization_is_byte_stable() {
+        let entry = OrchestrationEntry {
+            timestamp: "2026-01-01T00:00:00+00:00".to_string(),
+            iteration: 2,
+            hat: "loop".to_string(),
+            event: OrchestrationEvent::HatSelected {
+                hat: "builder".to_string(),
+                reason: "tasks_ready".to_string(),
+            },
+        };
+
+        let expected = r#"{"timestamp":"2026-01-01T00:00:00+00:00","iteration":2,"hat":"loop","event":{"type":"hat_selected","hat":"builder","reason":"tasks_ready"}}"#;
+        for _ in 0..3 {
+            assert_eq!(serde_json::to_string(&entry).unwrap(), expected);
+        }
+    }
 }
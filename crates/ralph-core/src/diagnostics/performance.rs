@@ -1,3 +1,4 @@
+use super::Severity;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fs::{File, OpenOptions};
@@ -20,6 +21,13 @@ pub enum PerformanceMetric {
     TokenCount { input: usize, output: usize },
 }
 
+impl PerformanceMetric {
+    /// Default severity for this metric. Metrics are always routine.
+    pub fn severity(&self) -> Severity {
+        Severity::Debug
+    }
+}
+
 pub struct PerformanceLogger {
     writer: BufWriter<File>,
 }
@@ -50,9 +58,12 @@ impl PerformanceLogger {
 
         serde_json::to_writer(&mut self.writer, &entry)?;
         writeln!(&mut self.writer)?;
-        self.writer.flush()?;
         Ok(())
     }
+
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
 }
 
 #[cfg(test)]
@@ -79,6 +79,11 @@ pub struct EventRecord {
     /// How many times this task has blocked (optional).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub blocked_count: Option<u32>,
+
+    /// Whether the backend execution of the logging iteration succeeded
+    /// (absent for agent-written events and loop-level records).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
 }
 
 impl EventRecord {
@@ -116,6 +121,7 @@ impl EventRecord {
             triggered: triggered.map(|h| h.to_string()),
             payload,
             blocked_count: None,
+            success: None,
         }
     }
 
@@ -124,6 +130,12 @@ impl EventRecord {
         self.blocked_count = Some(count);
         self
     }
+
+    /// Sets the backend execution outcome for this record.
+    pub fn with_success(mut self, success: bool) -> Self {
+        self.success = Some(success);
+        self
+    }
 }
 
 /// Logger that writes events to a JSONL file.
@@ -3,9 +3,16 @@
 //! This module contains the `LoopState` struct that tracks the current
 //! state of the orchestration loop including iteration count, failures,
 //! timing, and hat activation tracking.
+//!
+//! The state can be saved to and loaded from a JSON file so a loop that
+//! crashes mid-objective resumes its counters on restart instead of starting
+//! fresh. `Instant`-based timers are stored as elapsed seconds.
 
 use ralph_proto::HatId;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::Path;
 use std::time::{Duration, Instant};
 
 /// Current state of the event loop.
@@ -33,27 +40,53 @@ pub struct LoopState {
     pub abandoned_task_redispatches: u32,
     /// Consecutive malformed JSONL lines encountered (for validation backpressure).
     pub consecutive_malformed_events: u32,
+    /// Valid events seen since the malformed counter last decayed.
+    pub valid_events_since_malformed: u32,
     /// Whether a completion event has been observed in JSONL.
     pub completion_requested: bool,
+    /// Whether a `verify.passed` meeting quality thresholds has been seen.
+    pub verify_passed_seen: bool,
 
     /// Per-hat activation counts (used for max_activations).
     pub hat_activation_counts: HashMap<HatId, u32>,
 
+    /// Cumulative cost attributed to each hat.
+    pub hat_costs: HashMap<HatId, f64>,
+
+    /// Number of events produced on each topic.
+    pub topic_counts: HashMap<String, u32>,
+
     /// Hats for which `<hat_id>.exhausted` has been emitted.
     pub exhausted_hats: HashSet<HatId>,
 
     /// When the last Telegram check-in message was sent.
     /// `None` means no check-in has been sent yet.
     pub last_checkin_at: Option<Instant>,
+
+    /// Whether the `budget.warning` soft-limit event has been published.
+    pub soft_limit_warned: bool,
+
+    /// Whether side effects (scratchpad, state persistence) are suppressed.
+    pub dry_run: bool,
+
+    /// When the previous iteration ended (loop start before the first one).
+    pub iteration_checkpoint_at: Instant,
+    /// Cumulative cost when the previous iteration ended.
+    pub iteration_checkpoint_cost: f64,
+    /// Cost accrued during the most recent iteration.
+    pub last_iteration_cost: f64,
+    /// Wall-clock time taken by the most recent iteration.
+    pub last_iteration_duration: Duration,
 }
 
 impl Default for LoopState {
     fn default() -> Self {
+        let now = Instant::now();
         Self {
             iteration: 0,
             consecutive_failures: 0,
             cumulative_cost: 0.0,
-            started_at: Instant::now(),
+            started_at: now,
             last_hat: None,
             consecutive_blocked: 0,
             last_blocked_hat: None,
@@ -61,10 +94,20 @@ impl Default for LoopState {
             abandoned_tasks: Vec::new(),
             abandoned_task_redispatches: 0,
             consecutive_malformed_events: 0,
+            valid_events_since_malformed: 0,
             completion_requested: false,
+            verify_passed_seen: false,
             hat_activation_counts: HashMap::new(),
+            hat_costs: HashMap::new(),
+            topic_counts: HashMap::new(),
+            soft_limit_warned: false,
             exhausted_hats: HashSet::new(),
             last_checkin_at: None,
+            dry_run: false,
+            iteration_checkpoint_at: now,
+            iteration_checkpoint_cost: 0.0,
+            last_iteration_cost: 0.0,
+            last_iteration_duration: Duration::ZERO,
         }
     }
 }
@@ -79,4 +122,175 @@ impl LoopState {
     pub fn elapsed(&self) -> Duration {
         self.started_at.elapsed()
     }
+
+    /// Records the end of an iteration, computing its cost and duration deltas.
+    pub fn record_iteration_end(&mut self) {
+        let now = Instant::now();
+        self.last_iteration_cost = self.cumulative_cost - self.iteration_checkpoint_cost;
+        self.last_iteration_duration = now.duration_since(self.iteration_checkpoint_at);
+        self.iteration_checkpoint_at = now;
+        self.iteration_checkpoint_cost = self.cumulative_cost;
+    }
+
+    /// Returns the cost accrued during the most recent iteration.
+    pub fn last_iteration_cost(&self) -> f64 {
+        self.last_iteration_cost
+    }
+
+    /// Returns the wall-clock time taken by the most recent iteration.
+    pub fn last_iteration_duration(&self) -> Duration {
+        self.last_iteration_duration
+    }
+
+    /// Counts one event produced on `topic`.
+    pub fn record_topic(&mut self, topic: &str) {
+        *self.topic_counts.entry(topic.to_string()).or_insert(0) += 1;
+    }
+
+    /// Returns per-hat costs, highest first (ties ordered by hat ID).
+    pub fn cost_breakdown(&self) -> Vec<(HatId, f64)> {
+        let mut breakdown: Vec<_> = self
+            .hat_costs
+            .iter()
+            .map(|(hat, cost)| (hat.clone(), *cost))
+            .collect();
+        breakdown.sort_by(|a, b| {
+            b.1.total_cmp(&a.1)
+                .then_with(|| a.0.as_str().cmp(b.0.as_str()))
+        });
+        breakdown
+    }
+
+    /// Saves the state to `path` as JSON.
+    ///
+    /// Writes to a temporary sibling file and renames it into place, so a
+    /// crash mid-write never leaves a truncated state file behind.
+    pub fn save_to(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&PersistedLoopState::from(self)).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("loop state serialization failed: {e}"),
+            )
+        })?;
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, json)?;
+        std::fs::rename(&tmp_path, path)
+    }
+
+    /// Loads state previously written by [`LoopState::save_to`].
+    ///
+    /// The start time is rehydrated so that [`LoopState::elapsed`] continues
+    /// from the saved value. Check-in timing is not persisted.
+    pub fn load_from(path: &Path) -> io::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let persisted: PersistedLoopState = serde_json::from_str(&content).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("loop state deserialization failed: {e}"),
+            )
+        })?;
+        Ok(persisted.into())
+    }
+
+    /// Saves state to `path`; equivalent to [`LoopState::save_to`].
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        self.save_to(path)
+    }
+
+    /// Loads state from `path`; equivalent to [`LoopState::load_from`].
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Self::load_from(path)
+    }
+}
+
+/// On-disk representation of [`LoopState`].
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedLoopState {
+    iteration: u32,
+    consecutive_failures: u32,
+    cumulative_cost: f64,
+    elapsed_secs: f64,
+    last_hat: Option<HatId>,
+    consecutive_blocked: u32,
+    last_blocked_hat: Option<HatId>,
+    #[serde(default)]
+    task_block_counts: HashMap<String, u32>,
+    #[serde(default)]
+    abandoned_tasks: Vec<String>,
+    abandoned_task_redispatches: u32,
+    consecutive_malformed_events: u32,
+    #[serde(default)]
+    hat_activation_counts: HashMap<HatId, u32>,
+    #[serde(default)]
+    hat_costs: HashMap<HatId, f64>,
+    #[serde(default)]
+    topic_counts: HashMap<String, u32>,
+    #[serde(default)]
+    soft_limit_warned: bool,
+    #[serde(default)]
+    verify_passed_seen: bool,
+    #[serde(default)]
+    exhausted_hats: HashSet<HatId>,
+}
+
+impl From<&LoopState> for PersistedLoopState {
+    fn from(state: &LoopState) -> Self {
+        Self {
+            iteration: state.iteration,
+            consecutive_failures: state.consecutive_failures,
+            cumulative_cost: state.cumulative_cost,
+            elapsed_secs: state.elapsed().as_secs_f64(),
+            last_hat: state.last_hat.clone(),
+            consecutive_blocked: state.consecutive_blocked,
+            last_blocked_hat: state.last_blocked_hat.clone(),
+            task_block_counts: state.task_block_counts.clone(),
+            abandoned_tasks: state.abandoned_tasks.clone(),
+            abandoned_task_redispatches: state.abandoned_task_redispatches,
+            consecutive_malformed_events: state.consecutive_malformed_events,
+            hat_activation_counts: state.hat_activation_counts.clone(),
+            hat_costs: state.hat_costs.clone(),
+            topic_counts: state.topic_counts.clone(),
+            soft_limit_warned: state.soft_limit_warned,
+            verify_passed_seen: state.verify_passed_seen,
+            exhausted_hats: state.exhausted_hats.clone(),
+        }
+    }
+}
+
+impl From<PersistedLoopState> for LoopState {
+    fn from(persisted: PersistedLoopState) -> Self {
+        let elapsed = Duration::try_from_secs_f64(persisted.elapsed_secs).unwrap_or_default();
+        let now = Instant::now();
+        Self {
+            iteration: persisted.iteration,
+            consecutive_failures: persisted.consecutive_failures,
+            cumulative_cost: persisted.cumulative_cost,
+            started_at: now.checked_sub(elapsed).unwrap_or(now),
+            last_hat: persisted.last_hat,
+            consecutive_blocked: persisted.consecutive_blocked,
+            last_blocked_hat: persisted.last_blocked_hat,
+            task_block_counts: persisted.task_block_counts,
+            abandoned_tasks: persisted.abandoned_tasks,
+            abandoned_task_redispatches: persisted.abandoned_task_redispatches,
+            consecutive_malformed_events: persisted.consecutive_malformed_events,
+            valid_events_since_malformed: 0,
+            // A restarted loop must observe completion again.
+            completion_requested: false,
+            verify_passed_seen: persisted.verify_passed_seen,
+            hat_activation_counts: persisted.hat_activation_counts,
+            hat_costs: persisted.hat_costs,
+            topic_counts: persisted.topic_counts,
+            soft_limit_warned: persisted.soft_limit_warned,
+            exhausted_hats: persisted.exhausted_hats,
+            last_checkin_at: None,
+            dry_run: false,
+            iteration_checkpoint_at: now,
+            iteration_checkpoint_cost: persisted.cumulative_cost,
+            last_iteration_cost: 0.0,
+            last_iteration_duration: Duration::ZERO,
+        }
+    }
 }
@@ -8,21 +8,28 @@ mod tests;
 
 pub use loop_state::LoopState;
 
-use crate::config::{HatBackend, InjectMode, RalphConfig};
-use crate::event_parser::{EventParser, MutationEvidence, MutationStatus};
+use crate::config::{
+    CompletionPosition, HatBackend, InjectMode, MemoryBudgetMode, OrphanEventMode,
+    QualityReportMode, RalphConfig, TruncateMode,
+};
+use crate::event_parser::{BackpressureThresholds, EventParser, MutationEvidence, MutationStatus};
 use crate::event_reader::EventReader;
 use crate::hat_registry::HatRegistry;
 use crate::hatless_ralph::HatlessRalph;
 use crate::instructions::InstructionBuilder;
 use crate::loop_context::LoopContext;
-use crate::memory_store::{MarkdownMemoryStore, format_memories_as_markdown, truncate_to_budget};
+use crate::memory_store::{
+    DEFAULT_MEMORIES_PATH, MarkdownMemoryStore, format_memories_as_markdown,
+    format_memories_within_budget, truncate_to_budget,
+};
 use crate::skill_registry::SkillRegistry;
 use crate::text::floor_char_boundary;
-use ralph_proto::{CheckinContext, Event, EventBus, Hat, HatId, RobotService};
-use std::path::PathBuf;
+use ralph_proto::{CheckinContext, Event, EventBus, Hat, HatId, RobotService, Topic};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
 /// Reason the event loop terminated.
@@ -99,6 +106,151 @@ impl TerminationReason {
     }
 }
 
+/// Truncates scratchpad content to `char_budget`, cutting at line boundaries.
+///
+/// The dropped part is replaced by a comment noting how many chars were
+/// omitted and which markdown headings they contained.
+fn truncate_scratchpad(content: &str, char_budget: usize, mode: TruncateMode) -> String {
+    // First line boundary at or after `index`
+    let line_start_after = |index: usize| {
+        let index = floor_char_boundary(content, index);
+        content[index..].find('\n').map_or(index, |n| index + n + 1)
+    };
+    // Last line boundary at or before `index`
+    let line_end_before = |index: usize| {
+        let index = floor_char_boundary(content, index);
+        content[..index].rfind('\n').map_or(index, |n| n + 1)
+    };
+
+    match mode {
+        TruncateMode::Tail => {
+            let line_start = line_start_after(content.len() - char_budget);
+            let notice = truncation_notice("earlier", &content[..line_start]);
+            format!("{}\n\n{}", notice, &content[line_start..])
+        }
+        TruncateMode::Head => {
+            let line_end = line_end_before(char_budget);
+            let notice = truncation_notice("later", &content[line_end..]);
+            format!("{}\n{}", &content[..line_end], notice)
+        }
+        TruncateMode::Middle => {
+            let head_end = line_end_before(char_budget / 2);
+            let tail_start =
+                line_start_after(content.len() - (char_budget - head_end)).max(head_end);
+            let notice = truncation_notice("middle", &content[head_end..tail_start]);
+            format!(
+                "{}\n{}\n\n{}",
+                &content[..head_end],
+                notice,
+                &content[tail_start..]
+            )
+        }
+    }
+}
+
+/// Summarizes discarded scratchpad content by its markdown headings.
+fn truncation_notice(position: &str, discarded: &str) -> String {
+    let headings: Vec<&str> = discarded
+        .lines()
+        .filter(|line| line.starts_with('#'))
+        .collect();
+    if headings.is_empty() {
+        format!(
+            "<!-- {position} content truncated ({} chars omitted) -->",
+            discarded.len()
+        )
+    } else {
+        format!(
+            "<!-- {position} content truncated ({} chars omitted) -->\n\
+             <!-- discarded sections: {} -->",
+            discarded.len(),
+            headings.join(" | ")
+        )
+    }
+}
+
+/// Outcome of applying one batch of parsed JSONL records.
+#[derive(Debug, Default)]
+struct JsonlBatch {
+    /// True if any published event had no specific hat subscriber.
+    has_orphans: bool,
+    /// Events produced for the batch, in processing order.
+    events: Vec<Event>,
+    /// Number of events rejected by backpressure validation.
+    rejected: usize,
+}
+
+/// Machine-readable termination summary.
+///
+/// Published as the JSON payload of a `loop.terminate.json` event right after
+/// the markdown `loop.terminate` event, so tooling need not parse markdown.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TerminationSummary {
+    /// Reason string, as in [`TerminationReason::as_str`].
+    pub reason: String,
+    /// Process exit code for the reason.
+    pub exit_code: i32,
+    /// Iterations completed.
+    pub iterations: u32,
+    /// Wall-clock duration of the loop in seconds.
+    pub duration_secs: f64,
+    /// Total cost in USD.
+    pub cumulative_cost: f64,
+}
+
+/// Post-run report written by [`EventLoop::write_run_report`].
+///
+/// Maps are keyed by topic or hat ID and sorted, so reports from different
+/// runs diff cleanly.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RunReport {
+    /// Termination reason, exit code, iterations, duration, and total cost.
+    pub summary: TerminationSummary,
+    /// Events produced per topic.
+    pub topic_counts: BTreeMap<String, u32>,
+    /// Cost attributed to each hat in USD, highest first.
+    pub cost_breakdown: Vec<HatCost>,
+    /// Times each hat was activated.
+    pub hat_activations: BTreeMap<String, u32>,
+    /// Tasks abandoned after repeated `build.blocked` events.
+    pub abandoned_tasks: Vec<String>,
+    /// Times an already-abandoned task was dispatched again.
+    pub abandoned_task_redispatches: u32,
+}
+
+/// Cost attributed to one hat in a [`RunReport`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct HatCost {
+    pub hat: String,
+    pub cost_usd: f64,
+}
+
+/// Topics the event loop itself publishes, so hats may subscribe to them.
+pub(crate) const BUILTIN_EMITTED_TOPICS: &[&str] = &[
+    "task.start",
+    "task.resume",
+    "human.response",
+    "human.guidance",
+    "build.blocked",
+    "budget.warning",
+    "event.malformed",
+];
+
+/// Topics the event loop consumes itself, so hats may publish them.
+pub(crate) const BUILTIN_CONSUMED_TOPICS: &[&str] = &["human.interact"];
+
+/// Errors returned by [`EventLoop::inject_event`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum InjectError {
+    /// The event has an empty topic.
+    #[error("event topic must not be empty")]
+    EmptyTopic,
+
+    /// The topic is published only by the orchestrator itself.
+    #[error("topic '{0}' is reserved for the orchestrator")]
+    ReservedTopic(String),
+}
+
 /// The main event loop orchestrator.
 pub struct EventLoop {
     config: RalphConfig,
@@ -120,6 +272,8 @@ pub struct EventLoop {
     /// Robot service for human-in-the-loop communication.
     /// Injected externally when `human.enabled` is true and this is the primary loop.
     robot_service: Option<Box<dyn RobotService>>,
+    /// Iteration and time of the last state persistence, for `persistence_interval`.
+    last_persisted: (u32, Instant),
 }
 
 impl EventLoop {
@@ -163,6 +317,8 @@ impl EventLoop {
         context: LoopContext,
         diagnostics: crate::diagnostics::DiagnosticsCollector,
     ) -> Self {
+        let diagnostics =
+            diagnostics.with_event_buffer_capacity(config.event_loop.diagnostics_buffer_size);
         let registry = HatRegistry::from_config(&config);
         let instruction_builder =
             InstructionBuilder::with_events(config.core.clone(), config.events.clone());
@@ -224,22 +380,15 @@ impl EventLoop {
         .with_memories_enabled(config.memories.enabled)
         .with_skill_index(skill_index);
 
-        // Read timestamped events path from marker file, fall back to default
-        // The marker file contains a relative path like ".ralph/events-20260127-123456.jsonl"
-        // which we resolve relative to the workspace root
-        let events_path = std::fs::read_to_string(context.current_events_marker())
-            .map(|s| {
-                let relative = s.trim();
-                context.workspace().join(relative)
-            })
-            .unwrap_or_else(|_| context.events_path());
-        let event_reader = EventReader::new(&events_path);
+        let event_reader = EventReader::new(resolve_events_path(Some(&context)));
+        let state = restore_loop_state(&context.loop_state_path());
+        let last_persisted = (state.iteration, Instant::now());
 
         Self {
             config,
             registry,
             bus,
-            state: LoopState::new(),
+            state,
             instruction_builder,
             ralph,
             robot_guidance: Vec::new(),
@@ -248,6 +397,7 @@ impl EventLoop {
             loop_context: Some(context),
             skill_registry,
             robot_service: None,
+            last_persisted,
         }
     }
 
@@ -256,6 +406,8 @@ impl EventLoop {
         config: RalphConfig,
         diagnostics: crate::diagnostics::DiagnosticsCollector,
     ) -> Self {
+        let diagnostics =
+            diagnostics.with_event_buffer_capacity(config.event_loop.diagnostics_buffer_size);
         let registry = HatRegistry::from_config(&config);
         let instruction_builder =
             InstructionBuilder::with_events(config.core.clone(), config.events.clone());
@@ -318,12 +470,7 @@ impl EventLoop {
         .with_memories_enabled(config.memories.enabled)
         .with_skill_index(skill_index);
 
-        // Read events path from marker file, fall back to default if not present
-        // The marker file is written by run_loop_impl() at run startup
-        let events_path = std::fs::read_to_string(".ralph/current-events")
-            .map(|s| s.trim().to_string())
-            .unwrap_or_else(|_| ".ralph/events.jsonl".to_string());
-        let event_reader = EventReader::new(&events_path);
+        let event_reader = EventReader::new(resolve_events_path(None));
 
         Self {
             config,
@@ -338,6 +485,7 @@ impl EventLoop {
             loop_context: None,
             skill_registry,
             robot_service: None,
+            last_persisted: (0, Instant::now()),
         }
     }
 
@@ -373,11 +521,57 @@ impl EventLoop {
             .unwrap_or_else(|| PathBuf::from(&self.config.core.scratchpad))
     }
 
+    /// Returns the active events file path.
+    ///
+    /// Re-reads the current-events marker on each call so it always reflects
+    /// the file the agent is writing to. See [`resolve_events_path`].
+    pub fn events_path(&self) -> PathBuf {
+        resolve_events_path(self.loop_context.as_ref())
+    }
+
+    /// Returns the memories file path.
+    ///
+    /// Resolution order: `memories.path` from config (relative paths resolved
+    /// against the workspace), then the loop context's memories path, then
+    /// `.ralph/agent/memories.md` under the workspace root.
+    pub fn memories_path(&self) -> PathBuf {
+        let workspace = self
+            .loop_context
+            .as_ref()
+            .map_or(self.config.core.workspace_root.as_path(), |ctx| {
+                ctx.workspace()
+            });
+
+        self.config
+            .memories
+            .resolve_path(workspace)
+            .unwrap_or_else(|| {
+                self.loop_context
+                    .as_ref()
+                    .map(|ctx| ctx.memories_path())
+                    .unwrap_or_else(|| workspace.join(DEFAULT_MEMORIES_PATH))
+            })
+    }
+
     /// Returns the current loop state.
     pub fn state(&self) -> &LoopState {
         &self.state
     }
 
+    /// Enables or disables dry-run mode.
+    ///
+    /// In dry-run mode events still route through the bus, but nothing is
+    /// written to disk: scratchpad guidance, loop-state persistence and
+    /// state-file cleanup are logged instead of performed.
+    pub fn set_dry_run(&mut self, dry_run: bool) {
+        self.state.dry_run = dry_run;
+    }
+
+    /// Returns true if dry-run mode is enabled.
+    pub fn is_dry_run(&self) -> bool {
+        self.state.dry_run
+    }
+
     /// Returns the configuration.
     pub fn config(&self) -> &RalphConfig {
         &self.config
@@ -423,6 +617,33 @@ impl EventLoop {
 
     /// Checks if any termination condition is met.
     pub fn check_termination(&self) -> Option<TerminationReason> {
+        if let Some(reason) = self.check_limits() {
+            return Some(reason);
+        }
+
+        // Check for stop signal from Telegram /stop or CLI stop-requested
+        let stop_path =
+            std::path::Path::new(&self.config.core.workspace_root).join(".ralph/stop-requested");
+        if stop_path.exists() {
+            let _ = std::fs::remove_file(&stop_path);
+            return Some(TerminationReason::Stopped);
+        }
+
+        // Check for restart signal from Telegram /restart command
+        let restart_path =
+            std::path::Path::new(&self.config.core.workspace_root).join(".ralph/restart-requested");
+        if restart_path.exists() {
+            return Some(TerminationReason::RestartRequested);
+        }
+
+        None
+    }
+
+    /// Checks the termination limits derived from loop state alone.
+    ///
+    /// Unlike [`check_termination`](Self::check_termination), this never looks
+    /// at (or consumes) the stop and restart signal files.
+    fn check_limits(&self) -> Option<TerminationReason> {
         let cfg = &self.config.event_loop;
 
         if self.state.iteration >= cfg.max_iterations {
@@ -444,30 +665,19 @@ impl EventLoop {
         }
 
         // Check for loop thrashing: planner keeps dispatching abandoned tasks
-        if self.state.abandoned_task_redispatches >= 3 {
+        if cfg.max_abandoned_redispatches > 0
+            && self.state.abandoned_task_redispatches as usize >= cfg.max_abandoned_redispatches
+        {
             return Some(TerminationReason::LoopThrashing);
         }
 
         // Check for validation failures: too many consecutive malformed JSONL lines
-        if self.state.consecutive_malformed_events >= 3 {
+        if cfg.max_malformed_events > 0
+            && self.state.consecutive_malformed_events as usize >= cfg.max_malformed_events
+        {
             return Some(TerminationReason::ValidationFailure);
         }
 
-        // Check for stop signal from Telegram /stop or CLI stop-requested
-        let stop_path =
-            std::path::Path::new(&self.config.core.workspace_root).join(".ralph/stop-requested");
-        if stop_path.exists() {
-            let _ = std::fs::remove_file(&stop_path);
-            return Some(TerminationReason::Stopped);
-        }
-
-        // Check for restart signal from Telegram /restart command
-        let restart_path =
-            std::path::Path::new(&self.config.core.workspace_root).join(".ralph/restart-requested");
-        if restart_path.exists() {
-            return Some(TerminationReason::RestartRequested);
-        }
-
         None
     }
 
@@ -481,6 +691,27 @@ impl EventLoop {
 
         self.state.completion_requested = false;
 
+        if self.config.event_loop.require_verify_before_completion && !self.state.verify_passed_seen
+        {
+            warn!("Completion event rejected - no passing verify.passed seen yet");
+
+            self.diagnostics.log_orchestration(
+                self.state.iteration,
+                "loop",
+                crate::diagnostics::OrchestrationEvent::BackpressureTriggered {
+                    reason: "completion requires a prior passing verify.passed".to_string(),
+                },
+            );
+
+            self.bus.publish(Event::new(
+                "task.resume",
+                "Completion rejected: a passing `verify.passed` (with quality report) \
+                 is required before the completion promise is accepted.",
+            ));
+
+            return None;
+        }
+
         // In persistent mode, suppress completion and keep the loop alive
         if self.config.event_loop.persistent {
             info!("Completion event suppressed - persistent mode active, loop staying alive");
@@ -574,7 +805,7 @@ impl EventLoop {
     /// - Solo mode (no custom hats): Returns "ralph" if Ralph has pending events
     /// - Multi-hat mode (custom hats defined): Always returns "ralph" if ANY hat has pending events
     pub fn next_hat(&self) -> Option<&HatId> {
-        let next = self.bus.next_hat_with_pending();
+        let next = self.select_highest_priority_hat();
 
         // If no pending hat events but human interactions are pending, route to Ralph.
         if next.is_none() && self.bus.has_human_pending() {
@@ -596,6 +827,26 @@ impl EventLoop {
         }
     }
 
+    /// Returns the highest-priority hat with pending events.
+    ///
+    /// Priority comes from `HatConfig::priority` (higher first); ties break
+    /// by hat id so selection is deterministic.
+    pub fn select_highest_priority_hat(&self) -> Option<&HatId> {
+        self.bus
+            .hat_ids()
+            .filter(|id| {
+                self.bus
+                    .peek_pending(id)
+                    .is_some_and(|events| !events.is_empty())
+            })
+            .min_by(|a, b| self.compare_hat_priority(a, b))
+    }
+
+    /// Orders hats by descending priority, then ascending id.
+    fn compare_hat_priority(&self, a: &HatId, b: &HatId) -> std::cmp::Ordering {
+        self.registry.compare_priority(a, b)
+    }
+
     /// Checks if any hats have pending events.
     ///
     /// Use this after `process_output` to detect if the LLM failed to publish an event.
@@ -669,6 +920,19 @@ impl EventLoop {
     /// primed memories to the prompt context. If a scratchpad file exists and is
     /// non-empty, its content is also prepended (before memories).
     pub fn build_prompt(&mut self, hat_id: &HatId) -> Option<String> {
+        self.build_prompt_with_breakdown(hat_id)
+            .map(|(prompt, _)| prompt)
+    }
+
+    /// Builds the prompt for a hat's execution along with per-section size accounting.
+    ///
+    /// Behaves exactly like [`build_prompt`](Self::build_prompt), including consuming
+    /// pending events, but also reports how many bytes each injected block contributes.
+    /// Useful when debugging context-window overflows or tuning injection budgets.
+    pub fn build_prompt_with_breakdown(
+        &mut self,
+        hat_id: &HatId,
+    ) -> Option<(String, PromptBreakdown)> {
         // Handle "ralph" hat - the constant coordinator
         // Per spec: "Hatless Ralph is constant — Cannot be replaced, overwritten, or configured away"
         if hat_id.as_str() == "ralph" {
@@ -685,7 +949,7 @@ impl EventLoop {
 
                 let events_context = regular_events
                     .iter()
-                    .map(|e| Self::format_event(e))
+                    .map(|e| self.format_event(e))
                     .collect::<Vec<_>>()
                     .join("\n");
 
@@ -696,23 +960,30 @@ impl EventLoop {
                 // Build base prompt and prepend memories + scratchpad + ready tasks
                 let base_prompt = self.ralph.build_prompt(&events_context, &[]);
                 self.ralph.clear_robot_guidance();
-                let with_skills = self.prepend_auto_inject_skills(base_prompt);
-                let with_scratchpad = self.prepend_scratchpad(with_skills);
-                let final_prompt = self.prepend_ready_tasks(with_scratchpad);
 
                 debug!("build_prompt: routing to HatlessRalph (solo mode)");
-                return Some(final_prompt);
+                return Some(self.assemble_prompt(base_prompt, &[]));
             } else {
                 // Multi-hat mode: collect events and determine active hats
                 let mut all_hat_ids: Vec<HatId> = self.bus.hat_ids().cloned().collect();
-                // Deterministic ordering (avoid HashMap iteration order nondeterminism).
-                all_hat_ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+                // Highest priority first, then by id (avoid HashMap iteration order nondeterminism).
+                all_hat_ids.sort_by(|a, b| self.compare_hat_priority(a, b));
 
                 let mut all_events = Vec::new();
                 let mut system_events = Vec::new();
+                let max_events_per_hat = self.config.event_loop.max_events_per_hat;
 
                 for id in &all_hat_ids {
-                    let pending = self.bus.take_pending(id);
+                    let pending = match max_events_per_hat {
+                        Some(max) => {
+                            let mut taken = 0;
+                            self.bus.take_pending_where(id, |_| {
+                                taken += 1;
+                                taken <= max.max(1)
+                            })
+                        }
+                        None => self.bus.take_pending(id),
+                    };
                     if pending.is_empty() {
                         continue;
                     }
@@ -757,7 +1028,7 @@ impl EventLoop {
                 // Format events for context
                 let events_context = regular_events
                     .iter()
-                    .map(|e| Self::format_event(e))
+                    .map(|e| self.format_event(e))
                     .collect::<Vec<_>>()
                     .join("\n");
 
@@ -775,11 +1046,8 @@ impl EventLoop {
 
                 // Clear guidance after active_hats references are no longer needed
                 self.ralph.clear_robot_guidance();
-                let with_skills = self.prepend_auto_inject_skills(base_prompt);
-                let with_scratchpad = self.prepend_scratchpad(with_skills);
-                let final_prompt = self.prepend_ready_tasks(with_scratchpad);
 
-                return Some(final_prompt);
+                return Some(self.assemble_prompt(base_prompt, &active_hat_ids));
             }
         }
 
@@ -789,7 +1057,7 @@ impl EventLoop {
         let events = self.bus.take_pending(&hat_id.clone());
         let events_context = events
             .iter()
-            .map(|e| Self::format_event(e))
+            .map(|e| self.format_event(e))
             .collect::<Vec<_>>()
             .join("\n");
 
@@ -807,10 +1075,14 @@ impl EventLoop {
             "build_prompt: routing to build_custom_hat() for '{}'",
             hat_id.as_str()
         );
-        Some(
-            self.instruction_builder
-                .build_custom_hat(hat, &events_context),
-        )
+        let prompt = self
+            .instruction_builder
+            .build_custom_hat(hat, &events_context);
+        let breakdown = PromptBreakdown {
+            base: prompt.len(),
+            ..PromptBreakdown::default()
+        };
+        Some((prompt, breakdown))
     }
 
     /// Stores guidance payloads, persists them to scratchpad, and prepares them for prompt injection.
@@ -844,6 +1116,15 @@ impl EventLoop {
             scratchpad_path
         };
 
+        if self.state.dry_run {
+            info!(
+                count = guidance_events.len(),
+                path = %resolved_path.display(),
+                "Dry run: would persist human guidance to scratchpad"
+            );
+            return;
+        }
+
         // Create parent directories if needed
         if let Some(parent) = resolved_path.parent()
             && !parent.exists()
@@ -891,7 +1172,47 @@ impl EventLoop {
         self.ralph.set_robot_guidance(self.robot_guidance.clone());
     }
 
-    /// Prepends auto-injected skill content to the prompt.
+    /// Prepends auto-injected context to a base prompt, recording each block's size.
+    ///
+    /// Final layout, top to bottom: ready tasks, scratchpad, memories, skills, base prompt.
+    ///
+    /// Skills are gated by hat: Ralph (always the executor) plus any hats activated
+    /// by this iteration's events are considered when applying skill hat restrictions.
+    /// In solo mode there are no hats to gate on, so every skill is visible.
+    fn assemble_prompt(
+        &self,
+        base_prompt: String,
+        active_hat_ids: &[HatId],
+    ) -> (String, PromptBreakdown) {
+        let skill_hats: Vec<&str> = if self.registry.is_empty() {
+            Vec::new()
+        } else {
+            std::iter::once("ralph")
+                .chain(active_hat_ids.iter().map(|id| id.as_str()))
+                .collect()
+        };
+        let (memories, skills) = self.auto_inject_skill_sections(&skill_hats);
+        let scratchpad = self.scratchpad_section();
+        let ready_tasks = self.ready_tasks_section();
+
+        let breakdown = PromptBreakdown {
+            base: base_prompt.len(),
+            memories: memories.len(),
+            skills: skills.len(),
+            scratchpad: scratchpad.len(),
+            ready_tasks: ready_tasks.len(),
+        };
+
+        let mut prompt = String::with_capacity(breakdown.total());
+        prompt.push_str(&ready_tasks);
+        prompt.push_str(&scratchpad);
+        prompt.push_str(&memories);
+        prompt.push_str(&skills);
+        prompt.push_str(&base_prompt);
+        (prompt, breakdown)
+    }
+
+    /// Renders auto-injected skill content as `(memories, skills)` sections.
     ///
     /// This generalizes the former `prepend_memories()` into a skill auto-injection
     /// pipeline that handles memories, tools, and any other auto-inject skills.
@@ -900,35 +1221,50 @@ impl EventLoop {
     /// 1. Memory data + ralph-tools skill (special case: loads memory data from store, applies budget)
     /// 2. RObot interaction skill (gated by `robot.enabled`)
     /// 3. Other auto-inject skills from the registry (wrapped in XML tags)
-    fn prepend_auto_inject_skills(&self, prompt: String) -> String {
-        let mut prefix = String::new();
+    ///
+    /// Every injected block is followed by a blank line separating it from the next.
+    /// Skills restricted to specific hats are only injected when one of `hat_ids` matches.
+    fn auto_inject_skill_sections(&self, hat_ids: &[&str]) -> (String, String) {
+        let mut memories = String::new();
+        let mut skills = String::new();
 
         // 1. Memory data + ralph-tools skill — special case with data loading
-        self.inject_memories_and_tools_skill(&mut prefix);
+        self.inject_memories_and_tools_skill(&mut memories, &mut skills, hat_ids);
 
         // 2. RObot interaction skill — gated by robot.enabled
-        self.inject_robot_skill(&mut prefix);
+        self.inject_robot_skill(&mut skills, hat_ids);
 
         // 3. Other auto-inject skills from the registry
-        self.inject_custom_auto_skills(&mut prefix);
+        self.inject_custom_auto_skills(&mut skills, hat_ids);
 
-        if prefix.is_empty() {
-            return prompt;
-        }
+        (memories, skills)
+    }
 
-        prefix.push_str("\n\n");
-        prefix.push_str(&prompt);
-        prefix
+    /// Derives lowercase keyword tags from the loop objective.
+    ///
+    /// Uses [`text::keywords`](crate::text::keywords), so "Fix the auth login flow" yields
+    /// `["fix", "auth", "login", "flow"]`.
+    pub fn objective_tags(&self) -> Vec<String> {
+        self.ralph
+            .objective()
+            .map(|objective| crate::text::keywords(objective, &[]))
+            .unwrap_or_default()
     }
 
-    /// Injects memory data and the ralph-tools skill into the prefix.
+    /// Injects memory data and the ralph-tools skill.
     ///
     /// Special case: loads memory entries from the store, applies budget
-    /// truncation, then appends the ralph-tools skill content (which covers
-    /// both tasks and memories CLI usage).
+    /// truncation and writes them to `memories`, then appends the ralph-tools
+    /// skill content (which covers both tasks and memories CLI usage) to `skills`.
     /// Memory data is gated by `memories.enabled && memories.inject == Auto`.
-    /// The ralph-tools skill is injected when either memories or tasks are enabled.
-    fn inject_memories_and_tools_skill(&self, prefix: &mut String) {
+    /// The ralph-tools skill is injected when either memories or tasks are enabled,
+    /// subject to its hat restrictions (`skills.overrides.ralph-tools.hats`).
+    fn inject_memories_and_tools_skill(
+        &self,
+        memories_out: &mut String,
+        skills: &mut String,
+        hat_ids: &[&str],
+    ) {
         let memories_config = &self.config.memories;
 
         // Inject memory DATA if memories are enabled with auto-inject
@@ -938,9 +1274,8 @@ impl EventLoop {
                 memories_config.enabled, memories_config.inject, self.config.core.workspace_root
             );
 
-            let workspace_root = &self.config.core.workspace_root;
-            let store = MarkdownMemoryStore::with_default_path(workspace_root);
-            let memories_path = workspace_root.join(".ralph/agent/memories.md");
+            let store = MarkdownMemoryStore::new(self.memories_path());
+            let memories_path = store.path();
 
             info!(
                 "Looking for memories at: {:?} (exists: {})",
@@ -948,7 +1283,7 @@ impl EventLoop {
                 memories_path.exists()
             );
 
-            let memories = match store.load() {
+            let mut memories = match store.load() {
                 Ok(memories) => {
                     info!("Successfully loaded {} memories from store", memories.len());
                     memories
@@ -962,6 +1297,19 @@ impl EventLoop {
                 }
             };
 
+            if !memories_config.filter.tags.is_empty() {
+                let mut wanted = memories_config.filter.tags.clone();
+                wanted.extend(self.objective_tags());
+                let before = memories.len();
+                memories.retain(|memory| memory.has_any_tag(&wanted));
+                debug!(
+                    "Tag filter kept {} of {} memories (tags: {:?})",
+                    memories.len(),
+                    before,
+                    wanted
+                );
+            }
+
             if memories.is_empty() {
                 info!("Memory store is empty - no memories to inject");
             } else {
@@ -969,13 +1317,20 @@ impl EventLoop {
 
                 if memories_config.budget > 0 {
                     let original_len = memories_content.len();
-                    memories_content =
-                        truncate_to_budget(&memories_content, memories_config.budget);
+                    memories_content = match memories_config.budget_mode {
+                        MemoryBudgetMode::Truncate => {
+                            truncate_to_budget(&memories_content, memories_config.budget)
+                        }
+                        MemoryBudgetMode::WholeMemories => {
+                            format_memories_within_budget(&memories, memories_config.budget)
+                        }
+                    };
                     debug!(
-                        "Applied budget: {} chars -> {} chars (budget: {})",
+                        "Applied budget: {} chars -> {} chars (budget: {}, mode: {:?})",
                         original_len,
                         memories_content.len(),
-                        memories_config.budget
+                        memories_config.budget,
+                        memories_config.budget_mode
                     );
                 }
 
@@ -985,74 +1340,79 @@ impl EventLoop {
                     memories_content.len()
                 );
 
-                prefix.push_str(&memories_content);
+                push_prompt_block(memories_out, &memories_content);
             }
         }
 
         // Inject the ralph-tools skill when either memories or tasks are enabled
         if memories_config.enabled || self.config.tasks.enabled {
-            if let Some(skill) = self.skill_registry.get("ralph-tools") {
-                if !prefix.is_empty() {
-                    prefix.push_str("\n\n");
-                }
-                prefix.push_str(&format!(
-                    "<ralph-tools-skill>\n{}\n</ralph-tools-skill>",
-                    skill.content.trim()
-                ));
+            if let Some(skill) = self.skill_registry.get_for_hats("ralph-tools", hat_ids) {
+                push_prompt_block(
+                    skills,
+                    &format!(
+                        "<ralph-tools-skill>\n{}\n</ralph-tools-skill>",
+                        skill.content.trim()
+                    ),
+                );
                 debug!("Injected ralph-tools skill from registry");
             } else {
-                debug!("ralph-tools skill not found in registry - skill content not injected");
+                debug!(
+                    "ralph-tools skill not found or not enabled for {:?} - skill content not injected",
+                    hat_ids
+                );
             }
         }
     }
 
-    /// Injects the RObot interaction skill content into the prefix.
+    /// Injects the RObot interaction skill content.
     ///
-    /// Gated by `robot.enabled`. Teaches agents how and when to interact
-    /// with humans via `human.interact` events.
-    fn inject_robot_skill(&self, prefix: &mut String) {
+    /// Gated by `robot.enabled` and the skill's hat restrictions. Teaches agents
+    /// how and when to interact with humans via `human.interact` events.
+    fn inject_robot_skill(&self, skills: &mut String, hat_ids: &[&str]) {
         if !self.config.robot.enabled {
             return;
         }
 
-        if let Some(skill) = self.skill_registry.get("robot-interaction") {
-            if !prefix.is_empty() {
-                prefix.push_str("\n\n");
-            }
-            prefix.push_str(&format!(
-                "<robot-skill>\n{}\n</robot-skill>",
-                skill.content.trim()
-            ));
+        if let Some(skill) = self
+            .skill_registry
+            .get_for_hats("robot-interaction", hat_ids)
+        {
+            push_prompt_block(
+                skills,
+                &format!("<robot-skill>\n{}\n</robot-skill>", skill.content.trim()),
+            );
             debug!("Injected robot interaction skill from registry");
         }
     }
 
     /// Injects any user-configured auto-inject skills (excluding built-in ralph-tools/robot-interaction).
-    fn inject_custom_auto_skills(&self, prefix: &mut String) {
-        for skill in self.skill_registry.auto_inject_skills(None) {
+    fn inject_custom_auto_skills(&self, skills: &mut String, hat_ids: &[&str]) {
+        for skill in self.skill_registry.auto_inject_skills_for_hats(hat_ids) {
             // Skip built-in skills handled above
             if skill.name == "ralph-tools" || skill.name == "robot-interaction" {
                 continue;
             }
 
-            if !prefix.is_empty() {
-                prefix.push_str("\n\n");
-            }
-            prefix.push_str(&format!(
-                "<{name}-skill>\n{content}\n</{name}-skill>",
-                name = skill.name,
-                content = skill.content.trim()
-            ));
+            push_prompt_block(
+                skills,
+                &format!(
+                    "<{name}-skill>\n{content}\n</{name}-skill>",
+                    name = skill.name,
+                    content = skill.content.trim()
+                ),
+            );
             debug!("Injected auto-inject skill: {}", skill.name);
         }
     }
 
-    /// Prepends scratchpad content to the prompt if the file exists and is non-empty.
+    /// Renders the `<scratchpad>` block if the file exists and is non-empty.
     ///
     /// The scratchpad is the agent's working memory for the current objective.
     /// Auto-injecting saves one tool call per iteration.
-    /// When the file exceeds the budget, the TAIL is kept (most recent entries).
-    fn prepend_scratchpad(&self, prompt: String) -> String {
+    /// When the file exceeds `core.scratchpad_budget_tokens`, the part selected by
+    /// `core.scratchpad_truncate` is kept (the TAIL, i.e. most recent entries, by default).
+    /// Returns an empty string when there is nothing to inject.
+    fn scratchpad_section(&self) -> String {
         let scratchpad_path = self.scratchpad_path();
 
         let resolved_path = if scratchpad_path.is_relative() {
@@ -1066,74 +1426,47 @@ impl EventLoop {
                 "Scratchpad not found at {:?}, skipping injection",
                 resolved_path
             );
-            return prompt;
+            return String::new();
         }
 
         let content = match std::fs::read_to_string(&resolved_path) {
             Ok(c) => c,
             Err(e) => {
                 info!("Failed to read scratchpad for injection: {}", e);
-                return prompt;
+                return String::new();
             }
         };
 
         if content.trim().is_empty() {
             debug!("Scratchpad is empty, skipping injection");
-            return prompt;
+            return String::new();
         }
 
-        // Budget: 4000 tokens ~16000 chars. Keep the TAIL (most recent content).
-        let char_budget = 4000 * 4;
+        // Budget: tokens ~4 chars each (4000 tokens ~16000 chars by default)
+        let char_budget = self.config.core.scratchpad_budget_tokens * 4;
         let content = if content.len() > char_budget {
-            // Find a line boundary near the start of the tail
-            let start = content.len() - char_budget;
-            // Ensure we start at a valid UTF-8 character boundary
-            let start = floor_char_boundary(&content, start);
-            let line_start = content[start..].find('\n').map_or(start, |n| start + n + 1);
-            let discarded = &content[..line_start];
-
-            // Summarize discarded content by extracting markdown headings
-            let headings: Vec<&str> = discarded
-                .lines()
-                .filter(|line| line.starts_with('#'))
-                .collect();
-            let summary = if headings.is_empty() {
-                format!(
-                    "<!-- earlier content truncated ({} chars omitted) -->",
-                    line_start
-                )
-            } else {
-                format!(
-                    "<!-- earlier content truncated ({} chars omitted) -->\n\
-                     <!-- discarded sections: {} -->",
-                    line_start,
-                    headings.join(" | ")
-                )
-            };
-
-            format!("{}\n\n{}", summary, &content[line_start..])
+            truncate_scratchpad(&content, char_budget, self.config.core.scratchpad_truncate)
         } else {
             content
         };
 
         info!("Injecting scratchpad ({} chars) into prompt", content.len());
 
-        let mut final_prompt = format!(
+        format!(
             "<scratchpad path=\"{}\">\n{}\n</scratchpad>\n\n",
             self.config.core.scratchpad, content
-        );
-        final_prompt.push_str(&prompt);
-        final_prompt
+        )
     }
 
-    /// Prepends ready tasks to the prompt if tasks are enabled and any exist.
+    /// Renders the `<ready-tasks>` block if tasks are enabled and any exist.
     ///
     /// Loads the task store and formats ready (unblocked, open) tasks into
     /// a `<ready-tasks>` XML block. This saves the agent a tool call per
     /// iteration and puts tasks at the same prominence as the scratchpad.
-    fn prepend_ready_tasks(&self, prompt: String) -> String {
+    /// Returns an empty string when there is nothing to inject.
+    fn ready_tasks_section(&self) -> String {
         if !self.config.tasks.enabled {
-            return prompt;
+            return String::new();
         }
 
         use crate::task::TaskStatus;
@@ -1147,23 +1480,23 @@ impl EventLoop {
         };
 
         if !resolved_path.exists() {
-            return prompt;
+            return String::new();
         }
 
         let store = match TaskStore::load(&resolved_path) {
             Ok(s) => s,
             Err(e) => {
                 info!("Failed to load task store for injection: {}", e);
-                return prompt;
+                return String::new();
             }
         };
 
-        let ready = store.ready();
+        let ready = store.ready_sorted(self.config.tasks.ready_task_order);
         let open = store.open();
         let closed_count = store.all().len() - open.len();
 
         if open.is_empty() && closed_count == 0 {
-            return prompt;
+            return String::new();
         }
 
         let mut section = String::from("<ready-tasks>\n");
@@ -1176,7 +1509,11 @@ impl EventLoop {
                 open.len(),
                 closed_count
             ));
-            for task in &ready {
+            let limit = match self.config.tasks.max_injected_tasks {
+                0 => ready.len(),
+                max => max.min(ready.len()),
+            };
+            for task in &ready[..limit] {
                 let status_icon = match task.status {
                     TaskStatus::Open => "[ ]",
                     TaskStatus::InProgress => "[~]",
@@ -1187,6 +1524,9 @@ impl EventLoop {
                     status_icon, task.priority, task.title, task.id
                 ));
             }
+            if limit < ready.len() {
+                section.push_str(&format!("...and {} more\n", ready.len() - limit));
+            }
             // Show blocked tasks separately so agent knows they exist
             let ready_ids: Vec<&str> = ready.iter().map(|t| t.id.as_str()).collect();
             let blocked: Vec<_> = open
@@ -1215,9 +1555,7 @@ impl EventLoop {
             closed_count
         );
 
-        let mut final_prompt = section;
-        final_prompt.push_str(&prompt);
-        final_prompt
+        section
     }
 
     /// Builds the Ralph prompt (coordination mode).
@@ -1252,13 +1590,20 @@ impl EventLoop {
 
     /// Formats an event for prompt context.
     ///
-    /// For top-level prompts (task.start, task.resume), wraps the payload in
-    /// `<top-level-prompt>` XML tags to clearly delineate the user's original request.
-    fn format_event(event: &Event) -> String {
+    /// For top-level prompts (`event_loop.top_level_topics`, by default task.start
+    /// and task.resume), wraps the payload in `<top-level-prompt>` XML tags to
+    /// clearly delineate the user's original request.
+    fn format_event(&self, event: &Event) -> String {
         let topic = &event.topic;
         let payload = &event.payload;
 
-        if topic.as_str() == "task.start" || topic.as_str() == "task.resume" {
+        if self
+            .config
+            .event_loop
+            .top_level_topics
+            .iter()
+            .any(|t| t == topic.as_str())
+        {
             format!(
                 "Event: {} - <top-level-prompt>\n{}\n</top-level-prompt>",
                 topic, payload
@@ -1392,6 +1737,50 @@ impl EventLoop {
         &mut self.bus
     }
 
+    /// Publishes an event programmatically, applying the same validation as
+    /// events read from JSONL.
+    ///
+    /// Unlike publishing through [`EventLoop::bus`], gated topics are checked
+    /// first, so e.g. a `build.done` without backpressure evidence is published
+    /// as `build.blocked`. The completion topic requests completion instead of
+    /// being published.
+    pub fn inject_event(&mut self, event: Event) -> Result<(), InjectError> {
+        let topic = event.topic.as_str();
+        if topic.trim().is_empty() {
+            return Err(InjectError::EmptyTopic);
+        }
+        if topic == "loop.terminate" || topic == "loop.terminate.json" {
+            return Err(InjectError::ReservedTopic(topic.to_string()));
+        }
+
+        if topic == self.config.event_loop.completion_promise {
+            self.state.completion_requested = true;
+            self.diagnostics.log_orchestration(
+                self.state.iteration,
+                "inject",
+                crate::diagnostics::OrchestrationEvent::EventPublished {
+                    topic: topic.to_string(),
+                },
+            );
+            return Ok(());
+        }
+
+        let (event, backpressure) = self.validate_event(event);
+        self.state.record_topic(event.topic.as_str());
+        if event.topic == "verify.passed".into() {
+            self.state.verify_passed_seen = true;
+        }
+        if let Some(reason) = backpressure {
+            self.diagnostics.log_orchestration(
+                self.state.iteration,
+                "inject",
+                crate::diagnostics::OrchestrationEvent::BackpressureTriggered { reason },
+            );
+        }
+        self.bus.publish(event);
+        Ok(())
+    }
+
     /// Processes output from a hat execution.
     ///
     /// Returns the termination reason if the loop should stop.
@@ -1403,6 +1792,7 @@ impl EventLoop {
     ) -> Option<TerminationReason> {
         self.state.iteration += 1;
         self.state.last_hat = Some(hat_id.clone());
+        self.state.record_iteration_end();
 
         // Periodic robot check-in
         if let Some(interval_secs) = self.config.robot.checkin_interval_seconds
@@ -1460,10 +1850,48 @@ impl EventLoop {
         // This enforces tool use and prevents confabulation (agent claiming to emit without actually doing so).
         // See process_events_from_jsonl() for event processing.
 
+        if self.should_persist() {
+            self.persist_state();
+            self.diagnostics.flush();
+            self.last_persisted = (self.state.iteration, Instant::now());
+        }
+
         // Check termination conditions
         self.check_termination()
     }
 
+    /// Returns true when `event_loop.persistence_interval` says state is due.
+    fn should_persist(&self) -> bool {
+        let interval = self.config.event_loop.persistence_interval;
+        let (iteration, at) = self.last_persisted;
+        let by_iterations = interval.iterations > 0
+            && self.state.iteration.saturating_sub(iteration) >= interval.iterations;
+        let by_time = interval
+            .seconds
+            .is_some_and(|secs| at.elapsed() >= Duration::from_secs(secs));
+        by_iterations || by_time
+    }
+
+    /// Writes the loop state to the context's state file for crash recovery.
+    ///
+    /// No-op in legacy mode (no loop context). Failures are logged, not fatal.
+    fn persist_state(&self) {
+        let Some(ctx) = &self.loop_context else {
+            return;
+        };
+        if self.state.dry_run {
+            info!(
+                iteration = self.state.iteration,
+                path = %ctx.loop_state_path().display(),
+                "Dry run: would persist loop state"
+            );
+            return;
+        }
+        if let Err(e) = self.state.save_to(&ctx.loop_state_path()) {
+            warn!(error = %e, "Failed to persist loop state");
+        }
+    }
+
     /// Extracts task identifier from build.blocked payload.
     /// Uses first line of payload as task ID.
     fn extract_task_id(payload: &str) -> String {
@@ -1476,8 +1904,51 @@ impl EventLoop {
     }
 
     /// Adds cost to the cumulative total.
+    ///
+    /// The cost is attributed to the last active hat, if any.
     pub fn add_cost(&mut self, cost: f64) {
+        match self.state.last_hat.clone() {
+            Some(hat) => self.add_cost_for_hat(&hat, cost),
+            None => {
+                self.state.cumulative_cost += cost;
+                self.check_cost_soft_limit();
+            }
+        }
+    }
+
+    /// Adds cost to the cumulative total and to `hat`'s running total.
+    pub fn add_cost_for_hat(&mut self, hat: &HatId, cost: f64) {
         self.state.cumulative_cost += cost;
+        *self.state.hat_costs.entry(hat.clone()).or_insert(0.0) += cost;
+        self.check_cost_soft_limit();
+    }
+
+    /// Publishes a one-time `budget.warning` once `cost_soft_limit_usd` is crossed.
+    fn check_cost_soft_limit(&mut self) {
+        let Some(soft_limit) = self.config.event_loop.cost_soft_limit_usd else {
+            return;
+        };
+        if self.state.soft_limit_warned || self.state.cumulative_cost < soft_limit {
+            return;
+        }
+        self.state.soft_limit_warned = true;
+
+        let hard_limit = self
+            .config
+            .event_loop
+            .max_cost_usd
+            .map(|max| format!(" The hard limit is ${max:.2}."))
+            .unwrap_or_default();
+        let payload = format!(
+            "Budget soft limit reached: ${:.2} spent of ${soft_limit:.2}.{hard_limit} Wrap up the current work, update the scratchpad, and emit {} when done.",
+            self.state.cumulative_cost, self.config.event_loop.completion_promise
+        );
+
+        warn!(
+            cost = self.state.cumulative_cost,
+            soft_limit, "Cost soft limit reached, publishing budget.warning"
+        );
+        self.bus.publish(Event::new("budget.warning", payload));
     }
 
     /// Verifies all tasks in scratchpad are complete or cancelled.
@@ -1578,6 +2049,17 @@ impl EventLoop {
         vec![]
     }
 
+    /// Backpressure thresholds with any `event_loop` overrides applied.
+    fn backpressure_thresholds(&self) -> BackpressureThresholds {
+        let config = &self.config.event_loop;
+        let defaults = BackpressureThresholds::default();
+        BackpressureThresholds {
+            complexity: config.complexity_threshold.unwrap_or(defaults.complexity),
+            coverage: config.coverage_threshold.unwrap_or(defaults.coverage),
+            mutation: config.mutation_threshold.unwrap_or(defaults.mutation),
+        }
+    }
+
     fn warn_on_mutation_evidence(&self, evidence: &crate::event_parser::BackpressureEvidence) {
         let threshold = self.config.event_loop.mutation_score_warn_threshold;
 
@@ -1640,255 +2122,542 @@ impl EventLoop {
         }
     }
 
-    /// Processes events from JSONL and routes orphaned events to Ralph.
+    /// Applies topic-specific backpressure validation to a batch of events.
     ///
-    /// Also handles backpressure for malformed JSONL lines by:
-    /// 1. Emitting `event.malformed` system events for each parse failure
-    /// 2. Tracking consecutive failures for termination check
-    /// 3. Resetting counter when valid events are parsed
+    /// Pure transform with no side effects (no bus publish, no state or
+    /// diagnostics updates), so the rules can be exercised in isolation:
+    /// - `build.done` without passing backpressure evidence → `build.blocked`
+    /// - `review.done` without passing verification evidence → `review.blocked`
+    /// - `verify.passed` without a passing quality report → `verify.failed`
+    /// - `verify.failed` without a quality report gains a note requesting one
+    ///   when `event_loop.verify_failed_report` is `strict`
+    /// - everything else passes through unchanged
+    pub fn validate_events(&self, events: Vec<Event>) -> Vec<Event> {
+        events
+            .into_iter()
+            .map(|event| self.validate_event(event).0)
+            .collect()
+    }
+
+    /// Previews how [`process_events_from_jsonl`](Self::process_events_from_jsonl)
+    /// would handle `events`, without publishing or touching loop state.
     ///
-    /// Returns true if Ralph should be invoked to handle orphaned events.
-    pub fn process_events_from_jsonl(&mut self) -> std::io::Result<bool> {
-        let result = self.event_reader.read_new_events()?;
+    /// Each entry records the validation outcome and where the resulting event
+    /// would route. Stateful reactions (thrashing detection, human.interact
+    /// blocking, completion position rules) are not simulated.
+    pub fn preview_events(&self, events: Vec<Event>) -> EventProcessingPreview {
+        let completion_topic = self.config.event_loop.completion_promise.as_str();
 
-        // Handle malformed lines with backpressure
-        for malformed in &result.malformed {
-            let payload = format!(
-                "Line {}: {}\nContent: {}",
-                malformed.line_number, malformed.error, &malformed.content
-            );
-            let event = Event::new("event.malformed", &payload);
-            self.bus.publish(event);
-            self.state.consecutive_malformed_events += 1;
-            warn!(
-                line = malformed.line_number,
-                consecutive = self.state.consecutive_malformed_events,
-                "Malformed event line detected"
-            );
-        }
+        let events = events
+            .into_iter()
+            .map(|original| {
+                if original.topic.as_str() == completion_topic {
+                    return PreviewedEvent {
+                        outcome: original.clone(),
+                        original,
+                        backpressure: None,
+                        routed_to: None,
+                        is_completion: true,
+                    };
+                }
 
-        // Reset counter when valid events are parsed
-        if !result.events.is_empty() {
-            self.state.consecutive_malformed_events = 0;
-        }
+                let (mut outcome, backpressure) = self.validate_event(original.clone());
+                let routed_to = self
+                    .registry
+                    .get_for_topic(outcome.topic.as_str())
+                    .map(|hat| hat.id.clone());
+                if routed_to.is_none()
+                    && self.orphan_mode(&original.topic, &outcome.topic) == OrphanEventMode::Error
+                {
+                    outcome = Self::orphaned_event(&outcome.topic);
+                }
 
-        if result.events.is_empty() && result.malformed.is_empty() {
-            return Ok(false);
-        }
+                PreviewedEvent {
+                    original,
+                    outcome,
+                    backpressure,
+                    routed_to,
+                    is_completion: false,
+                }
+            })
+            .collect();
 
-        let mut has_orphans = false;
+        EventProcessingPreview { events }
+    }
 
-        // Validate and transform events (apply backpressure for build.done)
-        let mut validated_events = Vec::new();
-        let completion_topic = self.config.event_loop.completion_promise.as_str();
-        let total_events = result.events.len();
-        for (index, event) in result.events.into_iter().enumerate() {
-            let payload = event.payload.clone().unwrap_or_default();
+    /// Validates a single event, returning the (possibly synthesized) event and
+    /// the backpressure reason when the original event was rejected.
+    fn validate_event(&self, event: Event) -> (Event, Option<String>) {
+        let payload = event.payload.as_str();
 
-            if event.topic == completion_topic {
-                if index + 1 == total_events {
-                    self.state.completion_requested = true;
-                    self.diagnostics.log_orchestration(
-                        self.state.iteration,
-                        "jsonl",
-                        crate::diagnostics::OrchestrationEvent::EventPublished {
-                            topic: event.topic.clone(),
-                        },
-                    );
-                    info!(
-                        topic = %event.topic,
-                        "Completion event detected in JSONL"
-                    );
-                } else {
-                    warn!(
-                        topic = %event.topic,
-                        index = index,
-                        total_events = total_events,
-                        "Completion event ignored because it was not the last event"
-                    );
-                }
-                continue;
-            }
+        if payload.trim().is_empty()
+            && self
+                .config
+                .event_loop
+                .require_payload_topics
+                .iter()
+                .any(|topic| topic == event.topic.as_str())
+        {
+            warn!(topic = %event.topic, "Event rejected: empty payload");
+            return (
+                Event::new(
+                    "event.rejected",
+                    format!(
+                        "'{}' requires a non-empty payload. Re-emit it with its details.",
+                        event.topic
+                    ),
+                ),
+                Some(format!("empty payload for '{}'", event.topic)),
+            );
+        }
 
-            if event.topic == "build.done" {
+        match event.topic.as_str() {
+            "build.done" => {
                 // Validate build.done events have backpressure evidence
-                if let Some(evidence) = EventParser::parse_backpressure_evidence(&payload) {
-                    if evidence.all_passed() {
-                        self.warn_on_mutation_evidence(&evidence);
-                        validated_events.push(Event::new(event.topic.as_str(), &payload));
-                    } else {
-                        // Evidence present but checks failed - synthesize build.blocked
-                        warn!(
-                            tests = evidence.tests_passed,
-                            lint = evidence.lint_passed,
-                            typecheck = evidence.typecheck_passed,
-                            audit = evidence.audit_passed,
-                            coverage = evidence.coverage_passed,
-                            complexity = evidence.complexity_score,
-                            duplication = evidence.duplication_passed,
-                            performance = evidence.performance_regression,
-                            specs = evidence.specs_verified,
-                            "build.done rejected: backpressure checks failed"
-                        );
-
-                        let complexity = evidence
-                            .complexity_score
-                            .map(|value| format!("{value:.2}"))
-                            .unwrap_or_else(|| "missing".to_string());
-                        let performance = match evidence.performance_regression {
-                            Some(true) => "regression".to_string(),
-                            Some(false) => "pass".to_string(),
-                            None => "missing".to_string(),
-                        };
-                        let specs = match evidence.specs_verified {
-                            Some(true) => "pass".to_string(),
-                            Some(false) => "fail".to_string(),
-                            None => "not reported".to_string(),
-                        };
-
-                        self.diagnostics.log_orchestration(
-                            self.state.iteration,
-                            "jsonl",
-                            crate::diagnostics::OrchestrationEvent::BackpressureTriggered {
-                                reason: format!(
-                                    "backpressure checks failed: tests={}, lint={}, typecheck={}, audit={}, coverage={}, complexity={}, duplication={}, performance={}, specs={}",
-                                    evidence.tests_passed,
-                                    evidence.lint_passed,
-                                    evidence.typecheck_passed,
-                                    evidence.audit_passed,
-                                    evidence.coverage_passed,
-                                    complexity,
-                                    evidence.duplication_passed,
-                                    performance,
-                                    specs
-                                ),
-                            },
-                        );
-
-                        validated_events.push(Event::new(
-                            "build.blocked",
-                            "Backpressure checks failed. Fix tests/lint/typecheck/audit/coverage/complexity/duplication/specs before emitting build.done.",
-                        ));
-                    }
-                } else {
+                let thresholds = self.backpressure_thresholds();
+                let Some(evidence) =
+                    EventParser::parse_backpressure_evidence_with(payload, &thresholds)
+                else {
                     // No evidence found - synthesize build.blocked
                     warn!("build.done rejected: missing backpressure evidence");
-
-                    self.diagnostics.log_orchestration(
-                        self.state.iteration,
-                        "jsonl",
-                        crate::diagnostics::OrchestrationEvent::BackpressureTriggered {
-                            reason: "missing backpressure evidence".to_string(),
-                        },
+                    return (
+                        Event::new(
+                            "build.blocked",
+                            "Missing backpressure evidence. Include 'tests: pass', 'lint: pass', 'typecheck: pass', 'audit: pass', 'coverage: pass', 'complexity: <score>', 'duplication: pass', 'performance: pass' (optional), 'specs: pass' (optional) in build.done payload.",
+                        ),
+                        Some("missing backpressure evidence".to_string()),
                     );
+                };
 
-                    validated_events.push(Event::new(
-                        "build.blocked",
-                        "Missing backpressure evidence. Include 'tests: pass', 'lint: pass', 'typecheck: pass', 'audit: pass', 'coverage: pass', 'complexity: <score>', 'duplication: pass', 'performance: pass' (optional), 'specs: pass' (optional) in build.done payload.",
-                    ));
+                if evidence.all_passed_with(&thresholds) {
+                    self.warn_on_mutation_evidence(&evidence);
+                    return (event, None);
                 }
-            } else if event.topic == "review.done" {
-                // Validate review.done events have verification evidence
-                if let Some(evidence) = EventParser::parse_review_evidence(&payload) {
-                    if evidence.is_verified() {
-                        validated_events.push(Event::new(event.topic.as_str(), &payload));
-                    } else {
-                        // Evidence present but checks failed - synthesize review.blocked
-                        warn!(
-                            tests = evidence.tests_passed,
-                            build = evidence.build_passed,
-                            "review.done rejected: verification checks failed"
-                        );
 
-                        self.diagnostics.log_orchestration(
-                            self.state.iteration,
-                            "jsonl",
-                            crate::diagnostics::OrchestrationEvent::BackpressureTriggered {
-                                reason: format!(
-                                    "review verification failed: tests={}, build={}",
-                                    evidence.tests_passed, evidence.build_passed
-                                ),
-                            },
-                        );
+                // Evidence present but checks failed - synthesize build.blocked
+                warn!(
+                    tests = evidence.tests_passed,
+                    lint = evidence.lint_passed,
+                    typecheck = evidence.typecheck_passed,
+                    audit = evidence.audit_passed,
+                    coverage = evidence.coverage_passed,
+                    complexity = evidence.complexity_score,
+                    duplication = evidence.duplication_passed,
+                    performance = evidence.performance_regression,
+                    specs = evidence.specs_verified,
+                    "build.done rejected: backpressure checks failed"
+                );
 
-                        validated_events.push(Event::new(
-                            "review.blocked",
-                            "Review verification failed. Run tests and build before emitting review.done.",
-                        ));
-                    }
-                } else {
+                let complexity = evidence
+                    .complexity_score
+                    .map(|value| format!("{value:.2}"))
+                    .unwrap_or_else(|| "missing".to_string());
+                let performance = match evidence.performance_regression {
+                    Some(true) => "regression".to_string(),
+                    Some(false) => "pass".to_string(),
+                    None => "missing".to_string(),
+                };
+                let specs = match evidence.specs_verified {
+                    Some(true) => "pass".to_string(),
+                    Some(false) => "fail".to_string(),
+                    None => "not reported".to_string(),
+                };
+
+                (
+                    Event::new(
+                        "build.blocked",
+                        "Backpressure checks failed. Fix tests/lint/typecheck/audit/coverage/complexity/duplication/specs before emitting build.done.",
+                    ),
+                    Some(format!(
+                        "backpressure checks failed: tests={}, lint={}, typecheck={}, audit={}, coverage={}, complexity={}, duplication={}, performance={}, specs={}",
+                        evidence.tests_passed,
+                        evidence.lint_passed,
+                        evidence.typecheck_passed,
+                        evidence.audit_passed,
+                        evidence.coverage_passed,
+                        complexity,
+                        evidence.duplication_passed,
+                        performance,
+                        specs
+                    )),
+                )
+            }
+            "review.done" => {
+                // Validate review.done events have verification evidence
+                let Some(evidence) = EventParser::parse_review_evidence(payload) else {
                     // No evidence found - synthesize review.blocked
                     warn!("review.done rejected: missing verification evidence");
-
-                    self.diagnostics.log_orchestration(
-                        self.state.iteration,
-                        "jsonl",
-                        crate::diagnostics::OrchestrationEvent::BackpressureTriggered {
-                            reason: "missing review verification evidence".to_string(),
-                        },
+                    return (
+                        Event::new(
+                            "review.blocked",
+                            "Missing verification evidence. Include 'tests: pass' and 'build: pass' in review.done payload.",
+                        ),
+                        Some("missing review verification evidence".to_string()),
                     );
+                };
 
-                    validated_events.push(Event::new(
+                if evidence.is_verified() {
+                    return (event, None);
+                }
+
+                // Evidence present but checks failed - synthesize review.blocked
+                warn!(
+                    tests = evidence.tests_passed,
+                    build = evidence.build_passed,
+                    "review.done rejected: verification checks failed"
+                );
+
+                (
+                    Event::new(
                         "review.blocked",
-                        "Missing verification evidence. Include 'tests: pass' and 'build: pass' in review.done payload.",
-                    ));
+                        "Review verification failed. Run tests and build before emitting review.done.",
+                    ),
+                    Some(format!(
+                        "review verification failed: tests={}, build={}",
+                        evidence.tests_passed, evidence.build_passed
+                    )),
+                )
+            }
+            "verify.passed" => {
+                let Some(report) = EventParser::parse_quality_report(payload) else {
+                    // No quality report found - synthesize verify.failed
+                    warn!("verify.passed rejected: missing quality report");
+                    return (
+                        Event::new(
+                            "verify.failed",
+                            "Missing quality report. Include quality.tests, quality.coverage, quality.lint, quality.audit, quality.mutation, quality.complexity in verify.passed payload.",
+                        ),
+                        Some("missing quality report".to_string()),
+                    );
+                };
+
+                let thresholds = self.backpressure_thresholds();
+                if report.meets_thresholds_with(&thresholds) {
+                    return (event, None);
                 }
-            } else if event.topic == "verify.passed" {
-                if let Some(report) = EventParser::parse_quality_report(&payload) {
-                    if report.meets_thresholds() {
-                        validated_events.push(Event::new(event.topic.as_str(), &payload));
-                    } else {
-                        let failed = report.failed_dimensions();
-                        let reason = if failed.is_empty() {
-                            "quality thresholds failed".to_string()
-                        } else {
-                            format!("quality thresholds failed: {}", failed.join(", "))
-                        };
 
-                        warn!(
-                            failed_dimensions = ?failed,
-                            "verify.passed rejected: quality thresholds failed"
-                        );
+                let failed = report.failed_dimensions_with(&thresholds);
+                let reason = if failed.is_empty() {
+                    "quality thresholds failed".to_string()
+                } else {
+                    format!("quality thresholds failed: {}", failed.join(", "))
+                };
 
+                warn!(
+                    failed_dimensions = ?failed,
+                    "verify.passed rejected: quality thresholds failed"
+                );
+
+                (
+                    Event::new(
+                        "verify.failed",
+                        "Quality thresholds failed. Include quality.tests, quality.coverage, quality.lint, quality.audit, quality.mutation, quality.complexity with thresholds in verify.passed payload.",
+                    ),
+                    Some(reason),
+                )
+            }
+            "verify.failed" => {
+                if EventParser::parse_quality_report(payload).is_some() {
+                    return (event, None);
+                }
+                warn!("verify.failed missing quality report");
+                match self.config.event_loop.verify_failed_report {
+                    QualityReportMode::Lenient => (event, None),
+                    QualityReportMode::Strict => {
+                        // The failure still goes through, so this is an
+                        // annotation rather than backpressure.
                         self.diagnostics.log_orchestration(
                             self.state.iteration,
-                            "jsonl",
-                            crate::diagnostics::OrchestrationEvent::BackpressureTriggered {
-                                reason,
+                            "loop",
+                            crate::diagnostics::OrchestrationEvent::EventAnnotated {
+                                topic: "verify.failed".to_string(),
+                                note: "quality report missing".to_string(),
                             },
                         );
+                        (
+                            Event::new(
+                                "verify.failed",
+                                format!(
+                                    "{payload}\n\nNote: quality report missing. Include quality.tests, quality.coverage, quality.lint, quality.audit, quality.mutation, quality.complexity so this failure is actionable."
+                                ),
+                            ),
+                            None,
+                        )
+                    }
+                }
+            }
+            // Non-backpressure events pass through unchanged
+            _ => (event, None),
+        }
+    }
 
-                        validated_events.push(Event::new(
-                            "verify.failed",
-                            "Quality thresholds failed. Include quality.tests, quality.coverage, quality.lint, quality.audit, quality.mutation, quality.complexity with thresholds in verify.passed payload.",
-                        ));
+    /// Forgives malformed lines after valid events.
+    ///
+    /// With no decay window every valid batch resets the counter; otherwise one
+    /// malformed line is forgiven per `malformed_decay_window` valid events.
+    fn decay_malformed_counter(&mut self, valid_events: usize) {
+        let window = self.config.event_loop.malformed_decay_window;
+        if window == 0 {
+            self.state.consecutive_malformed_events = 0;
+            self.state.valid_events_since_malformed = 0;
+            return;
+        }
+
+        let window = u32::try_from(window).unwrap_or(u32::MAX);
+        let seen = self
+            .state
+            .valid_events_since_malformed
+            .saturating_add(u32::try_from(valid_events).unwrap_or(u32::MAX));
+        let forgiven = (seen / window).min(self.state.consecutive_malformed_events);
+        self.state.consecutive_malformed_events -= forgiven;
+        self.state.valid_events_since_malformed = if self.state.consecutive_malformed_events == 0 {
+            0
+        } else {
+            seen % window
+        };
+    }
+
+    /// Processes events from JSONL and routes orphaned events to Ralph.
+    ///
+    /// Also handles backpressure for malformed JSONL lines by:
+    /// 1. Emitting `event.malformed` system events for each parse failure
+    /// 2. Tracking consecutive failures for termination check
+    /// 3. Resetting (or, with `malformed_decay_window`, decaying) the counter
+    ///    when valid events are parsed
+    ///
+    /// Returns true if Ralph should be invoked to handle orphaned events.
+    pub fn process_events_from_jsonl(&mut self) -> std::io::Result<bool> {
+        let result = self.event_reader.read_new_events()?;
+        Ok(self.apply_parse_result(result).has_orphans)
+    }
+
+    /// Replays a recorded events file through the orchestrator's decision logic.
+    ///
+    /// Each JSONL record is fed, in order, through the same validation and
+    /// backpressure path as [`process_events_from_jsonl`](Self::process_events_from_jsonl),
+    /// then termination is checked. The returned list pairs the event the
+    /// orchestrator produced for each record (the validated or synthesized event,
+    /// `event.malformed` for unparseable lines, or the completion event) with the
+    /// termination it would have triggered. Replay stops at the first termination.
+    ///
+    /// Records carrying an `iteration` field (as written by `EventLogger`) advance
+    /// the iteration counter. The failure streak follows the backend outcome the
+    /// loop stamped on its records ([`EventRecord::success`]), applied once per
+    /// iteration as in [`process_output`](Self::process_output); backpressure
+    /// rejections alone never count as failures. Iterations that logged no
+    /// events leave no outcome behind and do not move the streak.
+    ///
+    /// The replay runs in dry-run mode with no robot service or loop context, so
+    /// nothing is written to disk, no git operations run, and the stop and
+    /// restart signal files are left untouched.
+    ///
+    /// [`EventRecord::success`]: crate::EventRecord::success
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the events file cannot be read.
+    pub fn replay_from_events(
+        config: RalphConfig,
+        events_path: impl AsRef<Path>,
+    ) -> std::io::Result<Vec<(Event, Option<TerminationReason>)>> {
+        let content = std::fs::read_to_string(events_path)?;
+
+        let mut event_loop =
+            Self::with_diagnostics(config, crate::diagnostics::DiagnosticsCollector::disabled());
+        event_loop.set_dry_run(true);
+
+        let mut replayed = Vec::new();
+        let mut scored_iteration = None;
+        for (index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mut result = crate::event_reader::ParseResult::default();
+            match serde_json::from_str::<crate::event_reader::Event>(line) {
+                Ok(event) => result.events.push(event),
+                Err(e) => result
+                    .malformed
+                    .push(crate::event_reader::MalformedLine::new(
+                        index as u64 + 1,
+                        line,
+                        e.to_string(),
+                    )),
+            }
+
+            if let Ok(record) = serde_json::from_str::<crate::EventRecord>(line) {
+                if record.iteration > 0 {
+                    event_loop.state.iteration = record.iteration;
+                }
+                if let Some(success) = record.success
+                    && scored_iteration != Some(record.iteration)
+                {
+                    scored_iteration = Some(record.iteration);
+                    if success {
+                        event_loop.state.consecutive_failures = 0;
+                    } else {
+                        event_loop.state.consecutive_failures += 1;
                     }
-                } else {
-                    // No quality report found - synthesize verify.failed
-                    warn!("verify.passed rejected: missing quality report");
+                }
+            }
+
+            let batch = event_loop.apply_parse_result(result);
+
+            let termination = event_loop
+                .check_completion_event()
+                .or_else(|| event_loop.check_limits());
+            let terminated = termination.is_some();
+            if let Some(event) = batch.events.into_iter().next() {
+                replayed.push((event, termination));
+            }
+            if terminated {
+                break;
+            }
+        }
+
+        Ok(replayed)
+    }
+
+    /// Builds the `event.orphaned` event that replaces an event on `topic`
+    /// when orphaned events are rejected ([`OrphanEventMode::Error`]).
+    fn orphaned_event(topic: &Topic) -> Event {
+        Event::new(
+            "event.orphaned",
+            format!(
+                "No hat subscribes to '{topic}'. Check the topic name against the hat topology."
+            ),
+        )
+    }
+
+    /// Returns how an event with no hat subscriber is handled.
+    ///
+    /// `original` is the topic as emitted and `outcome` the topic after
+    /// validation. Topics the loop acts on itself and events synthesized by
+    /// validation are never rejected, and a config without hats routes
+    /// everything to Ralph, its only consumer.
+    fn orphan_mode(&self, original: &Topic, outcome: &Topic) -> OrphanEventMode {
+        let builtin = BUILTIN_EMITTED_TOPICS.contains(&outcome.as_str())
+            || BUILTIN_CONSUMED_TOPICS.contains(&outcome.as_str());
+        if self.registry.is_empty() || builtin || original != outcome {
+            OrphanEventMode::RouteToRalph
+        } else {
+            self.config.event_loop.on_orphan_event
+        }
+    }
+
+    /// Applies parsed JSONL records: malformed-line backpressure, validation,
+    /// thrashing detection, human interaction, and publishing to the bus.
+    fn apply_parse_result(&mut self, result: crate::event_reader::ParseResult) -> JsonlBatch {
+        let mut batch = JsonlBatch::default();
+
+        // Handle malformed lines with backpressure
+        for malformed in &result.malformed {
+            let payload = format!(
+                "Line {}: {}\nContent: {}",
+                malformed.line_number, malformed.error, &malformed.content
+            );
+            let event = Event::new("event.malformed", &payload);
+            batch.events.push(event.clone());
+            self.bus.publish(event);
+            self.state.consecutive_malformed_events += 1;
+            warn!(
+                line = malformed.line_number,
+                consecutive = self.state.consecutive_malformed_events,
+                "Malformed event line detected"
+            );
+        }
 
+        // Reset or decay counter when valid events are parsed
+        if !result.events.is_empty() {
+            self.decay_malformed_counter(result.events.len());
+        }
+
+        if result.events.is_empty() && result.malformed.is_empty() {
+            return batch;
+        }
+
+        // Validate and transform events (apply backpressure for build.done)
+        let mut validated_events = Vec::new();
+        let completion_topic = self.config.event_loop.completion_promise.as_str();
+        let total_events = result.events.len();
+        for (index, event) in result.events.into_iter().enumerate() {
+            let payload = event.payload.clone().unwrap_or_default();
+
+            if event.topic == completion_topic {
+                let accepted = match self.config.event_loop.completion_position {
+                    CompletionPosition::LastOnly => index + 1 == total_events,
+                    CompletionPosition::Any => true,
+                };
+                batch
+                    .events
+                    .push(Event::new(event.topic.as_str(), &payload));
+                if accepted {
+                    self.state.completion_requested = true;
                     self.diagnostics.log_orchestration(
                         self.state.iteration,
                         "jsonl",
-                        crate::diagnostics::OrchestrationEvent::BackpressureTriggered {
-                            reason: "missing quality report".to_string(),
+                        crate::diagnostics::OrchestrationEvent::EventPublished {
+                            topic: event.topic.clone(),
                         },
                     );
-
-                    validated_events.push(Event::new(
-                        "verify.failed",
-                        "Missing quality report. Include quality.tests, quality.coverage, quality.lint, quality.audit, quality.mutation, quality.complexity in verify.passed payload.",
-                    ));
-                }
-            } else if event.topic == "verify.failed" {
-                if EventParser::parse_quality_report(&payload).is_none() {
-                    warn!("verify.failed missing quality report");
+                    info!(
+                        topic = %event.topic,
+                        "Completion event detected in JSONL"
+                    );
+                } else {
+                    warn!(
+                        topic = %event.topic,
+                        index = index,
+                        total_events = total_events,
+                        "Completion event ignored because it was not the last event"
+                    );
                 }
-                validated_events.push(Event::new(event.topic.as_str(), &payload));
-            } else {
-                // Non-backpressure events pass through unchanged
-                validated_events.push(Event::new(event.topic.as_str(), &payload));
+                continue;
+            }
+
+            let original_topic = Topic::new(event.topic.as_str());
+            let (event, backpressure) =
+                self.validate_event(Event::new(event.topic.as_str(), &payload));
+            if let Some(reason) = backpressure {
+                batch.rejected += 1;
+                self.diagnostics.log_orchestration(
+                    self.state.iteration,
+                    "jsonl",
+                    crate::diagnostics::OrchestrationEvent::BackpressureTriggered { reason },
+                );
             }
+
+            // Ralph is always registered with subscribe("*"), so every event has at
+            // least one subscriber. Events without a specific hat subscriber are
+            // "orphaned" — Ralph handles them as the universal fallback, unless
+            // configured to reject them, in which case they are replaced before
+            // being recorded so the dropped event never counts as published.
+            let event = if self.registry.has_subscriber(event.topic.as_str()) {
+                event
+            } else {
+                batch.has_orphans = true;
+                match self.orphan_mode(&original_topic, &event.topic) {
+                    OrphanEventMode::RouteToRalph => event,
+                    OrphanEventMode::Warn => {
+                        warn!(topic = %event.topic, "No hat subscribes to event — routing to Ralph");
+                        event
+                    }
+                    OrphanEventMode::Error => {
+                        warn!(topic = %event.topic, "No hat subscribes to event — rejecting");
+                        Self::orphaned_event(&event.topic)
+                    }
+                }
+            };
+            batch.events.push(event.clone());
+            validated_events.push(event);
+        }
+
+        // verify.passed only survives validation when quality thresholds are met
+        if validated_events
+            .iter()
+            .any(|e| e.topic == "verify.passed".into())
+        {
+            self.state.verify_passed_seen = true;
         }
 
         // Track build.blocked events for thrashing detection
@@ -1998,27 +2767,8 @@ impl EventLoop {
                 // Block: poll events file for human.response
                 // Per spec, even on send failure we treat as timeout (continue without blocking)
                 if send_ok {
-                    // Read the active events path from the current-events marker,
-                    // falling back to the default events.jsonl if not available.
-                    let events_path = self
-                        .loop_context
-                        .as_ref()
-                        .and_then(|ctx| {
-                            std::fs::read_to_string(ctx.current_events_marker())
-                                .ok()
-                                .map(|s| ctx.workspace().join(s.trim()))
-                        })
-                        .or_else(|| {
-                            std::fs::read_to_string(".ralph/current-events")
-                                .ok()
-                                .map(|s| PathBuf::from(s.trim()))
-                        })
-                        .unwrap_or_else(|| {
-                            self.loop_context
-                                .as_ref()
-                                .map(|ctx| ctx.events_path())
-                                .unwrap_or_else(|| PathBuf::from(".ralph/events.jsonl"))
-                        });
+                    // Wait on the same file the event reader consumes.
+                    let events_path = self.events_path();
 
                     match robot_service.wait_for_response(&events_path) {
                         Ok(Some(response)) => {
@@ -2050,10 +2800,7 @@ impl EventLoop {
             }
         }
 
-        // Publish validated events to the bus.
-        // Ralph is always registered with subscribe("*"), so every event has at least
-        // one subscriber. Events without a specific hat subscriber are "orphaned" —
-        // Ralph handles them as the universal fallback.
+        // Publish validated events (orphans already resolved) to the bus.
         for event in validated_events {
             self.diagnostics.log_orchestration(
                 self.state.iteration,
@@ -2063,10 +2810,6 @@ impl EventLoop {
                 },
             );
 
-            if !self.registry.has_subscriber(event.topic.as_str()) {
-                has_orphans = true;
-            }
-
             debug!(
                 topic = %event.topic,
                 "Publishing event from JSONL"
@@ -2083,7 +2826,10 @@ impl EventLoop {
             self.bus.publish(response);
         }
 
-        Ok(has_orphans)
+        for event in &batch.events {
+            self.state.record_topic(event.topic.as_str());
+        }
+        batch
     }
 
     /// Checks if output contains a completion event from Ralph.
@@ -2101,15 +2847,31 @@ impl EventLoop {
     /// Per spec: "Published by the orchestrator (not agents) when the loop exits."
     /// This is an observer-only event—hats cannot trigger on it.
     ///
-    /// Returns the event for logging purposes.
-    pub fn publish_terminate_event(&mut self, reason: &TerminationReason) -> Event {
+    /// Returns the published events for logging: the markdown `loop.terminate`
+    /// event first, followed by the `loop.terminate.json` summary.
+    pub fn publish_terminate_event(&mut self, reason: &TerminationReason) -> Vec<Event> {
         // Stop the robot service if it was running
         self.stop_robot_service();
 
+        // Diagnostics are otherwise only flushed on the persistence interval
+        self.diagnostics.flush();
+
+        // A finished loop must not resume its counters on the next run; only a
+        // requested restart (or a crash, which never reaches here) keeps them.
+        if *reason != TerminationReason::RestartRequested
+            && let Some(ctx) = &self.loop_context
+        {
+            if self.state.dry_run {
+                info!("Dry run: would remove persisted loop state");
+            } else {
+                let _ = std::fs::remove_file(ctx.loop_state_path());
+            }
+        }
+
         let elapsed = self.state.elapsed();
         let duration_str = format_duration(elapsed);
 
-        let payload = format!(
+        let mut payload = format!(
             "## Reason\n{}\n\n## Status\n{}\n\n## Summary\n- Iterations: {}\n- Duration: {}\n- Exit code: {}",
             reason.as_str(),
             termination_status_text(reason),
@@ -2118,10 +2880,32 @@ impl EventLoop {
             reason.exit_code()
         );
 
+        let breakdown = self.state.cost_breakdown();
+        if !breakdown.is_empty() {
+            payload.push_str(&format!(
+                "\n\n## Cost by Hat\n- Total: ${:.4}",
+                self.state.cumulative_cost
+            ));
+            for (hat, cost) in breakdown {
+                payload.push_str(&format!("\n- {hat}: ${cost:.4}"));
+            }
+        }
+
         let event = Event::new("loop.terminate", &payload);
 
         // Publish to bus for observers (but no hat can trigger on this)
         self.bus.publish(event.clone());
+        let mut events = vec![event];
+
+        let summary = self.termination_summary(reason);
+        match serde_json::to_string(&summary) {
+            Ok(json) => {
+                let json_event = Event::new("loop.terminate.json", json);
+                self.bus.publish(json_event.clone());
+                events.push(json_event);
+            }
+            Err(e) => warn!(error = %e, "Failed to serialize termination summary"),
+        }
 
         info!(
             reason = %reason.as_str(),
@@ -2133,7 +2917,66 @@ impl EventLoop {
             duration_str
         );
 
-        event
+        events
+    }
+
+    /// Builds the machine-readable summary for a termination.
+    pub fn termination_summary(&self, reason: &TerminationReason) -> TerminationSummary {
+        TerminationSummary {
+            reason: reason.as_str().to_string(),
+            exit_code: reason.exit_code(),
+            iterations: self.state.iteration,
+            duration_secs: self.state.elapsed().as_secs_f64(),
+            cumulative_cost: self.state.cumulative_cost,
+        }
+    }
+
+    /// Builds the post-run report for a termination.
+    pub fn run_report(&self, reason: &TerminationReason) -> RunReport {
+        RunReport {
+            summary: self.termination_summary(reason),
+            topic_counts: self
+                .state
+                .topic_counts
+                .iter()
+                .map(|(topic, count)| (topic.clone(), *count))
+                .collect(),
+            cost_breakdown: self
+                .state
+                .cost_breakdown()
+                .into_iter()
+                .map(|(hat, cost_usd)| HatCost {
+                    hat: hat.to_string(),
+                    cost_usd,
+                })
+                .collect(),
+            hat_activations: self
+                .state
+                .hat_activation_counts
+                .iter()
+                .map(|(hat, count)| (hat.to_string(), *count))
+                .collect(),
+            abandoned_tasks: self.state.abandoned_tasks.clone(),
+            abandoned_task_redispatches: self.state.abandoned_task_redispatches,
+        }
+    }
+
+    /// Writes the [`RunReport`] for a termination to `path` as JSON.
+    ///
+    /// Writes to a temporary sibling file and renames it into place, so
+    /// readers never see a partial report.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the report cannot be written.
+    pub fn write_run_report(&self, path: &Path, reason: &TerminationReason) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&self.run_report(reason))?;
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, json)?;
+        std::fs::rename(&tmp_path, path)
     }
 
     /// Returns the robot service's shutdown flag, if active.
@@ -2157,19 +3000,36 @@ impl EventLoop {
     // Human-in-the-loop planning support
     // -------------------------------------------------------------------------
 
-    /// Check if any event is a `user.prompt` event.
+    /// Check if any event is a user prompt event.
     ///
+    /// The topic comes from `event_loop.user_prompt_topic` (default `user.prompt`).
     /// Returns the first user prompt event found, or None.
     pub fn check_for_user_prompt(&self, events: &[Event]) -> Option<UserPrompt> {
+        let topic = self.config.event_loop.user_prompt_topic.as_str();
         events
             .iter()
-            .find(|e| e.topic.as_str() == "user.prompt")
+            .find(|e| e.topic.as_str() == topic)
             .map(|e| UserPrompt {
                 id: Self::extract_prompt_id(&e.payload),
                 text: e.payload.clone(),
+                source_hat: e.source.clone(),
+                created_at: chrono::Utc::now(),
+                options: Self::extract_prompt_options(&e.payload),
             })
     }
 
+    /// Extract answer options from a JSON payload's `options` string array.
+    fn extract_prompt_options(payload: &str) -> Option<Vec<String>> {
+        let value: serde_json::Value = serde_json::from_str(payload.trim()).ok()?;
+        let options: Vec<String> = value
+            .get("options")?
+            .as_array()?
+            .iter()
+            .filter_map(|option| option.as_str().map(str::to_string))
+            .collect();
+        (!options.is_empty()).then_some(options)
+    }
+
     /// Extract a prompt ID from the event payload.
     ///
     /// Supports both XML attribute format: `<event topic="user.prompt" id="q1">...</event>`
@@ -2182,19 +3042,81 @@ impl EventLoop {
             return payload[start + 4..start + 4 + end].to_string();
         }
 
-        // Fallback: generate a simple ID based on timestamp
+        // Then a JSON object payload such as `{"id": "q1", "question": "..."}`
+        if let Ok(serde_json::Value::Object(map)) = serde_json::from_str(payload.trim())
+            && let Some(id) = map.get("id").and_then(serde_json::Value::as_str)
+            && !id.is_empty()
+        {
+            return id.to_string();
+        }
+
+        // Fallback: generate a unique ID
         format!("q{}", Self::generate_prompt_id())
     }
 
-    /// Generate a simple unique ID for prompts.
-    /// Uses timestamp-based generation since uuid crate isn't available.
+    /// Generate a unique ID for prompts.
+    ///
+    /// Combines the creation timestamp with a process-wide monotonic counter,
+    /// so ids created within the same clock tick still differ.
     fn generate_prompt_id() -> String {
+        use std::sync::atomic::{AtomicU64, Ordering};
         use std::time::{SystemTime, UNIX_EPOCH};
+
+        static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
         let nanos = SystemTime::now()
             .duration_since(UNIX_EPOCH)
-            .unwrap()
+            .unwrap_or_default()
             .as_nanos();
-        format!("{:x}", nanos % 0xFFFF_FFFF)
+        let sequence = SEQUENCE.fetch_add(1, Ordering::Relaxed);
+        format!("{nanos:x}-{sequence:x}")
+    }
+}
+
+/// Planned handling of a batch of events, produced by
+/// [`EventLoop::preview_events`].
+#[derive(Debug, Clone)]
+pub struct EventProcessingPreview {
+    /// One entry per input event, in order.
+    pub events: Vec<PreviewedEvent>,
+}
+
+impl EventProcessingPreview {
+    /// Returns true if any event would fall through to Ralph with no hat subscriber.
+    pub fn has_orphans(&self) -> bool {
+        self.events.iter().any(PreviewedEvent::is_orphan)
+    }
+
+    /// Returns the entries whose event would be replaced before publishing.
+    pub fn transforms(&self) -> impl Iterator<Item = &PreviewedEvent> {
+        self.events.iter().filter(|e| e.is_transformed())
+    }
+}
+
+/// Planned handling of a single event.
+#[derive(Debug, Clone)]
+pub struct PreviewedEvent {
+    /// The event as emitted by the agent.
+    pub original: Event,
+    /// The event that would be published after validation.
+    pub outcome: Event,
+    /// Backpressure reason when validation rejected the original event.
+    pub backpressure: Option<String>,
+    /// Hat that subscribes to the outcome topic; `None` means Ralph handles it.
+    pub routed_to: Option<HatId>,
+    /// True if this is the completion promise (recorded, not published).
+    pub is_completion: bool,
+}
+
+impl PreviewedEvent {
+    /// Returns true if the published event differs from the original.
+    pub fn is_transformed(&self) -> bool {
+        self.outcome.topic != self.original.topic || self.outcome.payload != self.original.payload
+    }
+
+    /// Returns true if no hat subscribes to the outcome topic.
+    pub fn is_orphan(&self) -> bool {
+        !self.is_completion && self.routed_to.is_none()
     }
 }
 
@@ -2207,6 +3129,88 @@ pub struct UserPrompt {
     pub id: String,
     /// The prompt/question text
     pub text: String,
+    /// The hat that emitted the prompt, if known
+    pub source_hat: Option<HatId>,
+    /// When the prompt was detected
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Answer choices attached as a JSON `options` array
+    pub options: Option<Vec<String>>,
+}
+
+/// Per-section size accounting for an assembled prompt.
+///
+/// Returned by [`EventLoop::build_prompt_with_breakdown`]. Sizes are byte lengths
+/// of each block as rendered into the prompt (including separators), so the
+/// sections always sum to the total prompt length.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PromptBreakdown {
+    /// Hat/coordinator instructions and event context.
+    pub base: usize,
+    /// Injected memory data.
+    pub memories: usize,
+    /// Injected skill blocks (ralph-tools, robot-interaction, auto-inject skills).
+    pub skills: usize,
+    /// Injected `<scratchpad>` block.
+    pub scratchpad: usize,
+    /// Injected `<ready-tasks>` block.
+    pub ready_tasks: usize,
+}
+
+impl PromptBreakdown {
+    /// Returns the total prompt size in bytes.
+    pub fn total(&self) -> usize {
+        self.base + self.memories + self.skills + self.scratchpad + self.ready_tasks
+    }
+
+    /// Returns a rough token estimate for the whole prompt (~4 chars per token).
+    pub fn estimated_tokens(&self) -> usize {
+        self.total().div_ceil(4)
+    }
+}
+
+/// Loads persisted loop state if present, otherwise starts fresh.
+fn restore_loop_state(path: &Path) -> LoopState {
+    if !path.exists() {
+        return LoopState::new();
+    }
+    match LoopState::load_from(path) {
+        Ok(state) => {
+            info!(
+                iteration = state.iteration,
+                path = %path.display(),
+                "Restored loop state from previous run"
+            );
+            state
+        }
+        Err(e) => {
+            warn!(error = %e, path = %path.display(), "Ignoring unreadable loop state");
+            LoopState::new()
+        }
+    }
+}
+
+/// Resolves the active events file from the current-events marker.
+///
+/// The marker is written by `run_loop_impl()` at run startup and contains a
+/// relative path like `.ralph/events-20260127-123456.jsonl`. With a loop
+/// context the path is resolved against the context's workspace and falls back
+/// to the context's `events.jsonl`; without one (legacy mode) the marker and
+/// `.ralph/events.jsonl` fallback are relative to the current directory.
+fn resolve_events_path(context: Option<&LoopContext>) -> PathBuf {
+    match context {
+        Some(ctx) => std::fs::read_to_string(ctx.current_events_marker())
+            .map(|s| ctx.workspace().join(s.trim()))
+            .unwrap_or_else(|_| ctx.events_path()),
+        None => std::fs::read_to_string(".ralph/current-events")
+            .map(|s| PathBuf::from(s.trim()))
+            .unwrap_or_else(|_| PathBuf::from(".ralph/events.jsonl")),
+    }
+}
+
+/// Appends a prompt block followed by a blank-line separator.
+fn push_prompt_block(buf: &mut String, block: &str) {
+    buf.push_str(block);
+    buf.push_str("\n\n");
 }
 
 /// Formats a duration as human-readable string.
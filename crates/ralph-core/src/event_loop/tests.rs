@@ -33,6 +33,66 @@ hats:
     );
 }
 
+#[test]
+fn test_highest_priority_hat_events_collected_first() {
+    let yaml = r#"
+hats:
+  alpha:
+    name: "Alpha"
+    triggers: ["alpha.task"]
+    priority: 5
+  zulu:
+    name: "Zulu"
+    triggers: ["zulu.task"]
+    priority: 10
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+
+    event_loop
+        .bus
+        .publish(Event::new("alpha.task", "ALPHA_PAYLOAD"));
+    event_loop
+        .bus
+        .publish(Event::new("zulu.task", "ZULU_PAYLOAD"));
+
+    assert_eq!(
+        event_loop.select_highest_priority_hat().map(HatId::as_str),
+        Some("zulu")
+    );
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+    let zulu_pos = prompt.find("ZULU_PAYLOAD").expect("zulu event in prompt");
+    let alpha_pos = prompt.find("ALPHA_PAYLOAD").expect("alpha event in prompt");
+    assert!(
+        zulu_pos < alpha_pos,
+        "priority-10 hat's events should be collected before priority-5"
+    );
+}
+
+#[test]
+fn test_hat_priority_ties_break_by_id() {
+    let yaml = r#"
+hats:
+  beta:
+    name: "Beta"
+    triggers: ["beta.task"]
+  alpha:
+    name: "Alpha"
+    triggers: ["alpha.task"]
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+
+    event_loop.bus.publish(Event::new("beta.task", "b"));
+    event_loop.bus.publish(Event::new("alpha.task", "a"));
+
+    assert_eq!(
+        event_loop.select_highest_priority_hat().map(HatId::as_str),
+        Some("alpha")
+    );
+}
+
 #[test]
 fn test_guidance_persists_across_iterations_solo_mode() {
     let config = RalphConfig::default();
@@ -334,6 +394,133 @@ fn test_completion_promise_detection() {
     );
 }
 
+fn orphan_test_loop(mode: crate::config::OrphanEventMode) -> (EventLoop, tempfile::TempDir) {
+    orphan_test_loop_with_event(mode, "bulid.done", "typo")
+}
+
+/// Builds a loop with one `builder` hat (triggered by `build.task`) that has
+/// read a single `topic` event from JSONL.
+fn orphan_test_loop_with_event(
+    mode: crate::config::OrphanEventMode,
+    topic: &str,
+    payload: &str,
+) -> (EventLoop, tempfile::TempDir) {
+    let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+"#;
+    let mut config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    config.event_loop.on_orphan_event = mode;
+    orphan_loop_from_config(config, topic, payload)
+}
+
+fn orphan_loop_from_config(
+    config: RalphConfig,
+    topic: &str,
+    payload: &str,
+) -> (EventLoop, tempfile::TempDir) {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+    let ralph = HatId::new("ralph");
+    event_loop.bus.take_pending(&ralph);
+    event_loop.event_reader =
+        crate::event_reader::EventReader::new(temp_dir.path().join("events.jsonl"));
+    write_event_to_jsonl(&temp_dir.path().join("events.jsonl"), topic, payload);
+    (event_loop, temp_dir)
+}
+
+#[test]
+fn test_orphan_event_route_to_ralph_is_silent() {
+    let (mut event_loop, _temp_dir) =
+        orphan_test_loop(crate::config::OrphanEventMode::RouteToRalph);
+
+    assert!(event_loop.process_events_from_jsonl().unwrap());
+    let pending = event_loop.bus.take_pending(&HatId::new("ralph"));
+    let topics: Vec<_> = pending.iter().map(|e| e.topic.as_str()).collect();
+    assert_eq!(topics, vec!["bulid.done"]);
+}
+
+#[test]
+fn test_orphan_event_error_mode_publishes_orphaned_event() {
+    let (mut event_loop, _temp_dir) = orphan_test_loop(crate::config::OrphanEventMode::Error);
+
+    assert!(event_loop.process_events_from_jsonl().unwrap());
+    let pending = event_loop.bus.take_pending(&HatId::new("ralph"));
+    let topics: Vec<_> = pending.iter().map(|e| e.topic.as_str()).collect();
+    assert_eq!(topics, vec!["event.orphaned"]);
+    assert!(pending[0].payload.contains("bulid.done"));
+
+    // The rejected event is never counted as published
+    assert_eq!(event_loop.state.topic_counts.get("bulid.done"), None);
+    assert_eq!(
+        event_loop.state.topic_counts.get("event.orphaned"),
+        Some(&1)
+    );
+}
+
+#[test]
+fn test_orphan_event_error_mode_keeps_human_interact() {
+    let (mut event_loop, _temp_dir) = orphan_test_loop_with_event(
+        crate::config::OrphanEventMode::Error,
+        "human.interact",
+        "Which database should I use?",
+    );
+
+    event_loop.process_events_from_jsonl().unwrap();
+    let human = event_loop.bus.take_human_pending();
+    assert_eq!(human.len(), 1);
+    assert_eq!(human[0].topic.as_str(), "human.interact");
+    assert_eq!(event_loop.state.topic_counts.get("event.orphaned"), None);
+}
+
+#[test]
+fn test_orphan_event_error_mode_keeps_build_blocked_for_thrashing_detection() {
+    let (mut event_loop, _temp_dir) = orphan_test_loop_with_event(
+        crate::config::OrphanEventMode::Error,
+        "build.blocked",
+        "Task: task-1 blocked on missing credentials",
+    );
+
+    event_loop.process_events_from_jsonl().unwrap();
+    let pending = event_loop.bus.take_pending(&HatId::new("ralph"));
+    let topics: Vec<_> = pending.iter().map(|e| e.topic.as_str()).collect();
+    assert_eq!(topics, vec!["build.blocked"]);
+    assert_eq!(event_loop.state.consecutive_blocked, 1);
+    assert_eq!(event_loop.state.task_block_counts.values().sum::<u32>(), 1);
+}
+
+#[test]
+fn test_orphan_event_error_mode_keeps_synthesized_backpressure_events() {
+    // The builder hat publishes build.done, but nothing subscribes to the
+    // build.blocked that backpressure substitutes for it.
+    let (mut event_loop, _temp_dir) =
+        orphan_test_loop_with_event(crate::config::OrphanEventMode::Error, "build.done", "done");
+
+    event_loop.process_events_from_jsonl().unwrap();
+    let pending = event_loop.bus.take_pending(&HatId::new("ralph"));
+    let topics: Vec<_> = pending.iter().map(|e| e.topic.as_str()).collect();
+    assert_eq!(topics, vec!["build.blocked"]);
+}
+
+#[test]
+fn test_orphan_event_error_mode_routes_to_ralph_without_hats() {
+    let mut config = RalphConfig::default();
+    config.event_loop.on_orphan_event = crate::config::OrphanEventMode::Error;
+    let (mut event_loop, _temp_dir) = orphan_loop_from_config(config, "research.done", "notes");
+
+    assert!(event_loop.process_events_from_jsonl().unwrap());
+    let pending = event_loop.bus.take_pending(&HatId::new("ralph"));
+    let topics: Vec<_> = pending.iter().map(|e| e.topic.as_str()).collect();
+    assert_eq!(topics, vec!["research.done"]);
+
+    let preview = event_loop.preview_events(vec![Event::new("research.done", "notes")]);
+    assert_eq!(preview.events[0].outcome.topic.as_str(), "research.done");
+}
+
 #[test]
 fn test_completion_promise_with_open_tasks_still_terminates() {
     use std::fs;
@@ -438,6 +625,30 @@ fn test_completion_promise_requires_last_event() {
     );
 }
 
+#[test]
+fn test_completion_mid_batch_honored_with_any_position() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.event_loop.completion_position = crate::config::CompletionPosition::Any;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    // Agents may flush a summary event after completion in the same batch.
+    write_event_to_jsonl(&events_path, "LOOP_COMPLETE", "Done");
+    write_event_to_jsonl(&events_path, "summary.ready", "All work landed");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(
+        event_loop.check_completion_event(),
+        Some(TerminationReason::CompletionPromise)
+    );
+}
+
 #[test]
 fn test_builder_cannot_terminate_loop() {
     // Per spec: completion requires an emitted event; output-only tokens are ignored
@@ -1044,6 +1255,7 @@ fn test_default_publishes_injects_when_no_events() {
             backend: None,
             default_publishes: Some("task.done".to_string()),
             max_activations: None,
+            priority: 0,
         },
     );
     config.hats = hats;
@@ -1093,6 +1305,7 @@ fn test_default_publishes_not_injected_when_events_written() {
             backend: None,
             default_publishes: Some("task.done".to_string()),
             max_activations: None,
+            priority: 0,
         },
     );
     config.hats = hats;
@@ -1144,6 +1357,7 @@ fn test_default_publishes_not_injected_when_not_configured() {
             backend: None,
             default_publishes: None, // No default configured
             max_activations: None,
+            priority: 0,
         },
     );
     config.hats = hats;
@@ -1597,6 +1811,52 @@ fn test_check_for_user_prompt_detects_user_prompt_event() {
     assert_eq!(user_prompt.unwrap().id, "q1");
 }
 
+#[test]
+fn test_check_for_user_prompt_uses_configured_topic() {
+    let config: RalphConfig =
+        serde_yaml::from_str("event_loop:\n  user_prompt_topic: plan.question\n").unwrap();
+    let event_loop = EventLoop::new(config);
+
+    let events = vec![
+        Event::new("user.prompt", "Ignored under a custom topic"),
+        Event::new("plan.question", r#"{"id": "q3", "question": "Which API?"}"#),
+    ];
+
+    let user_prompt = event_loop.check_for_user_prompt(&events).unwrap();
+    assert_eq!(user_prompt.id, "q3");
+    assert!(user_prompt.text.contains("Which API?"));
+}
+
+#[test]
+fn test_check_for_user_prompt_populates_metadata() {
+    let config: RalphConfig = serde_yaml::from_str("hats: {}").unwrap();
+    let event_loop = EventLoop::new(config);
+
+    let before = chrono::Utc::now();
+    let events = vec![
+        Event::new(
+            "user.prompt",
+            r#"{"id": "q5", "question": "Which store?", "options": ["sqlite", "postgres"]}"#,
+        )
+        .with_source("planner"),
+    ];
+
+    let user_prompt = event_loop.check_for_user_prompt(&events).unwrap();
+    assert_eq!(user_prompt.id, "q5");
+    assert_eq!(user_prompt.source_hat, Some(HatId::new("planner")));
+    assert!(user_prompt.created_at >= before);
+    assert_eq!(
+        user_prompt.options,
+        Some(vec!["sqlite".to_string(), "postgres".to_string()])
+    );
+
+    let plain = event_loop
+        .check_for_user_prompt(&[Event::new("user.prompt", "Free-form question?")])
+        .unwrap();
+    assert!(plain.source_hat.is_none());
+    assert!(plain.options.is_none());
+}
+
 #[test]
 fn test_check_for_user_prompt_returns_none_when_no_user_prompt() {
     // Create EventLoop
@@ -1857,6 +2117,77 @@ fn test_malformed_counter_resets_on_valid_event() {
     );
 }
 
+#[test]
+fn test_malformed_counter_decays_with_window() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.event_loop.malformed_decay_window = 2;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+    event_loop.initialize("Test");
+
+    std::fs::write(&events_path, "not valid json\nstill not json\n").unwrap();
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(event_loop.state.consecutive_malformed_events, 2);
+
+    write_event_to_jsonl(&events_path, "build.task", "one");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(
+        event_loop.state.consecutive_malformed_events, 2,
+        "One valid event is not enough to decay with a window of 2"
+    );
+
+    write_event_to_jsonl(&events_path, "build.task", "two");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(
+        event_loop.state.consecutive_malformed_events, 1,
+        "Two valid events should forgive one malformed line"
+    );
+}
+
+#[test]
+fn test_termination_thresholds_follow_config() {
+    let mut config = RalphConfig::default();
+    config.event_loop.max_malformed_events = 5;
+    config.event_loop.max_abandoned_redispatches = 4;
+    let mut event_loop = EventLoop::new(config);
+
+    event_loop.state.consecutive_malformed_events = 4;
+    event_loop.state.abandoned_task_redispatches = 3;
+    assert_eq!(event_loop.check_termination(), None);
+
+    event_loop.state.consecutive_malformed_events = 5;
+    assert_eq!(
+        event_loop.check_termination(),
+        Some(TerminationReason::ValidationFailure)
+    );
+
+    event_loop.state.consecutive_malformed_events = 0;
+    event_loop.state.abandoned_task_redispatches = 4;
+    assert_eq!(
+        event_loop.check_termination(),
+        Some(TerminationReason::LoopThrashing)
+    );
+}
+
+#[test]
+fn test_zero_termination_thresholds_disable_checks() {
+    let mut config = RalphConfig::default();
+    config.event_loop.max_malformed_events = 0;
+    config.event_loop.max_abandoned_redispatches = 0;
+    let mut event_loop = EventLoop::new(config);
+
+    assert_eq!(event_loop.check_termination(), None);
+
+    event_loop.state.consecutive_malformed_events = 50;
+    event_loop.state.abandoned_task_redispatches = 50;
+    assert_eq!(event_loop.check_termination(), None);
+}
+
 #[test]
 fn test_validation_failure_termination_at_threshold() {
     // Kills: line 1165 `>=` → `<` and `&&` → `||`
@@ -1932,6 +2263,25 @@ fn test_format_event_wraps_top_level_prompts() {
     );
 }
 
+#[test]
+fn test_format_event_wraps_configured_top_level_topics() {
+    let mut config = RalphConfig::default();
+    config
+        .event_loop
+        .top_level_topics
+        .push("plan.start".to_string());
+    let event_loop = EventLoop::new(config);
+
+    let custom = event_loop.format_event(&Event::new("plan.start", "Design the API"));
+    assert_eq!(
+        custom,
+        "Event: plan.start - <top-level-prompt>\nDesign the API\n</top-level-prompt>"
+    );
+
+    let normal = event_loop.format_event(&Event::new("build.done", "completed"));
+    assert_eq!(normal, "Event: build.done - completed");
+}
+
 #[test]
 fn test_check_ralph_completion_detection() {
     // Kills: line 1241 return `true` / `false`
@@ -2127,6 +2477,79 @@ fn test_scratchpad_injection_tail_truncation() {
     );
 }
 
+/// Builds a prompt with a small scratchpad budget and the given truncation mode.
+fn prompt_with_truncated_scratchpad(mode: crate::config::TruncateMode) -> String {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let scratchpad_path = temp_dir.path().join(".ralph/agent/scratchpad.md");
+    std::fs::create_dir_all(scratchpad_path.parent().unwrap()).unwrap();
+
+    let mut content = String::new();
+    for section in ["Goals", "Research", "Notes"] {
+        content.push_str(&format!("### {section}\n"));
+        for i in 0..20 {
+            content.push_str(&format!("{section} line {i}\n"));
+        }
+    }
+    std::fs::write(&scratchpad_path, &content).unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.core.scratchpad_budget_tokens = 50;
+    config.core.scratchpad_truncate = mode;
+
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test prompt");
+    event_loop.build_prompt(&HatId::new("ralph")).unwrap()
+}
+
+#[test]
+fn test_scratchpad_truncate_modes() {
+    use crate::config::TruncateMode;
+
+    let tail = prompt_with_truncated_scratchpad(TruncateMode::Tail);
+    assert!(tail.contains("Notes line 19"));
+    assert!(!tail.contains("Goals line 0"));
+    assert!(tail.contains("<!-- earlier content truncated"));
+    assert!(tail.contains("discarded sections: ### Goals | ### Research"));
+
+    let head = prompt_with_truncated_scratchpad(TruncateMode::Head);
+    assert!(head.contains("### Goals\nGoals line 0"));
+    assert!(!head.contains("Notes line 19"));
+    assert!(head.contains("<!-- later content truncated"));
+    assert!(head.contains("discarded sections: ### Research | ### Notes"));
+
+    let middle = prompt_with_truncated_scratchpad(TruncateMode::Middle);
+    assert!(middle.contains("Goals line 0"));
+    assert!(middle.contains("Notes line 19"));
+    assert!(!middle.contains("Research line 10"));
+    assert!(middle.contains("<!-- middle content truncated"));
+    assert!(middle.contains("discarded sections: ### Research"));
+}
+
+#[test]
+fn test_scratchpad_budget_tokens_configurable() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let scratchpad_path = temp_dir.path().join(".ralph/agent/scratchpad.md");
+    std::fs::create_dir_all(scratchpad_path.parent().unwrap()).unwrap();
+    // ~20000 chars: over the default 16000-char budget
+    let mut content = String::new();
+    for i in 0..1000 {
+        content.push_str(&format!("Entry {i:04} padding\n"));
+    }
+    std::fs::write(&scratchpad_path, &content).unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.core.scratchpad_budget_tokens = 8000;
+
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test prompt");
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+
+    assert!(prompt.contains("Entry 0000 padding"));
+    assert!(!prompt.contains("content truncated"));
+}
+
 #[test]
 fn test_build_done_backpressure_accepts_mutants_warning() {
     use tempfile::tempdir;
@@ -2649,6 +3072,52 @@ fn test_non_persistent_mode_terminates_on_loop_complete() {
     );
 }
 
+#[test]
+fn test_require_verify_before_completion_blocks_until_verify_passes() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.event_loop.require_verify_before_completion = true;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    // Completion without a prior verify.passed is rejected
+    write_event_to_jsonl(&events_path, "LOOP_COMPLETE", "Done");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(event_loop.check_completion_event(), None);
+    let ralph_id = HatId::new("ralph");
+    assert!(
+        event_loop
+            .bus
+            .peek_pending(&ralph_id)
+            .is_some_and(|events| events
+                .iter()
+                .any(|e| e.topic.as_str() == "task.resume" && e.payload.contains("verify.passed"))),
+        "Rejected completion should re-inject task.resume"
+    );
+
+    // A verify.passed that fails thresholds does not qualify
+    write_event_to_jsonl(&events_path, "verify.passed", "All good");
+    let _ = event_loop.process_events_from_jsonl();
+    assert!(!event_loop.state.verify_passed_seen);
+
+    // A passing verify.passed unlocks completion
+    let payload = "quality.tests: pass\nquality.coverage: 82%\nquality.lint: pass\nquality.audit: pass\nquality.mutation: 72%\nquality.complexity: 7";
+    write_event_to_jsonl(&events_path, "verify.passed", payload);
+    let _ = event_loop.process_events_from_jsonl();
+    assert!(event_loop.state.verify_passed_seen);
+
+    write_event_to_jsonl(&events_path, "LOOP_COMPLETE", "Done");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(
+        event_loop.check_completion_event(),
+        Some(TerminationReason::CompletionPromise)
+    );
+}
+
 #[test]
 fn test_persistent_mode_still_respects_hard_limits() {
     let yaml = r"
@@ -2834,6 +3303,19 @@ fn test_extract_prompt_id_prefers_xml_id() {
     assert_eq!(EventLoop::extract_prompt_id(payload), "q42");
 }
 
+#[test]
+fn test_extract_prompt_id_from_json_payload() {
+    let payload = r#"{"id": "q7", "question": "Which database?"}"#;
+    assert_eq!(EventLoop::extract_prompt_id(payload), "q7");
+}
+
+#[test]
+fn test_extract_prompt_id_ignores_json_without_string_id() {
+    let id = EventLoop::extract_prompt_id(r#"{"question": "Which database?"}"#);
+    assert!(id.starts_with('q'));
+    assert!(id.len() > 1);
+}
+
 #[test]
 fn test_extract_prompt_id_fallback_prefix() {
     let id = EventLoop::extract_prompt_id("Plain question");
@@ -3126,17 +3608,63 @@ fn test_record_hat_activations_increments_counts() {
 }
 
 #[test]
-fn test_check_hat_exhaustion_emits_once_at_limit() {
+fn test_max_events_per_hat_balances_prompt_context() {
     let yaml = r#"
+event_loop:
+  max_events_per_hat: 2
 hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
   reviewer:
     name: "Reviewer"
-    triggers: ["review.done"]
-    publishes: ["review.blocked"]
-    max_activations: 2
+    triggers: ["review.request"]
+    publishes: ["review.done"]
 "#;
     let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
-    let mut event_loop = EventLoop::new(config);
+    let temp_dir = tempfile::tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    // Agent-emitted events carry no source; the flood of build tasks comes first
+    for i in 0..5 {
+        write_event_to_jsonl(&events_path, "build.task", &format!("build {i}"));
+    }
+    for i in 0..3 {
+        write_event_to_jsonl(&events_path, "review.request", &format!("review {i}"));
+    }
+    event_loop.process_events_from_jsonl().unwrap();
+
+    let ralph = HatId::new("ralph");
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert_eq!(prompt.matches("Event: build.task").count(), 2);
+    assert_eq!(prompt.matches("Event: review.request").count(), 2);
+    assert!(prompt.contains("build 0") && prompt.contains("build 1"));
+    assert!(!prompt.contains("build 2"));
+
+    // Deferred events are delivered on later iterations, oldest first.
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(prompt.contains("build 2") && prompt.contains("build 3"));
+    assert!(prompt.contains("review 2"));
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(prompt.contains("build 4"));
+    assert!(!prompt.contains("Event: review.request"));
+}
+
+#[test]
+fn test_check_hat_exhaustion_emits_once_at_limit() {
+    let yaml = r#"
+hats:
+  reviewer:
+    name: "Reviewer"
+    triggers: ["review.done"]
+    publishes: ["review.blocked"]
+    max_activations: 2
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
     let hat_id = HatId::new("reviewer");
     let dropped = vec![
         Event::new("review.done", "ok"),
@@ -3166,3 +3694,1313 @@ hats:
     assert!(drop_again);
     assert!(event_again.is_none());
 }
+
+// === Prompt Size Accounting Tests ===
+
+#[test]
+fn test_prompt_breakdown_sums_to_total_with_all_sections() {
+    use crate::memory::{Memory, MemoryType};
+    use crate::task::Task;
+    use crate::task_store::TaskStore;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    let scratchpad_path = root.join(".ralph/agent/scratchpad.md");
+    std::fs::create_dir_all(scratchpad_path.parent().unwrap()).unwrap();
+    std::fs::write(&scratchpad_path, "## Progress\n- [ ] Step 1\n").unwrap();
+
+    MarkdownMemoryStore::with_default_path(root)
+        .append(&Memory::new(
+            MemoryType::Pattern,
+            "Use thiserror for error types".to_string(),
+            vec!["errors".to_string()],
+        ))
+        .unwrap();
+
+    let mut store = TaskStore::load(&root.join(".ralph/agent/tasks.jsonl")).unwrap();
+    store.add(Task::new("Write the parser".to_string(), 1));
+    store.save().unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = root.to_path_buf();
+    config.memories.enabled = true;
+    config.tasks.enabled = true;
+
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test prompt");
+
+    let (prompt, breakdown) = event_loop
+        .build_prompt_with_breakdown(&HatId::new("ralph"))
+        .unwrap();
+
+    assert_eq!(breakdown.total(), prompt.len());
+    assert!(breakdown.base > 0, "base should be counted");
+    assert!(breakdown.memories > 0, "memories should be counted");
+    assert!(breakdown.skills > 0, "ralph-tools skill should be counted");
+    assert!(breakdown.scratchpad > 0, "scratchpad should be counted");
+    assert!(breakdown.ready_tasks > 0, "ready tasks should be counted");
+    assert_eq!(breakdown.estimated_tokens(), prompt.len().div_ceil(4));
+
+    // Sections are laid out in order: ready tasks, scratchpad, memories, skills, base
+    let scratchpad_start = breakdown.ready_tasks;
+    let memories_start = scratchpad_start + breakdown.scratchpad;
+    let skills_start = memories_start + breakdown.memories;
+    assert!(prompt.starts_with("<ready-tasks>"));
+    assert!(prompt[scratchpad_start..].starts_with("<scratchpad"));
+    assert!(prompt[memories_start..].starts_with("# Memories"));
+    assert!(prompt[skills_start..].starts_with("<ralph-tools-skill>"));
+}
+
+#[test]
+fn test_prompt_breakdown_base_only_when_nothing_injected() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.memories.enabled = false;
+    config.tasks.enabled = false;
+
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test prompt");
+
+    let (prompt, breakdown) = event_loop
+        .build_prompt_with_breakdown(&HatId::new("ralph"))
+        .unwrap();
+
+    assert_eq!(
+        breakdown,
+        PromptBreakdown {
+            base: prompt.len(),
+            ..PromptBreakdown::default()
+        }
+    );
+}
+
+#[test]
+fn test_build_prompt_matches_breakdown_prompt() {
+    let yaml = r#"
+hats:
+  planner:
+    name: "Planner"
+    triggers: ["task.start"]
+    publishes: ["build.task"]
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut plain = EventLoop::new(config.clone());
+    let mut accounted = EventLoop::new(config);
+    plain.initialize("Same prompt");
+    accounted.initialize("Same prompt");
+
+    let expected = plain.build_prompt(&HatId::new("ralph")).unwrap();
+    let (prompt, breakdown) = accounted
+        .build_prompt_with_breakdown(&HatId::new("ralph"))
+        .unwrap();
+
+    assert_eq!(prompt, expected);
+    assert_eq!(breakdown.total(), prompt.len());
+}
+
+// === Memories Path Resolution Tests ===
+
+#[test]
+fn test_memory_injection_reads_custom_memories_path() {
+    use crate::memory::{Memory, MemoryType};
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+
+    MarkdownMemoryStore::new(root.join("docs/team-memories.md"))
+        .append(&Memory::new(
+            MemoryType::Decision,
+            "Custom path memory".to_string(),
+            vec![],
+        ))
+        .unwrap();
+    MarkdownMemoryStore::with_default_path(root)
+        .append(&Memory::new(
+            MemoryType::Decision,
+            "Default path memory".to_string(),
+            vec![],
+        ))
+        .unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = root.to_path_buf();
+    config.memories.path = Some(PathBuf::from("docs/team-memories.md"));
+
+    let mut event_loop = EventLoop::new(config);
+    assert_eq!(
+        event_loop.memories_path(),
+        root.join("docs/team-memories.md")
+    );
+
+    event_loop.initialize("Test prompt");
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+
+    assert!(prompt.contains("Custom path memory"));
+    assert!(
+        !prompt.contains("Default path memory"),
+        "Default memories file should be ignored when a path override is set"
+    );
+}
+
+#[test]
+fn test_memories_path_resolves_against_loop_context() {
+    use crate::loop_context::LoopContext;
+    use crate::memory::{Memory, MemoryType};
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let workspace = temp_dir.path().to_path_buf();
+
+    // config.core.workspace_root stays at its default; the context decides.
+    let loop_context = LoopContext::primary(workspace.clone());
+    let mut event_loop = EventLoop::with_context(RalphConfig::default(), loop_context.clone());
+    assert_eq!(event_loop.memories_path(), loop_context.memories_path());
+
+    MarkdownMemoryStore::new(loop_context.memories_path())
+        .append(&Memory::new(
+            MemoryType::Context,
+            "Context-resolved memory".to_string(),
+            vec![],
+        ))
+        .unwrap();
+
+    event_loop.initialize("Test prompt");
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+    assert!(prompt.contains("Context-resolved memory"));
+
+    // Relative overrides resolve against the context workspace.
+    let mut config = RalphConfig::default();
+    config.memories.path = Some(PathBuf::from("notes/memories.md"));
+    let event_loop = EventLoop::with_context(config, loop_context);
+    assert_eq!(
+        event_loop.memories_path(),
+        workspace.join("notes/memories.md")
+    );
+}
+
+#[test]
+fn test_memory_injection_whole_memories_budget_mode() {
+    use crate::config::MemoryBudgetMode;
+    use crate::memory::{Memory, MemoryType};
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let store = MarkdownMemoryStore::with_default_path(root);
+
+    let mut memories = Vec::new();
+    for (i, created) in ["2025-01-01", "2025-02-01", "2025-03-01"]
+        .iter()
+        .enumerate()
+    {
+        let memory = Memory {
+            id: format!("mem-173737200{i}-a1b{i}"),
+            memory_type: MemoryType::Pattern,
+            content: format!(
+                "Memory number {i}. {}End of memory {i}.",
+                "filler ".repeat(30)
+            ),
+            tags: vec![],
+            created: (*created).to_string(),
+        };
+        store.append(&memory).unwrap();
+        memories.push(memory);
+    }
+
+    // Budget fits the two newest memories and the omission note, but not all three.
+    let budget = (format_memories_as_markdown(&memories[1..]).len() + 80).div_ceil(4);
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = root.to_path_buf();
+    config.memories.budget = budget;
+    config.memories.budget_mode = MemoryBudgetMode::WholeMemories;
+
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test prompt");
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+
+    for i in 1..3 {
+        assert!(prompt.contains(&format!("Memory number {i}.")));
+        assert!(
+            prompt.contains(&format!("End of memory {i}.")),
+            "kept memories must be injected whole"
+        );
+    }
+    assert!(
+        !prompt.contains("Memory number 0."),
+        "oldest memory should be dropped entirely"
+    );
+    assert!(prompt.contains("1 memories omitted"));
+    assert!(!prompt.contains("<!-- truncated:"));
+}
+
+#[test]
+fn test_memory_injection_filters_by_tags() {
+    use crate::memory::{Memory, MemoryType};
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let store = MarkdownMemoryStore::with_default_path(root);
+
+    for i in 0..10 {
+        let tag = if i % 3 == 0 && i > 0 { "auth" } else { "db" };
+        store
+            .append(&Memory {
+                id: format!("mem-17373720{i:02}-a1b2"),
+                memory_type: MemoryType::Pattern,
+                content: format!("Memory number {i} about {tag}"),
+                tags: vec![tag.to_string()],
+                created: "2025-01-01".to_string(),
+            })
+            .unwrap();
+    }
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = root.to_path_buf();
+    config.memories.filter.tags = vec!["auth".to_string()];
+
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test prompt");
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+
+    let injected: Vec<usize> = (0..10)
+        .filter(|i| prompt.contains(&format!("Memory number {i} about")))
+        .collect();
+    assert_eq!(injected, vec![3, 6, 9]);
+}
+
+#[test]
+fn test_memory_tag_filter_includes_objective_tags() {
+    use crate::memory::{Memory, MemoryType};
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let store = MarkdownMemoryStore::with_default_path(root);
+    for (i, tag) in ["auth", "billing", "ui"].iter().enumerate() {
+        store
+            .append(&Memory {
+                id: format!("mem-173737200{i}-a1b2"),
+                memory_type: MemoryType::Context,
+                content: format!("Memory tagged {tag}"),
+                tags: vec![(*tag).to_string()],
+                created: "2025-01-01".to_string(),
+            })
+            .unwrap();
+    }
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = root.to_path_buf();
+    config.memories.filter.tags = vec!["auth".to_string()];
+
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Fix the Billing export for all users");
+    assert_eq!(
+        event_loop.objective_tags(),
+        vec!["fix", "billing", "export", "users"]
+    );
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+    assert!(prompt.contains("Memory tagged auth"));
+    assert!(prompt.contains("Memory tagged billing"));
+    assert!(!prompt.contains("Memory tagged ui"));
+}
+
+// === Skill Injection Gating Tests ===
+
+#[test]
+fn test_ralph_tools_injected_only_for_configured_hats() {
+    let yaml = r#"
+hats:
+  planner:
+    name: "Planner"
+    triggers: ["task.start"]
+    publishes: ["build.task"]
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+skills:
+  overrides:
+    ralph-tools:
+      hats: ["builder"]
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    let ralph_id = HatId::new("ralph");
+
+    event_loop.initialize("Test prompt");
+    let planner_prompt = event_loop.build_prompt(&ralph_id).unwrap();
+    assert!(
+        !planner_prompt.contains("<ralph-tools-skill>"),
+        "ralph-tools should not be injected when only the planner is active"
+    );
+
+    event_loop
+        .bus
+        .publish(Event::new("build.task", "Implement the parser"));
+    let builder_prompt = event_loop.build_prompt(&ralph_id).unwrap();
+    assert!(
+        builder_prompt.contains("<ralph-tools-skill>"),
+        "ralph-tools should be injected when the builder is active"
+    );
+}
+
+#[test]
+fn test_hat_restricted_skills_visible_in_solo_mode() {
+    // Solo mode has no hats to gate on, so hat restrictions don't hide skills
+    // (matches SkillRegistry::is_visible with no hat).
+    let yaml = r#"
+skills:
+  overrides:
+    ralph-tools:
+      hats: ["builder"]
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test prompt");
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+    assert!(prompt.contains("<ralph-tools-skill>"));
+}
+
+#[test]
+fn test_robot_skill_disabled_via_skill_override() {
+    let yaml = r#"
+RObot:
+  enabled: true
+  telegram:
+    bot_token: "fake-token"
+skills:
+  overrides:
+    robot-interaction:
+      enabled: false
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test prompt");
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+    assert!(!prompt.contains("<robot-skill>"));
+}
+
+// === Pure Event Validation Tests ===
+
+fn validated_topics(event_loop: &EventLoop, topic: &str, payload: &str) -> Vec<String> {
+    event_loop
+        .validate_events(vec![Event::new(topic, payload)])
+        .into_iter()
+        .map(|e| e.topic.to_string())
+        .collect()
+}
+
+#[test]
+fn test_write_run_report_after_simulated_run() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.initialize("Test");
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "plan.ready", "step 1");
+    write_event_to_jsonl(&events_path, "plan.ready", "step 2");
+    write_event_to_jsonl(&events_path, "build.done", "no evidence");
+    event_loop.process_events_from_jsonl().unwrap();
+    event_loop.state.iteration = 3;
+    event_loop.record_hat_activations(&[HatId::new("builder")]);
+    event_loop.add_cost_for_hat(&HatId::new("builder"), 0.5);
+
+    let report_path = temp_dir.path().join("reports").join("run.json");
+    event_loop
+        .write_run_report(&report_path, &TerminationReason::MaxIterations)
+        .unwrap();
+
+    let report: RunReport =
+        serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+    assert_eq!(report.summary.reason, "max_iterations");
+    assert_eq!(report.summary.iterations, 3);
+    assert_eq!(report.topic_counts.get("plan.ready"), Some(&2));
+    assert_eq!(report.topic_counts.get("build.blocked"), Some(&1));
+    assert_eq!(report.hat_activations.get("builder"), Some(&1));
+    assert_eq!(
+        report.cost_breakdown,
+        vec![HatCost {
+            hat: "builder".to_string(),
+            cost_usd: 0.5,
+        }]
+    );
+    assert!(!report_path.with_extension("json.tmp").exists());
+}
+
+#[test]
+fn test_require_payload_topics_rejects_empty_payloads() {
+    let mut config = RalphConfig::default();
+    config.event_loop.require_payload_topics = vec!["build.done".to_string()];
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+
+    let preview = event_loop.preview_events(vec![
+        Event::new("build.done", "  \n"),
+        Event::new("task.resume", ""),
+    ]);
+
+    let rejected = &preview.events[0];
+    assert_eq!(rejected.outcome.topic.as_str(), "event.rejected");
+    assert!(rejected.outcome.payload.contains("build.done"));
+    assert_eq!(
+        rejected.backpressure.as_deref(),
+        Some("empty payload for 'build.done'")
+    );
+
+    let allowed = &preview.events[1];
+    assert_eq!(allowed.outcome.topic.as_str(), "task.resume");
+    assert!(allowed.backpressure.is_none());
+}
+
+#[test]
+fn test_require_payload_topics_applies_to_injected_events() {
+    let mut config = RalphConfig::default();
+    config.event_loop.require_payload_topics = vec!["plan.ready".to_string()];
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+    let ralph = HatId::new("ralph");
+    event_loop.bus.take_pending(&ralph);
+
+    event_loop
+        .inject_event(Event::new("plan.ready", ""))
+        .unwrap();
+    event_loop
+        .inject_event(Event::new("plan.ready", "step 1: parse config"))
+        .unwrap();
+
+    let topics: Vec<_> = event_loop
+        .bus
+        .take_pending(&ralph)
+        .iter()
+        .map(|e| e.topic.as_str().to_string())
+        .collect();
+    assert_eq!(topics, vec!["event.rejected", "plan.ready"]);
+}
+
+#[test]
+fn test_preview_events_plans_build_blocked_without_mutating() {
+    let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+  fixer:
+    name: "Fixer"
+    triggers: ["build.blocked"]
+    publishes: ["build.task"]
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let event_loop = EventLoop::new(config);
+
+    let preview = event_loop.preview_events(vec![
+        Event::new("build.done", "all done, trust me"),
+        Event::new("build.task", "Implement the parser"),
+        Event::new("misc.note", "fyi"),
+        Event::new("LOOP_COMPLETE", ""),
+    ]);
+
+    let blocked = &preview.events[0];
+    assert_eq!(blocked.outcome.topic.as_str(), "build.blocked");
+    assert!(blocked.backpressure.is_some());
+    assert_eq!(blocked.routed_to, Some(HatId::new("fixer")));
+
+    let task = &preview.events[1];
+    assert!(!task.is_transformed());
+    assert_eq!(task.routed_to, Some(HatId::new("builder")));
+
+    assert!(preview.events[2].is_orphan());
+    assert!(preview.events[3].is_completion);
+    assert!(preview.has_orphans());
+    assert_eq!(preview.transforms().count(), 1);
+
+    assert_eq!(event_loop.state.consecutive_blocked, 0);
+    assert!(!event_loop.state.completion_requested);
+}
+
+#[test]
+fn test_build_done_respects_configured_complexity_threshold() {
+    let payload = "tests: pass\nlint: pass\ntypecheck: pass\naudit: pass\ncoverage: pass\ncomplexity: 12\nduplication: pass";
+
+    let event_loop = EventLoop::new(RalphConfig::default());
+    assert_eq!(
+        validated_topics(&event_loop, "build.done", payload),
+        vec!["build.blocked"]
+    );
+
+    let mut config = RalphConfig::default();
+    config.event_loop.complexity_threshold = Some(15.0);
+    let event_loop = EventLoop::new(config);
+    assert_eq!(
+        validated_topics(&event_loop, "build.done", payload),
+        vec!["build.done"]
+    );
+}
+
+#[test]
+fn test_validate_events_build_done() {
+    let event_loop = EventLoop::new(RalphConfig::default());
+
+    let passing = "tests: pass\nlint: pass\ntypecheck: pass\naudit: pass\ncoverage: pass\ncomplexity: 7\nduplication: pass";
+    assert_eq!(
+        validated_topics(&event_loop, "build.done", passing),
+        vec!["build.done"]
+    );
+
+    let failing = "tests: fail\nlint: pass\ntypecheck: pass\naudit: pass\ncoverage: pass\ncomplexity: 7\nduplication: pass";
+    assert_eq!(
+        validated_topics(&event_loop, "build.done", failing),
+        vec!["build.blocked"]
+    );
+
+    assert_eq!(
+        validated_topics(&event_loop, "build.done", "done, trust me"),
+        vec!["build.blocked"]
+    );
+}
+
+#[test]
+fn test_validate_events_review_done() {
+    let event_loop = EventLoop::new(RalphConfig::default());
+
+    assert_eq!(
+        validated_topics(&event_loop, "review.done", "tests: pass\nbuild: pass"),
+        vec!["review.done"]
+    );
+    assert_eq!(
+        validated_topics(&event_loop, "review.done", "tests: fail\nbuild: pass"),
+        vec!["review.blocked"]
+    );
+    assert_eq!(
+        validated_topics(&event_loop, "review.done", "looks good"),
+        vec!["review.blocked"]
+    );
+}
+
+#[test]
+fn test_validate_events_verify_passed() {
+    let event_loop = EventLoop::new(RalphConfig::default());
+
+    let passing = "quality.tests: pass\nquality.coverage: 82%\nquality.lint: pass\nquality.audit: pass\nquality.mutation: 72%\nquality.complexity: 7";
+    assert_eq!(
+        validated_topics(&event_loop, "verify.passed", passing),
+        vec!["verify.passed"]
+    );
+
+    let failing = "quality.tests: pass\nquality.coverage: 40%\nquality.lint: pass\nquality.audit: pass\nquality.mutation: 72%\nquality.complexity: 7";
+    assert_eq!(
+        validated_topics(&event_loop, "verify.passed", failing),
+        vec!["verify.failed"]
+    );
+
+    assert_eq!(
+        validated_topics(&event_loop, "verify.passed", "all good"),
+        vec!["verify.failed"]
+    );
+}
+
+#[test]
+fn test_validate_events_passes_other_topics_through_without_side_effects() {
+    let event_loop = EventLoop::new(RalphConfig::default());
+
+    let validated = event_loop.validate_events(vec![
+        Event::new("verify.failed", "no report"),
+        Event::new("build.task", "Implement the parser"),
+        Event::new("build.done", "missing evidence"),
+    ]);
+
+    let topics: Vec<_> = validated.iter().map(|e| e.topic.to_string()).collect();
+    assert_eq!(topics, vec!["verify.failed", "build.task", "build.blocked"]);
+    assert_eq!(validated[1].payload, "Implement the parser");
+
+    assert!(
+        !event_loop.has_pending_events(),
+        "validate_events must not publish to the bus"
+    );
+    assert_eq!(event_loop.state().consecutive_blocked, 0);
+}
+
+// === Events Path Resolution Tests ===
+
+#[test]
+fn test_events_path_with_context_follows_marker() {
+    use crate::loop_context::LoopContext;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
+    std::fs::create_dir_all(loop_context.ralph_dir()).unwrap();
+    std::fs::write(
+        loop_context.current_events_marker(),
+        ".ralph/events-20260127-123456.jsonl\n",
+    )
+    .unwrap();
+
+    let event_loop = EventLoop::with_context(RalphConfig::default(), loop_context);
+
+    assert_eq!(
+        event_loop.events_path(),
+        temp_dir.path().join(".ralph/events-20260127-123456.jsonl")
+    );
+}
+
+#[test]
+fn test_events_path_with_context_without_marker() {
+    use crate::loop_context::LoopContext;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
+    let expected = loop_context.events_path();
+
+    let event_loop = EventLoop::with_context(RalphConfig::default(), loop_context);
+
+    assert_eq!(event_loop.events_path(), expected);
+}
+
+#[test]
+fn test_events_path_legacy_defaults_to_events_jsonl() {
+    let event_loop = EventLoop::new(RalphConfig::default());
+
+    assert_eq!(
+        event_loop.events_path(),
+        PathBuf::from(".ralph/events.jsonl")
+    );
+}
+
+#[test]
+fn test_human_response_wait_path_matches_event_reader_path() {
+    use crate::loop_context::LoopContext;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
+    std::fs::create_dir_all(loop_context.ralph_dir()).unwrap();
+    std::fs::write(
+        loop_context.current_events_marker(),
+        ".ralph/events-20260201-090000.jsonl",
+    )
+    .unwrap();
+
+    let event_loop = EventLoop::with_context(RalphConfig::default(), loop_context);
+
+    // The human.interact handler waits on events_path(); it must be the file
+    // the reader consumes, or responses would never be seen.
+    assert_eq!(event_loop.events_path(), event_loop.event_reader.path());
+}
+
+// === Loop State Persistence Tests ===
+
+#[test]
+fn test_loop_state_save_load_round_trip() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("loop-state.json");
+
+    let mut state = LoopState::new();
+    state.iteration = 7;
+    state.consecutive_failures = 2;
+    state.cumulative_cost = 1.25;
+    state.last_hat = Some(HatId::new("builder"));
+    state.abandoned_tasks.push("task-1".to_string());
+    state.task_block_counts.insert("task-1".to_string(), 3);
+    state.hat_activation_counts.insert(HatId::new("builder"), 4);
+    state.exhausted_hats.insert(HatId::new("reviewer"));
+    state.started_at = std::time::Instant::now()
+        .checked_sub(Duration::from_secs(120))
+        .unwrap();
+    state.completion_requested = true;
+
+    state.save_to(&path).unwrap();
+    let loaded = LoopState::load_from(&path).unwrap();
+
+    assert_eq!(loaded.iteration, 7);
+    assert_eq!(loaded.consecutive_failures, 2);
+    assert!((loaded.cumulative_cost - 1.25).abs() < f64::EPSILON);
+    assert_eq!(loaded.last_hat, Some(HatId::new("builder")));
+    assert_eq!(loaded.abandoned_tasks, vec!["task-1".to_string()]);
+    assert_eq!(loaded.task_block_counts.get("task-1"), Some(&3));
+    assert_eq!(
+        loaded.hat_activation_counts.get(&HatId::new("builder")),
+        Some(&4)
+    );
+    assert!(loaded.exhausted_hats.contains(&HatId::new("reviewer")));
+    assert!(loaded.elapsed() >= Duration::from_secs(120));
+    assert!(
+        !loaded.completion_requested,
+        "completion must be observed again after a restart"
+    );
+
+    // The short names remain available as wrappers
+    loaded.save(&path).unwrap();
+    assert_eq!(LoopState::load(&path).unwrap().iteration, 7);
+}
+
+#[test]
+fn test_event_loop_resumes_iteration_after_reconstruction() {
+    use crate::loop_context::LoopContext;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
+    let hat = HatId::new("ralph");
+
+    let mut event_loop = EventLoop::with_context(RalphConfig::default(), loop_context.clone());
+    for _ in 0..3 {
+        event_loop.process_output(&hat, "", true);
+    }
+    assert!(loop_context.loop_state_path().exists());
+
+    // Simulate a crash: drop the loop without terminating it.
+    drop(event_loop);
+
+    let mut restored = EventLoop::with_context(RalphConfig::default(), loop_context);
+    assert_eq!(restored.state().iteration, 3);
+    restored.process_output(&hat, "", true);
+    assert_eq!(restored.state().iteration, 4);
+}
+
+#[test]
+fn test_restart_requested_preserves_state_for_next_process() {
+    use crate::loop_context::LoopContext;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
+    let hat = HatId::new("ralph");
+
+    let mut event_loop = EventLoop::with_context(RalphConfig::default(), loop_context.clone());
+    event_loop.add_cost_for_hat(&hat, 0.5);
+    for _ in 0..5 {
+        event_loop.process_output(&hat, "", true);
+    }
+    event_loop.publish_terminate_event(&TerminationReason::RestartRequested);
+    assert!(loop_context.loop_state_path().exists());
+
+    let restored = EventLoop::with_context(RalphConfig::default(), loop_context);
+    assert_eq!(restored.state().iteration, 5);
+    assert!((restored.state().cumulative_cost - 0.5).abs() < f64::EPSILON);
+    assert_eq!(restored.state().cost_breakdown(), vec![(hat, 0.5)]);
+}
+
+#[test]
+fn test_terminate_clears_persisted_loop_state() {
+    use crate::loop_context::LoopContext;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
+    let mut event_loop = EventLoop::with_context(RalphConfig::default(), loop_context.clone());
+    event_loop.process_output(&HatId::new("ralph"), "", true);
+    assert!(loop_context.loop_state_path().exists());
+
+    event_loop.publish_terminate_event(&TerminationReason::CompletionPromise);
+
+    assert!(!loop_context.loop_state_path().exists());
+}
+
+#[test]
+fn test_loop_state_persisted_at_configured_iteration_cadence() {
+    use crate::loop_context::LoopContext;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
+    let state_path = loop_context.loop_state_path();
+    let mut config = RalphConfig::default();
+    config.event_loop.persistence_interval.iterations = 3;
+    let mut event_loop = EventLoop::with_context(config, loop_context);
+    let hat = HatId::new("ralph");
+
+    event_loop.process_output(&hat, "", true);
+    event_loop.process_output(&hat, "", true);
+    assert!(
+        !state_path.exists(),
+        "should not persist before 3 iterations"
+    );
+
+    event_loop.process_output(&hat, "", true);
+    assert_eq!(LoopState::load_from(&state_path).unwrap().iteration, 3);
+
+    event_loop.process_output(&hat, "", true);
+    assert_eq!(
+        LoopState::load_from(&state_path).unwrap().iteration,
+        3,
+        "iteration 4 is between persistence points"
+    );
+}
+
+#[test]
+fn test_loop_state_time_trigger_not_due_skips_persistence() {
+    use crate::loop_context::LoopContext;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
+    let state_path = loop_context.loop_state_path();
+    let mut config = RalphConfig::default();
+    config.event_loop.persistence_interval.iterations = 0;
+    config.event_loop.persistence_interval.seconds = Some(3600);
+    let mut event_loop = EventLoop::with_context(config, loop_context);
+
+    for _ in 0..5 {
+        event_loop.process_output(&HatId::new("ralph"), "", true);
+    }
+
+    assert!(!state_path.exists());
+}
+
+// === Inject Event Tests ===
+
+#[test]
+fn test_inject_event_transforms_unverified_build_done() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+
+    event_loop
+        .inject_event(Event::new("build.done", "done, trust me"))
+        .unwrap();
+
+    let pending = event_loop.bus.peek_pending(&HatId::new("ralph")).unwrap();
+    let topics: Vec<&str> = pending.iter().map(|e| e.topic.as_str()).collect();
+    assert_eq!(topics, vec!["build.blocked"]);
+}
+
+#[test]
+fn test_inject_event_passes_verified_build_done() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    let passing = "tests: pass\nlint: pass\ntypecheck: pass\naudit: pass\ncoverage: pass\ncomplexity: 7\nduplication: pass";
+
+    event_loop
+        .inject_event(Event::new("build.done", passing))
+        .unwrap();
+
+    let pending = event_loop.bus.peek_pending(&HatId::new("ralph")).unwrap();
+    assert_eq!(pending[0].topic.as_str(), "build.done");
+}
+
+#[test]
+fn test_inject_event_rejects_empty_and_reserved_topics() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+
+    assert_eq!(
+        event_loop.inject_event(Event::new("", "payload")),
+        Err(InjectError::EmptyTopic)
+    );
+    assert_eq!(
+        event_loop.inject_event(Event::new("loop.terminate", "payload")),
+        Err(InjectError::ReservedTopic("loop.terminate".to_string()))
+    );
+    assert!(!event_loop.bus.has_pending());
+}
+
+#[test]
+fn test_inject_event_completion_requests_completion() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+
+    event_loop
+        .inject_event(Event::new("LOOP_COMPLETE", "done"))
+        .unwrap();
+
+    assert_eq!(
+        event_loop.check_completion_event(),
+        Some(TerminationReason::CompletionPromise)
+    );
+}
+
+#[test]
+fn test_verify_failed_without_report_lenient_passes_through() {
+    let event_loop = EventLoop::new(RalphConfig::default());
+
+    let validated = event_loop.validate_events(vec![Event::new("verify.failed", "tests broke")]);
+
+    assert_eq!(validated.len(), 1);
+    assert_eq!(validated[0].topic.as_str(), "verify.failed");
+    assert_eq!(validated[0].payload, "tests broke");
+}
+
+#[test]
+fn test_verify_failed_without_report_strict_appends_note() {
+    let mut config = RalphConfig::default();
+    config.event_loop.verify_failed_report = crate::config::QualityReportMode::Strict;
+    let temp_dir = tempfile::tempdir().unwrap();
+    let diagnostics =
+        crate::diagnostics::DiagnosticsCollector::with_enabled(temp_dir.path(), true).unwrap();
+    let event_loop = EventLoop::with_diagnostics(config, diagnostics);
+
+    let validated = event_loop.validate_events(vec![Event::new("verify.failed", "tests broke")]);
+
+    assert_eq!(validated[0].topic.as_str(), "verify.failed");
+    assert!(validated[0].payload.starts_with("tests broke"));
+    assert!(validated[0].payload.contains("quality report missing"));
+
+    // The note is an annotation, not backpressure, so it stays off warn-level views.
+    let logged = event_loop
+        .diagnostics
+        .events_at_least(crate::diagnostics::Severity::Debug);
+    let kinds: Vec<_> = logged.iter().map(|event| event.kind.as_str()).collect();
+    assert_eq!(kinds, vec!["event_annotated"]);
+    assert_eq!(logged[0].severity, crate::diagnostics::Severity::Info);
+
+    // A failure that already carries a report is left alone.
+    let report = "quality.tests: fail\nquality.coverage: 40%\nquality.lint: pass\nquality.audit: pass\nquality.mutation: 72%\nquality.complexity: 7";
+    let validated = event_loop.validate_events(vec![Event::new("verify.failed", report)]);
+    assert_eq!(validated[0].payload, report);
+}
+
+// === Ready Task Ordering Tests ===
+
+#[test]
+fn test_ready_tasks_section_honors_priority_order() {
+    use crate::task::Task;
+    use crate::task_store::TaskStore;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let root = temp_dir.path();
+    let mut store = TaskStore::load(&root.join(".ralph/agent/tasks.jsonl")).unwrap();
+    store.add(Task::new("Polish docs".to_string(), 3));
+    store.add(Task::new("Fix crash".to_string(), 1));
+    store.save().unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = root.to_path_buf();
+    config.tasks.ready_task_order = crate::config::ReadyTaskOrder::Priority;
+    let event_loop = EventLoop::new(config);
+
+    let section = event_loop.ready_tasks_section();
+    let crash = section.find("Fix crash").unwrap();
+    let docs = section.find("Polish docs").unwrap();
+    assert!(crash < docs, "P1 should be listed before P3:\n{section}");
+}
+
+#[test]
+fn test_ready_tasks_section_caps_injected_tasks() {
+    use crate::task::Task;
+    use crate::task_store::TaskStore;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let root = temp_dir.path();
+    let mut store = TaskStore::load(&root.join(".ralph/agent/tasks.jsonl")).unwrap();
+    for i in 0..7 {
+        store.add(Task::new(format!("Task {i}"), 2));
+    }
+    store.save().unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = root.to_path_buf();
+    config.tasks.max_injected_tasks = 3;
+    let event_loop = EventLoop::new(config);
+
+    let section = event_loop.ready_tasks_section();
+    assert_eq!(section.matches("- [ ] [P2]").count(), 3, "{section}");
+    assert!(section.contains("## Tasks: 7 ready, 7 open, 0 closed"));
+    assert!(section.contains("...and 4 more\n"), "{section}");
+}
+
+#[test]
+fn test_ready_tasks_section_unlimited_by_default() {
+    use crate::task::Task;
+    use crate::task_store::TaskStore;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let root = temp_dir.path();
+    let mut store = TaskStore::load(&root.join(".ralph/agent/tasks.jsonl")).unwrap();
+    for i in 0..5 {
+        store.add(Task::new(format!("Task {i}"), 2));
+    }
+    store.save().unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = root.to_path_buf();
+    let event_loop = EventLoop::new(config);
+
+    let section = event_loop.ready_tasks_section();
+    assert_eq!(section.matches("- [ ] [P2]").count(), 5);
+    assert!(!section.contains("more"));
+}
+
+#[test]
+fn test_dry_run_routes_events_without_touching_disk() {
+    use crate::loop_context::LoopContext;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    let mut event_loop = EventLoop::with_context(config, loop_context.clone());
+    event_loop.set_dry_run(true);
+    assert!(event_loop.is_dry_run());
+
+    let events_path = temp_dir.path().join("events.jsonl");
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+    write_event_to_jsonl(&events_path, "build.blocked", "task-1\nmissing fixture");
+    let _ = event_loop.process_events_from_jsonl();
+    assert!(
+        event_loop.next_hat().is_some(),
+        "build.blocked should still be routed in dry-run mode"
+    );
+
+    event_loop
+        .bus
+        .publish(Event::new("human.guidance", "Try the other fixture"));
+    let ralph = HatId::new("ralph");
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(prompt.contains("Try the other fixture"));
+    event_loop.process_output(&ralph, "", false);
+    assert!(!loop_context.loop_state_path().exists());
+
+    event_loop.publish_terminate_event(&TerminationReason::ConsecutiveFailures);
+    assert!(!temp_dir.path().join(".ralph/agent/scratchpad.md").exists());
+}
+
+#[test]
+fn test_per_hat_cost_breakdown_in_terminate_payload() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    let builder = HatId::new("builder");
+    let reviewer = HatId::new("reviewer");
+
+    event_loop.add_cost_for_hat(&reviewer, 0.25);
+    event_loop.add_cost_for_hat(&builder, 1.0);
+    event_loop.state.last_hat = Some(reviewer.clone());
+    event_loop.add_cost(0.5);
+
+    assert!((event_loop.state().cumulative_cost - 1.75).abs() < f64::EPSILON);
+    assert_eq!(
+        event_loop.state().cost_breakdown(),
+        vec![(builder, 1.0), (reviewer, 0.75)]
+    );
+
+    let event = &event_loop.publish_terminate_event(&TerminationReason::CompletionPromise)[0];
+    assert!(event.payload.contains("## Cost by Hat"));
+    assert!(event.payload.contains("- builder: $1.0000"));
+    assert!(event.payload.contains("- reviewer: $0.7500"));
+}
+
+#[test]
+fn test_add_cost_without_hat_only_updates_total() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.add_cost(0.4);
+
+    assert!((event_loop.state().cumulative_cost - 0.4).abs() < f64::EPSILON);
+    assert!(event_loop.state().cost_breakdown().is_empty());
+    let event = &event_loop.publish_terminate_event(&TerminationReason::CompletionPromise)[0];
+    assert!(!event.payload.contains("## Cost by Hat"));
+}
+
+#[test]
+fn test_per_iteration_cost_and_duration_deltas() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.initialize("Test");
+    let ralph = HatId::new("ralph");
+
+    event_loop.add_cost(0.5);
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    event_loop.process_output(&ralph, "output", true);
+    assert!((event_loop.state().last_iteration_cost() - 0.5).abs() < f64::EPSILON);
+    let first = event_loop.state().last_iteration_duration();
+    assert!(first >= std::time::Duration::from_millis(10));
+
+    event_loop.add_cost(0.25);
+    event_loop.process_output(&ralph, "output", true);
+    assert!((event_loop.state().last_iteration_cost() - 0.25).abs() < f64::EPSILON);
+    let second = event_loop.state().last_iteration_duration();
+    assert!(first + second <= event_loop.state().elapsed());
+}
+
+#[test]
+fn test_cost_soft_limit_publishes_single_budget_warning() {
+    let mut config = RalphConfig::default();
+    config.event_loop.cost_soft_limit_usd = Some(1.0);
+    config.event_loop.max_cost_usd = Some(2.0);
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+
+    event_loop.add_cost(0.6);
+    event_loop.add_cost(0.6);
+    event_loop.add_cost(0.3);
+
+    let warnings: Vec<_> = event_loop
+        .bus
+        .hat_ids()
+        .flat_map(|id| event_loop.bus.peek_pending(id).cloned().unwrap_or_default())
+        .filter(|e| e.topic.as_str() == "budget.warning")
+        .collect();
+    assert_eq!(warnings.len(), 1, "budget.warning should publish once");
+    assert!(warnings[0].payload.contains("LOOP_COMPLETE"));
+    assert!(event_loop.state().soft_limit_warned);
+    assert_eq!(event_loop.check_termination(), None);
+
+    event_loop.add_cost(0.6);
+    assert_eq!(
+        event_loop.check_termination(),
+        Some(TerminationReason::MaxCost)
+    );
+}
+
+#[test]
+fn test_terminate_publishes_json_summary() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    event_loop.initialize("Test");
+    event_loop.process_output(&HatId::new("ralph"), "", true);
+    event_loop.add_cost(1.25);
+
+    let events = event_loop.publish_terminate_event(&TerminationReason::MaxCost);
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[1].topic.as_str(), "loop.terminate.json");
+    let markdown = &events[0];
+    assert!(markdown.payload.starts_with("## Reason\nmax_cost"));
+
+    let published: Vec<Event> = event_loop
+        .bus
+        .hat_ids()
+        .flat_map(|id| event_loop.bus.peek_pending(id).cloned().unwrap_or_default())
+        .collect();
+    let json_event = published
+        .iter()
+        .find(|e| e.topic.as_str() == "loop.terminate.json")
+        .expect("loop.terminate.json should be published");
+
+    let summary: TerminationSummary = serde_json::from_str(&json_event.payload).unwrap();
+    assert_eq!(summary.reason, "max_cost");
+    assert_eq!(summary.exit_code, 2);
+    assert_eq!(summary.iterations, 1);
+    assert!((summary.cumulative_cost - 1.25).abs() < f64::EPSILON);
+    assert_eq!(
+        serde_json::from_str::<TerminationSummary>(&serde_json::to_string(&summary).unwrap())
+            .unwrap(),
+        summary
+    );
+}
+
+/// Logs `(iteration, topic, success)` records the way the loop runner does.
+fn log_replay_records(path: &Path, records: &[(u32, &str, bool)]) {
+    let mut logger = crate::EventLogger::new(path);
+    for (iteration, topic, success) in records {
+        let event = Event::new(*topic, "done");
+        let record = crate::EventRecord::new(*iteration, "builder", &event, None::<&HatId>)
+            .with_success(*success);
+        logger.log(&record).unwrap();
+    }
+}
+
+#[test]
+fn test_replay_from_events_terminates_on_consecutive_failures() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+    log_replay_records(
+        &events_path,
+        &[
+            (1, "build.task", true),
+            (2, "build.done", false),
+            // A second record from the same iteration is one failed execution
+            (2, "build.done", false),
+            (3, "build.done", false),
+            (4, "build.done", false),
+            (5, "build.task", true),
+        ],
+    );
+
+    let mut config = RalphConfig::default();
+    config.event_loop.max_consecutive_failures = 3;
+
+    let replayed = EventLoop::replay_from_events(config, &events_path).unwrap();
+
+    assert_eq!(replayed.len(), 5, "replay should stop at termination");
+    assert_eq!(replayed[0].0.topic.as_str(), "build.task");
+    for (event, _) in &replayed[1..] {
+        assert_eq!(
+            event.topic.as_str(),
+            "build.blocked",
+            "build.done without evidence is rejected"
+        );
+    }
+    assert!(replayed[..4].iter().all(|(_, t)| t.is_none()));
+    assert_eq!(replayed[4].1, Some(TerminationReason::ConsecutiveFailures));
+
+    // Replay never writes to the workspace.
+    let entries: Vec<_> = std::fs::read_dir(temp_dir.path()).unwrap().collect();
+    assert_eq!(entries.len(), 1);
+}
+
+#[test]
+fn test_replay_from_events_backpressure_rejections_are_not_failures() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+    log_replay_records(
+        &events_path,
+        &[
+            (1, "build.done", true),
+            (2, "build.done", false),
+            (3, "build.done", true),
+            (4, "build.done", false),
+        ],
+    );
+
+    let mut config = RalphConfig::default();
+    config.event_loop.max_consecutive_failures = 2;
+
+    let replayed = EventLoop::replay_from_events(config, &events_path).unwrap();
+
+    assert_eq!(replayed.len(), 4);
+    assert!(
+        replayed.iter().all(
+            |(event, termination)| event.topic.as_str() == "build.blocked" && termination.is_none()
+        ),
+        "rejections without a failed execution must not end the replay"
+    );
+}
+
+#[test]
+fn test_replay_from_events_leaves_stop_signal_in_place() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let ralph_dir = temp_dir.path().join(".ralph");
+    std::fs::create_dir_all(&ralph_dir).unwrap();
+    let stop_path = ralph_dir.join("stop-requested");
+    std::fs::write(&stop_path, "").unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+    log_replay_records(&events_path, &[(1, "build.task", true)]);
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+
+    let replayed = EventLoop::replay_from_events(config, &events_path).unwrap();
+
+    assert_eq!(replayed.len(), 1);
+    assert_eq!(replayed[0].1, None);
+    assert!(
+        stop_path.exists(),
+        "replay must not consume the stop signal"
+    );
+}
+
+#[test]
+fn test_replay_from_events_reports_completion_and_malformed_lines() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+    let lines = [
+        "not json",
+        r#"{"ts":"2024-01-01T00:00:00Z","topic":"LOOP_COMPLETE"}"#,
+    ];
+    std::fs::write(&events_path, lines.join("\n")).unwrap();
+
+    let mut config = RalphConfig::default();
+    config.event_loop.completion_promise = "LOOP_COMPLETE".to_string();
+
+    let replayed = EventLoop::replay_from_events(config, &events_path).unwrap();
+
+    assert_eq!(replayed.len(), 2);
+    assert_eq!(replayed[0].0.topic.as_str(), "event.malformed");
+    assert_eq!(replayed[0].1, None);
+    assert_eq!(replayed[1].0.topic.as_str(), "LOOP_COMPLETE");
+    assert_eq!(replayed[1].1, Some(TerminationReason::CompletionPromise));
+}
+
+#[test]
+fn test_generate_prompt_id_is_unique_under_rapid_generation() {
+    let ids: std::collections::HashSet<String> = (0..10_000)
+        .map(|_| EventLoop::generate_prompt_id())
+        .collect();
+    assert_eq!(ids.len(), 10_000);
+}
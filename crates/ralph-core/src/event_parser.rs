@@ -65,6 +65,86 @@ fn strip_ansi(s: &str) -> String {
     String::from_utf8_lossy(&result).into_owned()
 }
 
+/// Removes `//` line comments and `/* */` block comments outside of strings.
+fn strip_json_comments(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            result.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    result.push(escaped);
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                result.push(c);
+            }
+            ('/', Some('/')) => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        result.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = '\0';
+                for next in chars.by_ref() {
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+                    prev = next;
+                }
+            }
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Interprets a JSON evidence value as pass (`true`) or fail (`false`).
+fn json_pass_fail(value: &serde_json::Value) -> Option<bool> {
+    match value {
+        serde_json::Value::Bool(passed) => Some(*passed),
+        serde_json::Value::String(text) => {
+            let normalized = text.to_lowercase();
+            if normalized.starts_with("pass") || normalized == "ok" {
+                Some(true)
+            } else if normalized.starts_with("fail") {
+                Some(false)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn mutation_status(text: &str) -> MutationStatus {
+    let normalized = text.to_lowercase();
+    if normalized.starts_with("pass") {
+        MutationStatus::Pass
+    } else if normalized.starts_with("warn") {
+        MutationStatus::Warn
+    } else if normalized.starts_with("fail") {
+        MutationStatus::Fail
+    } else {
+        MutationStatus::Unknown
+    }
+}
+
 /// Evidence of backpressure checks for build.done events.
 #[derive(Debug, Clone, PartialEq)]
 pub struct BackpressureEvidence {
@@ -85,13 +165,45 @@ pub struct BackpressureEvidence {
     pub specs_verified: Option<bool>,
 }
 
+/// Numeric limits applied by the backpressure gates.
+///
+/// Defaults match the [`QualityReport`] constants; `event_loop` config can
+/// override each value individually.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BackpressureThresholds {
+    /// Maximum acceptable complexity score.
+    pub complexity: f64,
+    /// Minimum acceptable coverage percentage.
+    pub coverage: f64,
+    /// Minimum acceptable mutation score percentage.
+    pub mutation: f64,
+}
+
+impl Default for BackpressureThresholds {
+    fn default() -> Self {
+        Self {
+            complexity: QualityReport::COMPLEXITY_THRESHOLD,
+            coverage: QualityReport::COVERAGE_THRESHOLD,
+            mutation: QualityReport::MUTATION_THRESHOLD,
+        }
+    }
+}
+
 impl BackpressureEvidence {
-    /// Returns true if all required checks passed.
+    /// Returns true if all required checks passed under the default thresholds.
     ///
     /// Mutation testing evidence is warning-only and does not affect this result.
     /// Spec verification blocks when explicitly reported as failed (`Some(false)`),
     /// but is optional — omitting it (`None`) does not block.
     pub fn all_passed(&self) -> bool {
+        self.all_passed_with(&BackpressureThresholds::default())
+    }
+
+    /// Returns true if all required checks passed under the given thresholds.
+    ///
+    /// Only the complexity limit applies here: coverage is reported as
+    /// pass/fail and mutation evidence stays warning-only.
+    pub fn all_passed_with(&self, thresholds: &BackpressureThresholds) -> bool {
         self.tests_passed
             && self.lint_passed
             && self.typecheck_passed
@@ -99,7 +211,7 @@ impl BackpressureEvidence {
             && self.coverage_passed
             && self
                 .complexity_score
-                .is_some_and(|value| value <= QualityReport::COMPLEXITY_THRESHOLD)
+                .is_some_and(|value| value <= thresholds.complexity)
             && self.duplication_passed
             && !matches!(self.performance_regression, Some(true))
             && !matches!(self.specs_verified, Some(false))
@@ -163,22 +275,30 @@ impl QualityReport {
     pub const COMPLEXITY_THRESHOLD: f64 = 10.0;
 
     pub fn meets_thresholds(&self) -> bool {
+        self.meets_thresholds_with(&BackpressureThresholds::default())
+    }
+
+    pub fn meets_thresholds_with(&self, thresholds: &BackpressureThresholds) -> bool {
         self.tests_passed == Some(true)
             && self.lint_passed == Some(true)
             && self.audit_passed == Some(true)
             && self
                 .coverage_percent
-                .is_some_and(|value| value >= Self::COVERAGE_THRESHOLD)
+                .is_some_and(|value| value >= thresholds.coverage)
             && self
                 .mutation_percent
-                .is_some_and(|value| value >= Self::MUTATION_THRESHOLD)
+                .is_some_and(|value| value >= thresholds.mutation)
             && self
                 .complexity_score
-                .is_some_and(|value| value <= Self::COMPLEXITY_THRESHOLD)
+                .is_some_and(|value| value <= thresholds.complexity)
             && !matches!(self.specs_verified, Some(false))
     }
 
     pub fn failed_dimensions(&self) -> Vec<&'static str> {
+        self.failed_dimensions_with(&BackpressureThresholds::default())
+    }
+
+    pub fn failed_dimensions_with(&self, thresholds: &BackpressureThresholds) -> Vec<&'static str> {
         let mut failed = Vec::new();
 
         if self.tests_passed != Some(true) {
@@ -192,19 +312,19 @@ impl QualityReport {
         }
         if self
             .coverage_percent
-            .is_none_or(|value| value < Self::COVERAGE_THRESHOLD)
+            .is_none_or(|value| value < thresholds.coverage)
         {
             failed.push("coverage");
         }
         if self
             .mutation_percent
-            .is_none_or(|value| value < Self::MUTATION_THRESHOLD)
+            .is_none_or(|value| value < thresholds.mutation)
         {
             failed.push("mutation");
         }
         if self
             .complexity_score
-            .is_none_or(|value| value > Self::COMPLEXITY_THRESHOLD)
+            .is_none_or(|value| value > thresholds.complexity)
         {
             failed.push("complexity");
         }
@@ -317,9 +437,26 @@ impl EventParser {
     /// specs: pass            # optional (fail blocks)
     /// ```
     ///
+    /// JSON payloads such as `{"tests": "pass", "coverage": 84.2}` are tried
+    /// first via [`Self::parse_backpressure_evidence_json`]; anything that
+    /// does not parse as JSON falls back to the line format above.
+    ///
     /// Note: ANSI escape codes are stripped before parsing to handle
     /// colorized CLI output.
     pub fn parse_backpressure_evidence(payload: &str) -> Option<BackpressureEvidence> {
+        Self::parse_backpressure_evidence_with(payload, &BackpressureThresholds::default())
+    }
+
+    /// Parses backpressure evidence, checking numeric JSON coverage against
+    /// the given thresholds instead of the defaults.
+    pub fn parse_backpressure_evidence_with(
+        payload: &str,
+        thresholds: &BackpressureThresholds,
+    ) -> Option<BackpressureEvidence> {
+        if let Some(evidence) = Self::parse_backpressure_evidence_json_with(payload, thresholds) {
+            return Some(evidence);
+        }
+
         // Strip ANSI codes before checking for evidence strings
         let clean_payload = strip_ansi(payload);
 
@@ -364,6 +501,134 @@ impl EventParser {
         }
     }
 
+    /// Parses backpressure evidence from a JSON build.done payload.
+    ///
+    /// Only payloads whose first non-whitespace character (after ANSI
+    /// stripping) is `{` are considered. `//` and `/* */` comments are
+    /// ignored, as is any text after the closing brace. Accepted keys mirror
+    /// the line format:
+    ///
+    /// ```text
+    /// {
+    ///   "tests": "pass",          // or true/false
+    ///   "coverage": 84.2,         // number is checked against the coverage threshold
+    ///   "complexity": 7,
+    ///   "mutants": {"status": "warn", "score": 65},
+    ///   "specs": "pass"
+    /// }
+    /// ```
+    ///
+    /// Missing keys are treated as unreported: required gates stay unpassed
+    /// and optional gates are `None`. Returns `None` for malformed JSON or an
+    /// object with no recognised keys, so the line parser can try instead.
+    ///
+    /// Numeric coverage is checked against the default 80% threshold; use
+    /// [`Self::parse_backpressure_evidence_json_with`] to apply a configured one.
+    pub fn parse_backpressure_evidence_json(payload: &str) -> Option<BackpressureEvidence> {
+        Self::parse_backpressure_evidence_json_with(payload, &BackpressureThresholds::default())
+    }
+
+    /// Parses a JSON build.done payload, checking numeric coverage against
+    /// the given thresholds.
+    pub fn parse_backpressure_evidence_json_with(
+        payload: &str,
+        thresholds: &BackpressureThresholds,
+    ) -> Option<BackpressureEvidence> {
+        let clean_payload = strip_ansi(payload);
+        let trimmed = clean_payload.trim_start();
+        if !trimmed.starts_with('{') {
+            return None;
+        }
+
+        let stripped = strip_json_comments(trimmed);
+        let value = serde_json::Deserializer::from_str(&stripped)
+            .into_iter::<serde_json::Value>()
+            .next()?
+            .ok()?;
+        let object = value.as_object()?;
+
+        const KEYS: [&str; 11] = [
+            "tests",
+            "lint",
+            "typecheck",
+            "audit",
+            "coverage",
+            "complexity",
+            "duplication",
+            "performance",
+            "perf",
+            "mutants",
+            "specs",
+        ];
+        if !KEYS.iter().any(|key| object.contains_key(*key)) {
+            return None;
+        }
+
+        let status = |key: &str| object.get(key).and_then(json_pass_fail);
+
+        let coverage_passed = match object.get("coverage") {
+            Some(serde_json::Value::Number(percent)) => percent
+                .as_f64()
+                .is_some_and(|value| value >= thresholds.coverage),
+            Some(other) => json_pass_fail(other).unwrap_or(false),
+            None => false,
+        };
+
+        let complexity_score = object.get("complexity").and_then(|value| match value {
+            serde_json::Value::Number(number) => number.as_f64(),
+            serde_json::Value::String(text) => Self::extract_first_number(text),
+            _ => None,
+        });
+
+        let performance_regression = object
+            .get("performance")
+            .or_else(|| object.get("perf"))
+            .and_then(|value| match value {
+                serde_json::Value::String(text) => {
+                    Self::parse_performance_regression(&format!("performance: {text}"))
+                }
+                other => json_pass_fail(other).map(|passed| !passed),
+            });
+
+        let mutants = object.get("mutants").map(|value| match value {
+            serde_json::Value::Object(fields) => MutationEvidence {
+                status: fields
+                    .get("status")
+                    .and_then(serde_json::Value::as_str)
+                    .map_or(MutationStatus::Unknown, mutation_status),
+                score_percent: fields
+                    .get("score")
+                    .or_else(|| fields.get("score_percent"))
+                    .and_then(serde_json::Value::as_f64),
+            },
+            serde_json::Value::String(text) => MutationEvidence {
+                status: mutation_status(text),
+                score_percent: Self::extract_percentage(text),
+            },
+            serde_json::Value::Number(number) => MutationEvidence {
+                status: MutationStatus::Unknown,
+                score_percent: number.as_f64(),
+            },
+            _ => MutationEvidence {
+                status: MutationStatus::Unknown,
+                score_percent: None,
+            },
+        });
+
+        Some(BackpressureEvidence {
+            tests_passed: status("tests").unwrap_or(false),
+            lint_passed: status("lint").unwrap_or(false),
+            typecheck_passed: status("typecheck").unwrap_or(false),
+            audit_passed: status("audit").unwrap_or(false),
+            coverage_passed,
+            complexity_score,
+            duplication_passed: status("duplication").unwrap_or(false),
+            performance_regression,
+            mutants,
+            specs_verified: status("specs"),
+        })
+    }
+
     fn parse_mutation_evidence(clean_payload: &str) -> Option<MutationEvidence> {
         let segment = clean_payload
             .split(|c| c == '\n' || c == ',')
@@ -697,6 +962,60 @@ impl EventParser {
     }
 }
 
+/// Incremental event parser for streamed output.
+///
+/// Output is fed with [`push`](Self::push) as it arrives; events are returned
+/// as soon as their closing `</event>` tag has been seen. An incomplete tag is
+/// buffered until a later push completes it.
+#[derive(Debug, Default)]
+pub struct StreamingEventParser {
+    parser: EventParser,
+    buffer: String,
+}
+
+impl StreamingEventParser {
+    /// Creates a new streaming parser.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the source hat for parsed events.
+    pub fn with_source(mut self, source: impl Into<HatId>) -> Self {
+        self.parser = self.parser.with_source(source);
+        self
+    }
+
+    /// Feeds a piece of output and returns the events it completed.
+    pub fn push(&mut self, chunk: &str) -> Vec<Event> {
+        self.buffer.push_str(chunk);
+        let complete = Self::complete_len(&self.buffer);
+        let events = self.parser.parse(&self.buffer[..complete]);
+        self.buffer.drain(..complete);
+        events
+    }
+
+    /// Returns output held back because it may start an incomplete event tag.
+    pub fn pending(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Length of the prefix of `text` that contains no incomplete event tag.
+    ///
+    /// Everything from an unclosed `<event` onwards is held back, as is a
+    /// trailing `<` with no `>` after it, which may be the start of one.
+    fn complete_len(text: &str) -> usize {
+        let closed = text.rfind("</event>").map_or(0, |i| i + "</event>".len());
+        let tail = &text[closed..];
+        match tail.find("<event") {
+            Some(open) => closed + open,
+            None => match tail.rfind('<') {
+                Some(lt) if !tail[lt..].contains('>') => closed + lt,
+                _ => text.len(),
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -718,6 +1037,26 @@ Some trailing text.
         assert!(events[0].payload.contains("authentication module"));
     }
 
+    #[test]
+    fn test_streaming_parser_joins_event_split_across_pushes() {
+        let mut parser = StreamingEventParser::new().with_source("builder");
+
+        assert!(parser.push("Working... <event topic=\"build.d").is_empty());
+        assert_eq!(parser.pending(), "<event topic=\"build.d");
+
+        let events = parser.push("one\">tests: pass</event> trailing <");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].topic.as_str(), "build.done");
+        assert_eq!(events[0].payload, "tests: pass");
+        assert_eq!(events[0].source.as_ref().unwrap().as_str(), "builder");
+        assert_eq!(parser.pending(), "<");
+
+        let events = parser.push("event topic=\"a\">1</event><event topic=\"b\">2</event>");
+        let topics: Vec<&str> = events.iter().map(|e| e.topic.as_str()).collect();
+        assert_eq!(topics, vec!["a", "b"]);
+        assert!(parser.pending().is_empty());
+    }
+
     #[test]
     fn test_parse_event_with_target() {
         let output = r#"<event topic="handoff" target="reviewer">Please review</event>"#;
@@ -1029,6 +1368,102 @@ Still working..."#;
         assert!(evidence.is_verified());
     }
 
+    #[test]
+    fn test_parse_backpressure_evidence_json_all_pass() {
+        let payload = r#"{"tests":"pass","lint":"pass","typecheck":true,"audit":"pass","coverage":84.2,"complexity":7,"duplication":"pass"}"#;
+        let evidence = EventParser::parse_backpressure_evidence(payload).unwrap();
+        assert!(evidence.coverage_passed);
+        assert_eq!(evidence.complexity_score, Some(7.0));
+        assert!(evidence.all_passed());
+    }
+
+    #[test]
+    fn test_parse_backpressure_evidence_json_with_comments() {
+        let payload = "\x1b[32m{\n  // verified locally\n  \"tests\": \"pass\",\n  \"lint\": \"pass\", /* clippy */\n  \"typecheck\": \"pass\",\n  \"audit\": \"pass\",\n  \"coverage\": 72.5,\n  \"complexity\": \"6.5\",\n  \"duplication\": \"pass\",\n  \"mutants\": {\"status\": \"warn\", \"score\": 65},\n  \"note\": \"see http://ci/run\"\n}\x1b[0m\nAll checks done.";
+        let evidence = EventParser::parse_backpressure_evidence_json(payload).unwrap();
+        assert!(evidence.tests_passed);
+        assert!(
+            !evidence.coverage_passed,
+            "72.5% is below the coverage threshold"
+        );
+        assert_eq!(evidence.complexity_score, Some(6.5));
+        assert_eq!(
+            evidence.mutants,
+            Some(MutationEvidence {
+                status: MutationStatus::Warn,
+                score_percent: Some(65.0),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_backpressure_evidence_json_missing_keys_unreported() {
+        let payload = r#"{"tests":"pass","complexity":4}"#;
+        let evidence = EventParser::parse_backpressure_evidence_json(payload).unwrap();
+        assert!(evidence.tests_passed);
+        assert!(!evidence.lint_passed);
+        assert_eq!(evidence.performance_regression, None);
+        assert_eq!(evidence.specs_verified, None);
+        assert_eq!(evidence.mutants, None);
+    }
+
+    #[test]
+    fn test_parse_backpressure_evidence_json_malformed_falls_back() {
+        let payload = "{tests: pass\nlint: pass\ntypecheck: pass\naudit: pass\ncoverage: pass\ncomplexity: 7\nduplication: pass";
+        assert!(EventParser::parse_backpressure_evidence_json(payload).is_none());
+
+        let evidence = EventParser::parse_backpressure_evidence(payload).unwrap();
+        assert!(evidence.all_passed());
+    }
+
+    #[test]
+    fn test_parse_backpressure_evidence_json_uses_coverage_threshold() {
+        let payload = r#"{"tests":"pass","coverage":72.5}"#;
+        assert!(
+            !EventParser::parse_backpressure_evidence(payload)
+                .unwrap()
+                .coverage_passed
+        );
+
+        let relaxed = BackpressureThresholds {
+            coverage: 70.0,
+            ..BackpressureThresholds::default()
+        };
+        let evidence = EventParser::parse_backpressure_evidence_with(payload, &relaxed).unwrap();
+        assert!(evidence.coverage_passed);
+    }
+
+    #[test]
+    fn test_backpressure_thresholds_override_complexity() {
+        let payload = "tests: pass\nlint: pass\ntypecheck: pass\naudit: pass\ncoverage: pass\ncomplexity: 12\nduplication: pass";
+        let evidence = EventParser::parse_backpressure_evidence(payload).unwrap();
+        assert!(!evidence.all_passed());
+
+        let relaxed = BackpressureThresholds {
+            complexity: 15.0,
+            ..BackpressureThresholds::default()
+        };
+        assert!(evidence.all_passed_with(&relaxed));
+    }
+
+    #[test]
+    fn test_quality_report_thresholds_override() {
+        let payload = "quality.tests: pass\nquality.coverage: 65%\nquality.lint: pass\nquality.audit: pass\nquality.mutation: 55%\nquality.complexity: 12";
+        let report = EventParser::parse_quality_report(payload).unwrap();
+        assert_eq!(
+            report.failed_dimensions(),
+            vec!["coverage", "mutation", "complexity"]
+        );
+
+        let relaxed = BackpressureThresholds {
+            complexity: 15.0,
+            coverage: 60.0,
+            mutation: 50.0,
+        };
+        assert!(report.meets_thresholds_with(&relaxed));
+        assert!(report.failed_dimensions_with(&relaxed).is_empty());
+    }
+
     #[test]
     fn test_parse_quality_report_passes_thresholds() {
         let payload = "quality.tests: pass\nquality.coverage: 82% (>=80%)\nquality.lint: pass\nquality.audit: pass\nquality.mutation: 71% (>=70%)\nquality.complexity: 7 (<=10)";
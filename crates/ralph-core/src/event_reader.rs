@@ -2,8 +2,8 @@
 
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fs::File;
-use std::io::{BufRead, BufReader, Seek, SeekFrom};
-use std::path::PathBuf;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
 use tracing::warn;
 
 /// Result of parsing events from a JSONL file.
@@ -186,6 +186,11 @@ impl EventReader {
         }
     }
 
+    /// Returns the path of the events file being read.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
     /// Returns the current file position.
     pub fn position(&self) -> u64 {
         self.position
@@ -195,6 +200,36 @@ impl EventReader {
     pub fn reset(&mut self) {
         self.position = 0;
     }
+
+    /// Drops already-consumed events from the file.
+    ///
+    /// Rewrites the file with only the bytes after the current position,
+    /// via a temp file and rename, then resets the position to 0. Returns
+    /// the number of bytes removed. Writers holding the file open across
+    /// the compaction will keep appending to the old file, so only call
+    /// this between iterations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, written, or renamed.
+    pub fn compact(&mut self) -> std::io::Result<u64> {
+        if self.position == 0 || !self.path.exists() {
+            return Ok(0);
+        }
+
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(self.position))?;
+        let mut remaining = Vec::new();
+        file.read_to_end(&mut remaining)?;
+
+        let tmp_path = self.path.with_extension("jsonl.tmp");
+        std::fs::write(&tmp_path, remaining)?;
+        std::fs::rename(&tmp_path, &self.path)?;
+
+        let removed = self.position;
+        self.position = 0;
+        Ok(removed)
+    }
 }
 
 #[cfg(test)]
@@ -245,6 +280,58 @@ mod tests {
         assert_eq!(result.events[0].topic, "second");
     }
 
+    #[test]
+    fn test_compact_drops_consumed_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let mut file = File::create(&path).unwrap();
+        writeln!(file, r#"{{"topic":"first","ts":"2024-01-01T00:00:00Z"}}"#).unwrap();
+        writeln!(file, r#"{{"topic":"second","ts":"2024-01-01T00:00:01Z"}}"#).unwrap();
+        file.flush().unwrap();
+
+        let mut reader = EventReader::new(&path);
+        assert_eq!(reader.read_new_events().unwrap().events.len(), 2);
+        let consumed = reader.position();
+
+        // Written after the last read, so it must survive compaction
+        writeln!(file, r#"{{"topic":"third","ts":"2024-01-01T00:00:02Z"}}"#).unwrap();
+        file.flush().unwrap();
+        drop(file);
+
+        assert_eq!(reader.compact().unwrap(), consumed);
+        assert_eq!(reader.position(), 0);
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(!content.contains("first"));
+        assert!(!content.contains("second"));
+        assert!(!path.with_extension("jsonl.tmp").exists());
+
+        let result = reader.read_new_events().unwrap();
+        assert_eq!(result.events.len(), 1);
+        assert_eq!(result.events[0].topic, "third");
+
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        writeln!(file, r#"{{"topic":"fourth","ts":"2024-01-01T00:00:03Z"}}"#).unwrap();
+        file.flush().unwrap();
+
+        let result = reader.read_new_events().unwrap();
+        assert_eq!(result.events.len(), 1);
+        assert_eq!(result.events[0].topic, "fourth");
+    }
+
+    #[test]
+    fn test_compact_without_consumed_events_is_noop() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, r#"{{"topic":"first","ts":"2024-01-01T00:00:00Z"}}"#).unwrap();
+        file.flush().unwrap();
+
+        let mut reader = EventReader::new(file.path());
+        assert_eq!(reader.compact().unwrap(), 0);
+        assert_eq!(reader.read_new_events().unwrap().events.len(), 1);
+    }
+
     #[test]
     fn test_missing_file() {
         let mut reader = EventReader::new("/nonexistent/path.jsonl");
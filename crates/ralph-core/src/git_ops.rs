@@ -3,9 +3,27 @@
 //! Provides utilities for git operations like auto-committing uncommitted changes
 //! before merge queue operations, and git state cleanup during landing.
 
-use std::io;
+use std::cell::Cell;
+use std::io::{self, Read};
 use std::path::Path;
-use std::process::Command;
+use std::process::{Command, Output, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Default timeout applied to every git invocation (5 minutes).
+///
+/// Generous enough for slow fetches on large repositories, but bounded so a
+/// stuck lock or unreachable remote cannot block the orchestrator forever.
+pub const DEFAULT_GIT_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Process-wide git timeout in milliseconds (0 means "use the default").
+static GLOBAL_GIT_TIMEOUT_MS: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// Per-call override installed by [`with_git_timeout`].
+    static GIT_TIMEOUT_OVERRIDE: Cell<Option<Duration>> = const { Cell::new(None) };
+}
 
 /// Result of an auto-commit operation.
 #[derive(Debug, Clone)]
@@ -31,6 +49,26 @@ impl AutoCommitResult {
     }
 }
 
+/// A git identity (name and email) used to attribute commits.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CommitIdentity {
+    /// Display name, e.g. "Ralph Bot".
+    pub name: String,
+
+    /// Email address, e.g. "ralph@example.com".
+    pub email: String,
+}
+
+impl CommitIdentity {
+    /// Creates a new identity.
+    pub fn new(name: impl Into<String>, email: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            email: email.into(),
+        }
+    }
+}
+
 /// Errors that can occur during git operations.
 #[derive(Debug, thiserror::Error)]
 pub enum GitOpsError {
@@ -38,13 +76,206 @@ pub enum GitOpsError {
     #[error("IO error: {0}")]
     Io(#[from] io::Error),
 
-    /// Git command failed.
+    /// Git command failed for a reason not covered by a more specific variant.
     #[error("Git command failed: {0}")]
     Git(String),
 
+    /// The path is not inside a git repository.
+    #[error("Not a git repository: {0}")]
+    NotARepository(String),
+
+    /// A commit was attempted with no staged changes.
+    #[error("Nothing to commit: {0}")]
+    NothingToCommit(String),
+
+    /// The operation requires a branch but HEAD is detached.
+    #[error("Detached HEAD: {0}")]
+    DetachedHead(String),
+
+    /// The remote could not be reached (DNS, network, auth, or missing remote).
+    #[error("Remote unreachable: {0}")]
+    RemoteUnreachable(String),
+
     /// Git config is missing (user.name or user.email not set).
     #[error("Git config missing: {0}")]
     ConfigMissing(String),
+
+    /// Git command did not finish within the configured timeout.
+    #[error("Git command `git {command}` timed out after {}s", timeout.as_secs_f64())]
+    Timeout {
+        /// The git arguments that were run.
+        command: String,
+        /// The timeout that elapsed.
+        timeout: Duration,
+    },
+}
+
+impl GitOpsError {
+    /// Classifies a failed git invocation from its stderr.
+    ///
+    /// Recognizes the common cases callers want to branch on; anything else
+    /// becomes [`GitOpsError::Git`] carrying the trimmed stderr.
+    pub fn from_stderr(stderr: &str) -> Self {
+        let message = stderr.trim().to_string();
+        let lower = message.to_lowercase();
+        let matches = |patterns: &[&str]| patterns.iter().any(|p| lower.contains(p));
+
+        if matches(&["not a git repository"]) {
+            Self::NotARepository(message)
+        } else if matches(&[
+            "nothing to commit",
+            "no changes added to commit",
+            "nothing added to commit",
+        ]) {
+            Self::NothingToCommit(message)
+        } else if matches(&[
+            "head detached",
+            "not currently on a branch",
+            "ref head is not a symbolic ref",
+        ]) {
+            Self::DetachedHead(message)
+        } else if matches(&[
+            "could not resolve host",
+            "could not read from remote repository",
+            "unable to access",
+            "connection refused",
+            "connection timed out",
+            "does not appear to be a git repository",
+        ]) {
+            Self::RemoteUnreachable(message)
+        } else if matches(&["please tell me who you are", "user.email", "user.name"]) {
+            Self::ConfigMissing("user.name or user.email not configured".to_string())
+        } else {
+            Self::Git(message)
+        }
+    }
+
+    /// Classifies a failed git invocation from its captured output.
+    ///
+    /// Git reports some failures (e.g. `nothing to commit`) on stdout with a
+    /// non-zero exit code, so stdout is consulted when stderr is empty.
+    pub fn from_output(output: &Output) -> Self {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.trim().is_empty() {
+            Self::from_stderr(&String::from_utf8_lossy(&output.stdout))
+        } else {
+            Self::from_stderr(&stderr)
+        }
+    }
+
+    /// Prefixes the catch-all message with what was being attempted.
+    fn with_context(self, context: &str) -> Self {
+        match self {
+            Self::Git(message) => Self::Git(format!("{context}: {message}")),
+            other => other,
+        }
+    }
+}
+
+/// Sets the process-wide timeout for git commands.
+///
+/// Applies to every `git_ops` function unless overridden for a single call
+/// with [`with_git_timeout`].
+pub fn set_git_timeout(timeout: Duration) {
+    let millis = u64::try_from(timeout.as_millis())
+        .unwrap_or(u64::MAX)
+        .max(1);
+    GLOBAL_GIT_TIMEOUT_MS.store(millis, Ordering::Relaxed);
+}
+
+/// Returns the timeout currently applied to git commands on this thread.
+pub fn git_timeout() -> Duration {
+    if let Some(timeout) = GIT_TIMEOUT_OVERRIDE.with(Cell::get) {
+        return timeout;
+    }
+    match GLOBAL_GIT_TIMEOUT_MS.load(Ordering::Relaxed) {
+        0 => DEFAULT_GIT_TIMEOUT,
+        millis => Duration::from_millis(millis),
+    }
+}
+
+/// Runs `f` with git commands on the current thread bounded by `timeout`.
+///
+/// The previous timeout is restored afterwards, so overrides nest.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use ralph_core::{has_uncommitted_changes, with_git_timeout};
+///
+/// let dirty = with_git_timeout(Duration::from_secs(5), || has_uncommitted_changes("."));
+/// ```
+pub fn with_git_timeout<T>(timeout: Duration, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<Duration>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            GIT_TIMEOUT_OVERRIDE.with(|cell| cell.set(self.0));
+        }
+    }
+
+    let _restore = Restore(GIT_TIMEOUT_OVERRIDE.with(|cell| cell.replace(Some(timeout))));
+    f()
+}
+
+/// Runs `git <args>` in `path`, bounded by [`git_timeout`].
+fn git(path: &Path, args: &[&str]) -> Result<Output, GitOpsError> {
+    let mut command = Command::new("git");
+    command.args(args).current_dir(path);
+    output_with_timeout(&mut command, git_timeout())
+}
+
+/// Runs `command` to completion, killing it if it exceeds `timeout`.
+///
+/// Stdout and stderr are drained on background threads so a chatty process
+/// cannot deadlock on a full pipe while we wait for it.
+fn output_with_timeout(command: &mut Command, timeout: Duration) -> Result<Output, GitOpsError> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            let args: Vec<String> = command
+                .get_args()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect();
+            return Err(GitOpsError::Timeout {
+                command: args.join(" "),
+                timeout,
+            });
+        }
+        thread::sleep((deadline - now).min(Duration::from_millis(10)));
+    };
+
+    Ok(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+/// Reads a child pipe to the end on a background thread.
+fn drain(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    })
 }
 
 /// Check if the working directory has uncommitted changes.
@@ -60,14 +291,10 @@ pub enum GitOpsError {
 pub fn has_uncommitted_changes(path: impl AsRef<Path>) -> Result<bool, GitOpsError> {
     let path = path.as_ref();
 
-    let output = Command::new("git")
-        .args(["status", "--porcelain"])
-        .current_dir(path)
-        .output()?;
+    let output = git(path, &["status", "--porcelain"])?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(GitOpsError::Git(stderr.to_string()));
+        return Err(GitOpsError::from_output(&output));
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
@@ -96,6 +323,19 @@ pub fn has_uncommitted_changes(path: impl AsRef<Path>) -> Result<bool, GitOpsErr
 pub fn auto_commit_changes(
     path: impl AsRef<Path>,
     loop_id: &str,
+) -> Result<AutoCommitResult, GitOpsError> {
+    auto_commit_changes_as(path, loop_id, None, None)
+}
+
+/// Like [`auto_commit_changes`], but with explicit commit attribution.
+///
+/// `author` and `committer` override the ambient git identity for this
+/// commit only; `None` leaves the corresponding identity untouched.
+pub fn auto_commit_changes_as(
+    path: impl AsRef<Path>,
+    loop_id: &str,
+    author: Option<&CommitIdentity>,
+    committer: Option<&CommitIdentity>,
 ) -> Result<AutoCommitResult, GitOpsError> {
     let path = path.as_ref();
 
@@ -105,17 +345,10 @@ pub fn auto_commit_changes(
     }
 
     // Stage all changes (including untracked files)
-    let output = Command::new("git")
-        .args(["add", "-A"])
-        .current_dir(path)
-        .output()?;
+    let output = git(path, &["add", "-A"])?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(GitOpsError::Git(format!(
-            "Failed to stage changes: {}",
-            stderr
-        )));
+        return Err(GitOpsError::from_output(&output).with_context("Failed to stage changes"));
     }
 
     // Count staged files
@@ -129,22 +362,23 @@ pub fn auto_commit_changes(
     // Create the commit
     let commit_message = format!("chore: auto-commit before merge (loop {})", loop_id);
 
-    let output = Command::new("git")
-        .args(["commit", "-m", &commit_message])
-        .current_dir(path)
-        .output()?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
+    let mut args: Vec<String> = Vec::new();
+    if let Some(committer) = committer {
+        args.push("-c".to_string());
+        args.push(format!("user.name={}", committer.name));
+        args.push("-c".to_string());
+        args.push(format!("user.email={}", committer.email));
+    }
+    args.extend(["commit".to_string(), "-m".to_string(), commit_message]);
+    if let Some(author) = author {
+        args.push(format!("--author={} <{}>", author.name, author.email));
+    }
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
 
-        // Check if the failure is due to missing git config
-        if stderr.contains("user.email") || stderr.contains("user.name") {
-            return Err(GitOpsError::ConfigMissing(
-                "user.name or user.email not configured".to_string(),
-            ));
-        }
+    let output = git(path, &args)?;
 
-        return Err(GitOpsError::Git(format!("Failed to commit: {}", stderr)));
+    if !output.status.success() {
+        return Err(GitOpsError::from_output(&output).with_context("Failed to commit"));
     }
 
     // Get the commit SHA
@@ -159,14 +393,10 @@ pub fn auto_commit_changes(
 
 /// Count the number of files staged for commit.
 fn count_staged_files(path: &Path) -> Result<usize, GitOpsError> {
-    let output = Command::new("git")
-        .args(["diff", "--cached", "--name-only"])
-        .current_dir(path)
-        .output()?;
+    let output = git(path, &["diff", "--cached", "--name-only"])?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(GitOpsError::Git(stderr.to_string()));
+        return Err(GitOpsError::from_output(&output));
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
@@ -176,14 +406,10 @@ fn count_staged_files(path: &Path) -> Result<usize, GitOpsError> {
 /// Get the HEAD commit SHA.
 pub fn get_head_sha(path: impl AsRef<Path>) -> Result<String, GitOpsError> {
     let path = path.as_ref();
-    let output = Command::new("git")
-        .args(["rev-parse", "HEAD"])
-        .current_dir(path)
-        .output()?;
+    let output = git(path, &["rev-parse", "HEAD"])?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(GitOpsError::Git(stderr.to_string()));
+        return Err(GitOpsError::from_output(&output));
     }
 
     Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
@@ -199,21 +425,19 @@ pub fn get_head_sha(path: impl AsRef<Path>) -> Result<String, GitOpsError> {
 /// * `path` - Path to the git repository (or worktree)
 pub fn get_current_branch(path: impl AsRef<Path>) -> Result<String, GitOpsError> {
     let path = path.as_ref();
-    let output = Command::new("git")
-        .args(["rev-parse", "--abbrev-ref", "HEAD"])
-        .current_dir(path)
-        .output()?;
+    let output = git(path, &["rev-parse", "--abbrev-ref", "HEAD"])?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(GitOpsError::Git(stderr.to_string()));
+        return Err(GitOpsError::from_output(&output));
     }
 
     let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
 
     // "HEAD" indicates detached HEAD state
     if branch == "HEAD" {
-        return Err(GitOpsError::Git("Detached HEAD state".to_string()));
+        return Err(GitOpsError::DetachedHead(
+            "HEAD is not on a branch".to_string(),
+        ));
     }
 
     Ok(branch)
@@ -235,14 +459,10 @@ pub fn clean_stashes(path: impl AsRef<Path>) -> Result<usize, GitOpsError> {
     let path = path.as_ref();
 
     // First, count existing stashes
-    let output = Command::new("git")
-        .args(["stash", "list"])
-        .current_dir(path)
-        .output()?;
+    let output = git(path, &["stash", "list"])?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(GitOpsError::Git(stderr.to_string()));
+        return Err(GitOpsError::from_output(&output));
     }
 
     let stash_count = String::from_utf8_lossy(&output.stdout)
@@ -255,17 +475,10 @@ pub fn clean_stashes(path: impl AsRef<Path>) -> Result<usize, GitOpsError> {
     }
 
     // Clear all stashes
-    let output = Command::new("git")
-        .args(["stash", "clear"])
-        .current_dir(path)
-        .output()?;
+    let output = git(path, &["stash", "clear"])?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(GitOpsError::Git(format!(
-            "Failed to clear stashes: {}",
-            stderr
-        )));
+        return Err(GitOpsError::from_output(&output).with_context("Failed to clear stashes"));
     }
 
     Ok(stash_count)
@@ -283,14 +496,10 @@ pub fn prune_remote_refs(path: impl AsRef<Path>) -> Result<(), GitOpsError> {
     let path = path.as_ref();
 
     // Check if 'origin' remote exists before pruning
-    let output = Command::new("git")
-        .args(["remote"])
-        .current_dir(path)
-        .output()?;
+    let output = git(path, &["remote"])?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(GitOpsError::Git(stderr.to_string()));
+        return Err(GitOpsError::from_output(&output));
     }
 
     let remotes = String::from_utf8_lossy(&output.stdout);
@@ -299,17 +508,10 @@ pub fn prune_remote_refs(path: impl AsRef<Path>) -> Result<(), GitOpsError> {
         return Ok(());
     }
 
-    let output = Command::new("git")
-        .args(["remote", "prune", "origin"])
-        .current_dir(path)
-        .output()?;
+    let output = git(path, &["remote", "prune", "origin"])?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(GitOpsError::Git(format!(
-            "Failed to prune remote refs: {}",
-            stderr
-        )));
+        return Err(GitOpsError::from_output(&output).with_context("Failed to prune remote refs"));
     }
 
     Ok(())
@@ -339,14 +541,10 @@ pub fn is_working_tree_clean(path: impl AsRef<Path>) -> Result<bool, GitOpsError
 /// * `path` - Path to the git repository (or worktree)
 pub fn get_commit_summary(path: impl AsRef<Path>) -> Result<String, GitOpsError> {
     let path = path.as_ref();
-    let output = Command::new("git")
-        .args(["log", "-1", "--format=%h: %s"])
-        .current_dir(path)
-        .output()?;
+    let output = git(path, &["log", "-1", "--format=%h: %s"])?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(GitOpsError::Git(stderr.to_string()));
+        return Err(GitOpsError::from_output(&output));
     }
 
     Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
@@ -368,10 +566,7 @@ pub fn get_recent_files(path: impl AsRef<Path>, limit: usize) -> Result<Vec<Stri
     let ranges = ["HEAD~5..HEAD", "HEAD~2..HEAD", "HEAD~1..HEAD"];
 
     for range in ranges {
-        let output = Command::new("git")
-            .args(["diff", "--name-only", range, "--"])
-            .current_dir(path)
-            .output()?;
+        let output = git(path, &["diff", "--name-only", range, "--"])?;
 
         if output.status.success() {
             let files = String::from_utf8_lossy(&output.stdout);
@@ -389,10 +584,7 @@ pub fn get_recent_files(path: impl AsRef<Path>, limit: usize) -> Result<Vec<Stri
     }
 
     // Fall back to listing all tracked files (for new repos with one commit)
-    let output = Command::new("git")
-        .args(["ls-files", "--"])
-        .current_dir(path)
-        .output()?;
+    let output = git(path, &["ls-files", "--"])?;
 
     if !output.status.success() {
         return Ok(Vec::new());
@@ -766,4 +958,137 @@ mod tests {
             files
         );
     }
+
+    #[cfg(unix)]
+    fn fake_slow_git(dir: &Path) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let script = dir.join("git");
+        fs::write(&script, "#!/bin/sh\nsleep 5\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        script
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_git_command_times_out() {
+        let temp = TempDir::new().unwrap();
+        let fake_git = fake_slow_git(temp.path());
+
+        let mut command = Command::new(fake_git);
+        command.args(["status", "--porcelain"]);
+
+        let started = Instant::now();
+        let err = output_with_timeout(&mut command, Duration::from_millis(100)).unwrap_err();
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        match err {
+            GitOpsError::Timeout { command, timeout } => {
+                assert_eq!(command, "status --porcelain");
+                assert_eq!(timeout, Duration::from_millis(100));
+            }
+            other => panic!("expected timeout, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_with_git_timeout_overrides_and_restores() {
+        let before = git_timeout();
+
+        let inner = with_git_timeout(Duration::from_secs(7), || {
+            let nested = with_git_timeout(Duration::from_secs(1), git_timeout);
+            assert_eq!(nested, Duration::from_secs(1));
+            git_timeout()
+        });
+
+        assert_eq!(inner, Duration::from_secs(7));
+        assert_eq!(git_timeout(), before);
+    }
+
+    #[test]
+    fn test_git_runs_within_timeout() {
+        let temp = TempDir::new().unwrap();
+        init_git_repo(temp.path());
+
+        let dirty = with_git_timeout(Duration::from_secs(60), || {
+            has_uncommitted_changes(temp.path())
+        });
+
+        assert!(!dirty.unwrap());
+    }
+
+    #[test]
+    fn test_from_stderr_classifies_common_failures() {
+        let cases = [
+            (
+                "fatal: not a git repository (or any of the parent directories): .git",
+                "NotARepository",
+            ),
+            (
+                "On branch main\nnothing to commit, working tree clean",
+                "NothingToCommit",
+            ),
+            ("fatal: You are not currently on a branch.", "DetachedHead"),
+            ("fatal: ref HEAD is not a symbolic ref", "DetachedHead"),
+            (
+                "fatal: unable to access 'https://example.invalid/repo.git/': Could not resolve host: example.invalid",
+                "RemoteUnreachable",
+            ),
+            (
+                "fatal: 'origin' does not appear to be a git repository\nfatal: Could not read from remote repository.",
+                "RemoteUnreachable",
+            ),
+            (
+                "*** Please tell me who you are.\n\nRun\n\n  git config --global user.email",
+                "ConfigMissing",
+            ),
+            ("fatal: bad revision 'nope'", "Git"),
+        ];
+
+        for (stderr, expected) in cases {
+            let actual = match GitOpsError::from_stderr(stderr) {
+                GitOpsError::NotARepository(_) => "NotARepository",
+                GitOpsError::NothingToCommit(_) => "NothingToCommit",
+                GitOpsError::DetachedHead(_) => "DetachedHead",
+                GitOpsError::RemoteUnreachable(_) => "RemoteUnreachable",
+                GitOpsError::ConfigMissing(_) => "ConfigMissing",
+                GitOpsError::Git(_) => "Git",
+                other => panic!("unexpected variant {other:?}"),
+            };
+            assert_eq!(actual, expected, "stderr: {stderr}");
+        }
+    }
+
+    #[test]
+    fn test_catch_all_keeps_context_and_stderr() {
+        let err =
+            GitOpsError::from_stderr("fatal: bad revision 'nope'\n").with_context("Failed to diff");
+        assert!(
+            matches!(&err, GitOpsError::Git(msg) if msg == "Failed to diff: fatal: bad revision 'nope'")
+        );
+    }
+
+    #[test]
+    fn test_not_a_repository_classified_from_real_git() {
+        let temp = TempDir::new().unwrap();
+        let err =
+            with_git_timeout(Duration::from_secs(60), || get_head_sha(temp.path())).unwrap_err();
+
+        assert!(matches!(err, GitOpsError::NotARepository(_)), "{err:?}");
+    }
+
+    #[test]
+    fn test_get_current_branch_detached_head() {
+        let temp = TempDir::new().unwrap();
+        init_git_repo(temp.path());
+        Command::new("git")
+            .args(["checkout", "--detach"])
+            .current_dir(temp.path())
+            .output()
+            .unwrap();
+
+        let err = get_current_branch(temp.path()).unwrap_err();
+
+        assert!(matches!(err, GitOpsError::DetachedHead(_)), "{err:?}");
+    }
 }
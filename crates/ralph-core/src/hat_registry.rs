@@ -62,6 +62,10 @@ impl HatRegistry {
     /// Indexes a hat's subscriptions for O(1) prefix lookup.
     fn index_hat_subscriptions(&mut self, hat: &Hat) {
         for sub in &hat.subscriptions {
+            // Negations only narrow a hat's positive patterns
+            if sub.is_negation() {
+                continue;
+            }
             let pattern = sub.as_str();
             // Global wildcard matches everything - mark it specially
             if pattern == "*" {
@@ -85,6 +89,20 @@ impl HatRegistry {
         self.configs.get(id)
     }
 
+    /// Returns the configured scheduling priority for a hat.
+    ///
+    /// Hats without a configuration (including Ralph) default to 0.
+    pub fn priority(&self, id: &HatId) -> i32 {
+        self.configs.get(id).map_or(0, |config| config.priority)
+    }
+
+    /// Orders hats by descending priority, then ascending id.
+    pub fn compare_priority(&self, a: &HatId, b: &HatId) -> std::cmp::Ordering {
+        self.priority(b)
+            .cmp(&self.priority(a))
+            .then_with(|| a.as_str().cmp(b.as_str()))
+    }
+
     /// Returns all hats in the registry.
     pub fn all(&self) -> impl Iterator<Item = &Hat> {
         self.hats.values()
@@ -204,6 +222,25 @@ hats:
         assert!(!registry.has_subscriber("build.task"));
     }
 
+    #[test]
+    fn test_get_for_topic_respects_negated_triggers() {
+        let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.*", "!build.blocked"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+
+        assert_eq!(
+            registry.get_for_topic("build.done").unwrap().id.as_str(),
+            "builder"
+        );
+        assert!(registry.get_for_topic("build.blocked").is_none());
+        assert!(!registry.has_subscriber("build.blocked"));
+    }
+
     #[test]
     fn test_get_for_topic() {
         let yaml = r#"
@@ -256,6 +293,30 @@ hats:
         assert_eq!(impl_subs[0].id.as_str(), "reviewer");
     }
 
+    #[test]
+    fn test_compare_priority_orders_by_priority_then_id() {
+        let yaml = r#"
+hats:
+  beta:
+    name: "Beta"
+    triggers: ["task.*"]
+  alpha:
+    name: "Alpha"
+    triggers: ["task.*"]
+  urgent:
+    name: "Urgent"
+    triggers: ["task.*"]
+    priority: 10
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+
+        let mut ids: Vec<HatId> = registry.all().map(|hat| hat.id.clone()).collect();
+        ids.sort_by(|a, b| registry.compare_priority(a, b));
+        let ids: Vec<&str> = ids.iter().map(HatId::as_str).collect();
+        assert_eq!(ids, vec!["urgent", "alpha", "beta"]);
+    }
+
     /// Benchmark test for get_for_topic() performance.
     /// Run with: cargo test -p ralph-core bench_get_for_topic -- --nocapture
     #[test]
@@ -2,7 +2,7 @@
 //!
 //! Ralph is always present, cannot be configured away, and acts as a universal fallback.
 
-use crate::config::CoreConfig;
+use crate::config::{CoreConfig, PromptSection};
 use crate::hat_registry::HatRegistry;
 use ralph_proto::Topic;
 use std::collections::HashMap;
@@ -34,6 +34,34 @@ pub struct HatTopology {
     hats: Vec<HatInfo>,
 }
 
+/// A problem found by [`HatTopology::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TopologyWarning {
+    /// A hat publishes a topic that no other hat subscribes to.
+    DeadEndPublish { hat: String, topic: String },
+    /// A hat subscribes to a topic that no other hat publishes.
+    UnreachableSubscription { hat: String, topic: String },
+}
+
+impl std::fmt::Display for TopologyWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TopologyWarning::DeadEndPublish { hat, topic } => {
+                write!(
+                    f,
+                    "Hat '{hat}' publishes '{topic}' but no hat subscribes to it"
+                )
+            }
+            TopologyWarning::UnreachableSubscription { hat, topic } => {
+                write!(
+                    f,
+                    "Hat '{hat}' subscribes to '{topic}' but no hat publishes it"
+                )
+            }
+        }
+    }
+}
+
 /// Information about a hat that receives an event.
 #[derive(Debug, Clone)]
 pub struct EventReceiver {
@@ -139,6 +167,149 @@ impl HatTopology {
 
         Self { hats }
     }
+
+    /// Renders the topology as a Graphviz DOT digraph.
+    ///
+    /// Hats are box nodes and topics are ellipse nodes. A `hat -> topic` edge
+    /// means the hat publishes the topic; a `topic -> hat` edge means the hat
+    /// subscribes to it.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph hats {\n    rankdir=LR;\n");
+
+        for hat in &self.hats {
+            dot.push_str(&format!("    \"{}\" [shape=box];\n", dot_escape(&hat.name)));
+        }
+        for topic in self.topics() {
+            dot.push_str(&format!("    \"{}\" [shape=ellipse];\n", dot_escape(topic)));
+        }
+        for (from, to, _) in self.edges() {
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\";\n",
+                dot_escape(from),
+                dot_escape(to)
+            ));
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the topology as JSON with `hats`, `topics` and `edges` arrays.
+    ///
+    /// Each edge is `{"from", "to", "kind"}` where `kind` is `"publishes"`
+    /// (hat to topic) or `"subscribes"` (topic to hat).
+    pub fn to_json(&self) -> serde_json::Value {
+        let hats: Vec<serde_json::Value> = self
+            .hats
+            .iter()
+            .map(|hat| {
+                serde_json::json!({
+                    "name": hat.name,
+                    "description": hat.description,
+                    "subscribes_to": hat.subscribes_to,
+                    "publishes": hat.publishes,
+                })
+            })
+            .collect();
+        let edges: Vec<serde_json::Value> = self
+            .edges()
+            .into_iter()
+            .map(|(from, to, kind)| serde_json::json!({ "from": from, "to": to, "kind": kind }))
+            .collect();
+
+        serde_json::json!({
+            "hats": hats,
+            "topics": self.topics(),
+            "edges": edges,
+        })
+    }
+
+    /// Checks the topology for topics that lead nowhere.
+    ///
+    /// Reports a [`TopologyWarning::DeadEndPublish`] when no other hat
+    /// subscribes to a published topic, and a
+    /// [`TopologyWarning::UnreachableSubscription`] when no other hat publishes
+    /// a topic matching a subscription. Ralph's catch-all is deliberately not
+    /// counted as a consumer or producer, so entry triggers Ralph emits itself
+    /// (such as `event_loop.starting_event`) are reported as unreachable.
+    /// Negated patterns and the reserved `task.start`/`task.resume` triggers
+    /// are skipped.
+    pub fn validate(&self) -> Vec<TopologyWarning> {
+        const RESERVED_TRIGGERS: &[&str] = &["task.start", "task.resume"];
+        let mut warnings = Vec::new();
+
+        for hat in &self.hats {
+            let others = || self.hats.iter().filter(|other| other.name != hat.name);
+
+            for topic in &hat.publishes {
+                let consumed = others().any(|other| {
+                    other
+                        .subscribes_to
+                        .iter()
+                        .any(|pattern| Topic::new(pattern.as_str()).matches_str(topic))
+                });
+                if !consumed {
+                    warnings.push(TopologyWarning::DeadEndPublish {
+                        hat: hat.name.clone(),
+                        topic: topic.clone(),
+                    });
+                }
+            }
+
+            for pattern in &hat.subscribes_to {
+                let topic = Topic::new(pattern.as_str());
+                if topic.is_negation() || RESERVED_TRIGGERS.contains(&pattern.as_str()) {
+                    continue;
+                }
+                let produced = others().any(|other| {
+                    other
+                        .publishes
+                        .iter()
+                        .any(|published| topic.matches_str(published))
+                });
+                if !produced {
+                    warnings.push(TopologyWarning::UnreachableSubscription {
+                        hat: hat.name.clone(),
+                        topic: pattern.clone(),
+                    });
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Returns every topic mentioned by a hat, sorted and deduplicated.
+    fn topics(&self) -> Vec<&str> {
+        let mut topics: Vec<&str> = self
+            .hats
+            .iter()
+            .flat_map(|hat| hat.subscribes_to.iter().chain(&hat.publishes))
+            .map(String::as_str)
+            .collect();
+        topics.sort_unstable();
+        topics.dedup();
+        topics
+    }
+
+    /// Returns `(from, to, kind)` edges in hat declaration order.
+    fn edges(&self) -> Vec<(&str, &str, &'static str)> {
+        let mut edges = Vec::new();
+        for hat in &self.hats {
+            for topic in &hat.subscribes_to {
+                edges.push((topic.as_str(), hat.name.as_str(), "subscribes"));
+            }
+            for topic in &hat.publishes {
+                edges.push((hat.name.as_str(), topic.as_str(), "publishes"));
+            }
+        }
+        edges
+    }
+}
+
+/// Escapes a string for use inside a double-quoted DOT identifier.
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
 }
 
 impl HatlessRalph {
@@ -200,6 +371,11 @@ impl HatlessRalph {
         self.objective = Some(objective);
     }
 
+    /// Returns the stored objective, if set.
+    pub fn objective(&self) -> Option<&str> {
+        self.objective.as_deref()
+    }
+
     /// Sets robot guidance messages collected from `human.guidance` events.
     ///
     /// Called by `EventLoop::build_prompt()` before `HatlessRalph::build_prompt()`.
@@ -251,7 +427,7 @@ impl HatlessRalph {
         let mut prompt = self.core_prompt();
 
         // Inject skill index between GUARDRAILS and OBJECTIVE
-        if !self.skill_index.is_empty() {
+        if !self.skill_index.is_empty() && self.section_enabled(PromptSection::Skills) {
             prompt.push_str(&self.skill_index);
             prompt.push('\n');
         }
@@ -285,7 +461,9 @@ impl HatlessRalph {
             prompt.push_str(&self.workflow_section());
         }
 
-        if let Some(topology) = &self.hat_topology {
+        if let Some(topology) = &self.hat_topology
+            && self.section_enabled(PromptSection::Hats)
+        {
             prompt.push_str(&self.hats_section(topology, active_hats));
         }
 
@@ -341,7 +519,7 @@ You MUST NOT get distracted by workflow mechanics — they serve this goal.
         // Adapt guardrails based on whether scratchpad or memories mode is active
         let guardrails = self
             .core
-            .guardrails
+            .effective_guardrails()
             .iter()
             .enumerate()
             .map(|(i, g)| {
@@ -461,17 +639,24 @@ Its content is auto-injected in `<scratchpad>` tags at the top of your context e
             }
         }
 
-        prompt.push_str(&format!(
-            r"### GUARDRAILS
+        if self.section_enabled(PromptSection::Guardrails) {
+            prompt.push_str(&format!(
+                r"### GUARDRAILS
 {guardrails}
 
 ",
-            guardrails = guardrails,
-        ));
+                guardrails = guardrails,
+            ));
+        }
 
         prompt
     }
 
+    /// Returns true if `core.prompt_sections` allows the section.
+    fn section_enabled(&self, section: PromptSection) -> bool {
+        self.core.prompt_sections.is_enabled(section)
+    }
+
     fn workflow_section(&self) -> String {
         // Different workflow for solo mode vs multi-hat mode
         if self.hat_topology.is_some() {
@@ -920,6 +1105,65 @@ mod tests {
         assert!(prompt.contains("LOOP_COMPLETE"));
     }
 
+    #[test]
+    fn test_guardrails_override_and_append_in_core_prompt() {
+        let yaml = r#"
+core:
+  guardrails_override: ["Team rule"]
+  guardrails_append: ["Appended rule"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let ralph = HatlessRalph::new("LOOP_COMPLETE", config.core, &HatRegistry::new(), None);
+
+        let prompt = ralph.build_prompt("", &[]);
+
+        assert!(prompt.contains("### GUARDRAILS\n999. Team rule\n1000. Appended rule\n"));
+        assert!(!prompt.contains("Backpressure is law"));
+    }
+
+    #[test]
+    fn test_prompt_sections_denylist_omits_hats() {
+        let yaml = r#"
+core:
+  prompt_sections:
+    exclude: ["hats"]
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+        let ralph = HatlessRalph::new("LOOP_COMPLETE", config.core.clone(), &registry, None)
+            .with_skill_index("## SKILLS\n\n| skill |\n".to_string());
+
+        let prompt = ralph.build_prompt("", &[]);
+
+        assert!(
+            !prompt.contains("## HATS"),
+            "denied section should be absent"
+        );
+        assert!(prompt.contains("### GUARDRAILS"));
+        assert!(prompt.contains("## SKILLS"));
+        assert!(prompt.contains("## EVENT WRITING"));
+    }
+
+    #[test]
+    fn test_prompt_sections_allowlist_keeps_only_listed() {
+        let mut core = CoreConfig::default();
+        core.prompt_sections.include = vec![PromptSection::Skills];
+        let registry = HatRegistry::new();
+        let ralph = HatlessRalph::new("LOOP_COMPLETE", core, &registry, None)
+            .with_skill_index("## SKILLS\n\n| skill |\n".to_string());
+
+        let prompt = ralph.build_prompt("", &[]);
+
+        assert!(!prompt.contains("### GUARDRAILS"));
+        assert!(prompt.contains("## SKILLS"));
+        assert!(prompt.contains("### 0a. ORIENTATION"));
+    }
+
     #[test]
     fn test_prompt_with_hats() {
         // Test multi-hat mode WITHOUT starting_event (no fast path)
@@ -1481,6 +1725,92 @@ hats:
         );
     }
 
+    #[test]
+    fn test_topology_to_dot_and_json_render_edges() {
+        let yaml = r#"
+hats:
+  planner:
+    name: "Planner"
+    triggers: ["task.start"]
+    publishes: ["build.task"]
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+        let topology = HatTopology::from_registry(&registry);
+
+        let dot = topology.to_dot();
+        assert!(dot.starts_with("digraph hats {"));
+        assert!(dot.contains("\"Planner\" [shape=box];"));
+        assert!(dot.contains("\"build.task\" [shape=ellipse];"));
+        assert!(dot.contains("\"task.start\" -> \"Planner\";"));
+        assert!(dot.contains("\"Planner\" -> \"build.task\";"));
+        assert!(dot.contains("\"build.task\" -> \"Builder\";"));
+        assert!(dot.contains("\"Builder\" -> \"build.done\";"));
+
+        let json = topology.to_json();
+        assert_eq!(json["hats"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            json["topics"],
+            serde_json::json!(["build.done", "build.task", "task.start"])
+        );
+        let edges = json["edges"].as_array().unwrap();
+        assert_eq!(edges.len(), 4);
+        assert!(edges.contains(&serde_json::json!({
+            "from": "Planner", "to": "build.task", "kind": "publishes"
+        })));
+        assert!(edges.contains(&serde_json::json!({
+            "from": "build.task", "to": "Builder", "kind": "subscribes"
+        })));
+    }
+
+    #[test]
+    fn test_topology_validate_reports_dead_end_publish() {
+        let yaml = r#"
+hats:
+  planner:
+    name: "Planner"
+    triggers: ["build.done"]
+    publishes: ["build.task", "docs.task"]
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let topology = HatTopology::from_registry(&HatRegistry::from_config(&config));
+
+        assert_eq!(
+            topology.validate(),
+            vec![TopologyWarning::DeadEndPublish {
+                hat: "Planner".to_string(),
+                topic: "docs.task".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_topology_validate_clean_topology_has_no_warnings() {
+        let yaml = r#"
+hats:
+  planner:
+    name: "Planner"
+    triggers: ["build.done"]
+    publishes: ["build.task"]
+  builder:
+    name: "Builder"
+    triggers: ["build.*"]
+    publishes: ["build.done"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let topology = HatTopology::from_registry(&HatRegistry::from_config(&config));
+
+        assert!(topology.validate().is_empty());
+    }
+
     #[test]
     fn test_topology_table_only_when_ralph_coordinating() {
         // Topology table + Mermaid shown only when Ralph is coordinating (no active hats)
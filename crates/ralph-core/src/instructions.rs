@@ -5,7 +5,7 @@
 //! - 1, 2, 3: Workflow phases
 //! - 999+: Guardrails (higher = more important)
 
-use crate::config::{CoreConfig, EventMetadata};
+use crate::config::{CoreConfig, EventMetadata, PromptSection};
 use ralph_proto::Hat;
 use std::collections::HashMap;
 
@@ -134,14 +134,23 @@ impl InstructionBuilder {
     /// Use this for hats beyond the default Ralph.
     /// When instructions are empty, derives them from the pub/sub contract.
     pub fn build_custom_hat(&self, hat: &Hat, events_context: &str) -> String {
-        let guardrails = self
+        let guardrails = if self
             .core
-            .guardrails
-            .iter()
-            .enumerate()
-            .map(|(i, g)| format!("{}. {g}", 999 + i))
-            .collect::<Vec<_>>()
-            .join("\n");
+            .prompt_sections
+            .is_enabled(PromptSection::Guardrails)
+        {
+            let guardrails = self
+                .core
+                .effective_guardrails()
+                .iter()
+                .enumerate()
+                .map(|(i, g)| format!("{}. {g}", 999 + i))
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!("### GUARDRAILS\n{guardrails}\n\n")
+        } else {
+            String::new()
+        };
 
         let role_instructions = if hat.instructions.is_empty() {
             self.derive_instructions_from_contract(hat)
@@ -188,10 +197,7 @@ You MUST NOT close tasks unless ALL conditions are met:
 You MUST publish a result event with evidence.
 {publish_topics}{must_publish}
 
-### GUARDRAILS
-{guardrails}
-
----
+{guardrails}---
 You MUST handle these events:
 {events}",
             name = hat.name,
@@ -252,6 +258,49 @@ mod tests {
         assert!(instructions.contains("PR #123 ready for review"));
     }
 
+    #[test]
+    fn test_denied_guardrails_section_omitted() {
+        let mut core = CoreConfig::default();
+        core.prompt_sections.exclude = vec![PromptSection::Guardrails];
+        let builder = InstructionBuilder::new(core);
+
+        let hat = Hat::new("worker", "Worker").with_instructions("Do the work.");
+        let instructions = builder.build_custom_hat(&hat, "context");
+
+        assert!(!instructions.contains("### GUARDRAILS"));
+        assert!(!instructions.contains("999."));
+        assert!(instructions.contains("### 1. EXECUTE"));
+        assert!(instructions.contains("Do the work."));
+        assert!(instructions.contains("You MUST handle these events:\ncontext"));
+    }
+
+    #[test]
+    fn test_guardrails_override_replaces_defaults() {
+        let mut core = CoreConfig::default();
+        core.guardrails_override = Some(vec!["Règle locale".to_string()]);
+        let builder = InstructionBuilder::new(core);
+
+        let hat = Hat::new("worker", "Worker").with_instructions("Do the work.");
+        let instructions = builder.build_custom_hat(&hat, "context");
+
+        assert!(instructions.contains("### GUARDRAILS\n999. Règle locale\n\n---"));
+        assert!(!instructions.contains("Fresh context each iteration"));
+    }
+
+    #[test]
+    fn test_guardrails_append_adds_to_defaults() {
+        let mut core = CoreConfig::default();
+        core.guardrails_append = vec!["Never touch prod".to_string()];
+        let defaults = core.guardrails.len();
+        let builder = InstructionBuilder::new(core);
+
+        let hat = Hat::new("worker", "Worker").with_instructions("Do the work.");
+        let instructions = builder.build_custom_hat(&hat, "context");
+
+        assert!(instructions.contains("999. Fresh context each iteration"));
+        assert!(instructions.contains(&format!("{}. Never touch prod", 999 + defaults)));
+    }
+
     #[test]
     fn test_custom_guardrails_injected() {
         let custom_core = CoreConfig {
@@ -259,6 +308,7 @@ mod tests {
             specs_dir: "./specifications/".to_string(),
             guardrails: vec!["Custom rule one".to_string(), "Custom rule two".to_string()],
             workspace_root: std::path::PathBuf::from("."),
+            ..CoreConfig::default()
         };
         let builder = InstructionBuilder::new(custom_core);
 
@@ -10,7 +10,8 @@
 //! handoffs between Ralph loops.
 
 use crate::git_ops::{
-    AutoCommitResult, auto_commit_changes, clean_stashes, is_working_tree_clean, prune_remote_refs,
+    AutoCommitResult, CommitIdentity, auto_commit_changes_as, clean_stashes, is_working_tree_clean,
+    prune_remote_refs,
 };
 use crate::handoff::{HandoffError, HandoffWriter};
 use crate::loop_context::LoopContext;
@@ -70,6 +71,23 @@ pub struct LandingConfig {
 
     /// Whether to generate the handoff file.
     pub generate_handoff: bool,
+
+    /// Author for landing commits (defaults to the ambient git identity).
+    pub commit_author: Option<CommitIdentity>,
+
+    /// Committer for landing commits (defaults to the ambient git identity).
+    pub commit_committer: Option<CommitIdentity>,
+}
+
+impl LandingConfig {
+    /// Default landing configuration with the commit identities from `identity`.
+    pub fn with_identity(identity: &crate::config::LandingIdentityConfig) -> Self {
+        Self {
+            commit_author: identity.commit_author.clone(),
+            commit_committer: identity.commit_committer.clone(),
+            ..Self::default()
+        }
+    }
 }
 
 impl Default for LandingConfig {
@@ -79,6 +97,8 @@ impl Default for LandingConfig {
             clear_stashes: true,
             prune_refs: true,
             generate_handoff: true,
+            commit_author: None,
+            commit_committer: None,
         }
     }
 }
@@ -134,7 +154,12 @@ impl LandingHandler {
 
         // Step 2: Auto-commit uncommitted changes
         let commit_result = if self.config.auto_commit {
-            match auto_commit_changes(workspace, &loop_id) {
+            match auto_commit_changes_as(
+                workspace,
+                &loop_id,
+                self.config.commit_author.as_ref(),
+                self.config.commit_committer.as_ref(),
+            ) {
                 Ok(result) => {
                     if result.committed {
                         info!(
@@ -366,6 +391,7 @@ mod tests {
             clear_stashes: false,
             prune_refs: false,
             generate_handoff: false,
+            ..LandingConfig::default()
         };
 
         let handler = LandingHandler::with_config(ctx.clone(), config);
@@ -375,6 +401,51 @@ mod tests {
         assert!(!result.working_tree_clean); // Changes still there
     }
 
+    #[test]
+    fn test_landing_commit_uses_configured_identity() {
+        let (temp, ctx) = setup_test_context();
+        fs::write(temp.path().join("new_file.txt"), "content").unwrap();
+
+        let bot = CommitIdentity::new("Ralph Bot", "ralph-bot@example.com");
+        let config = LandingConfig {
+            commit_author: Some(bot.clone()),
+            commit_committer: Some(bot),
+            ..LandingConfig::default()
+        };
+        let handler = LandingHandler::with_config(ctx.clone(), config);
+        let result = handler.land("Test prompt").unwrap();
+        assert!(result.committed);
+
+        let output = Command::new("git")
+            .args(["log", "-1", "--format=%an <%ae>|%cn <%ce>"])
+            .current_dir(temp.path())
+            .output()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            "Ralph Bot <ralph-bot@example.com>|Ralph Bot <ralph-bot@example.com>"
+        );
+    }
+
+    #[test]
+    fn test_landing_commit_defaults_to_ambient_identity() {
+        let (temp, ctx) = setup_test_context();
+        fs::write(temp.path().join("new_file.txt"), "content").unwrap();
+
+        let handler = LandingHandler::new(ctx.clone());
+        assert!(handler.land("Test prompt").unwrap().committed);
+
+        let output = Command::new("git")
+            .args(["log", "-1", "--format=%an <%ae>"])
+            .current_dir(temp.path())
+            .output()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            "Test User <test@test.local>"
+        );
+    }
+
     #[test]
     fn test_landing_generates_handoff_content() {
         let (_temp, ctx) = setup_test_context();
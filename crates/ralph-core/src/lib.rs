@@ -56,25 +56,31 @@ pub mod worktree;
 #[cfg(feature = "recording")]
 pub use cli_capture::{CliCapture, CliCapturePair};
 pub use config::{
-    CliConfig, ConfigError, CoreConfig, EventLoopConfig, EventMetadata, FeaturesConfig, HatBackend,
-    HatConfig, InjectMode, MemoriesConfig, MemoriesFilter, RalphConfig, SkillOverride,
-    SkillsConfig,
+    CliConfig, CompletionPosition, ConfigError, CoreConfig, EventLoopConfig, EventMetadata,
+    FeaturesConfig, HatBackend, HatConfig, InjectMode, LandingIdentityConfig, MemoriesConfig,
+    MemoriesFilter, MemoryBudgetMode, OrphanEventMode, PersistenceInterval, PromptSection,
+    PromptSectionsConfig, QualityReportMode, RalphConfig, ReadyTaskOrder, SkillOverride,
+    SkillsConfig, TruncateMode,
 };
 // Re-export loop_name types (also available via FeaturesConfig.loop_naming)
 pub use diagnostics::DiagnosticsCollector;
 pub use event_logger::{EventHistory, EventLogger, EventRecord};
-pub use event_loop::{EventLoop, LoopState, TerminationReason, UserPrompt};
-pub use event_parser::EventParser;
+pub use event_loop::{
+    EventLoop, EventProcessingPreview, HatCost, InjectError, LoopState, PreviewedEvent,
+    PromptBreakdown, RunReport, TerminationReason, TerminationSummary, UserPrompt,
+};
+pub use event_parser::{EventParser, StreamingEventParser};
 pub use event_reader::{Event, EventReader, MalformedLine, ParseResult};
 pub use file_lock::{FileLock, LockGuard as FileLockGuard, LockedFile};
 pub use git_ops::{
-    AutoCommitResult, GitOpsError, auto_commit_changes, clean_stashes, get_commit_summary,
-    get_current_branch, get_head_sha, get_recent_files, has_uncommitted_changes,
-    is_working_tree_clean, prune_remote_refs,
+    AutoCommitResult, CommitIdentity, DEFAULT_GIT_TIMEOUT, GitOpsError, auto_commit_changes,
+    auto_commit_changes_as, clean_stashes, get_commit_summary, get_current_branch, get_head_sha,
+    get_recent_files, git_timeout, has_uncommitted_changes, is_working_tree_clean,
+    prune_remote_refs, set_git_timeout, with_git_timeout,
 };
 pub use handoff::{HandoffError, HandoffResult, HandoffWriter};
 pub use hat_registry::HatRegistry;
-pub use hatless_ralph::{HatInfo, HatTopology, HatlessRalph};
+pub use hatless_ralph::{HatInfo, HatTopology, HatlessRalph, TopologyWarning};
 pub use instructions::InstructionBuilder;
 pub use landing::{LandingConfig, LandingError, LandingHandler, LandingResult};
 pub use loop_completion::{CompletionAction, CompletionError, LoopCompletionHandler};
@@ -85,23 +91,29 @@ pub use loop_name::{LoopNameGenerator, LoopNamingConfig};
 pub use loop_registry::{LoopEntry, LoopRegistry, RegistryError};
 pub use memory::{Memory, MemoryType};
 pub use memory_store::{
-    DEFAULT_MEMORIES_PATH, MarkdownMemoryStore, format_memories_as_markdown, truncate_to_budget,
+    DEFAULT_MEMORIES_PATH, MarkdownMemoryStore, format_memories_as_markdown,
+    format_memories_within_budget, select_memories_within_budget, truncate_to_budget,
 };
 pub use merge_queue::{
-    MergeButtonState, MergeEntry, MergeEvent, MergeEventType, MergeOption, MergeQueue,
-    MergeQueueError, MergeState, SteeringDecision, merge_button_state, merge_execution_summary,
-    merge_needs_steering, smart_merge_summary,
+    MergeButtonEvent, MergeButtonState, MergeButtonTransitionError, MergeEntry, MergeEvent,
+    MergeEventHook, MergeEventType, MergeOption, MergeQueue, MergeQueueError, MergeState,
+    MergeSteeringConfig, SteeringDecision, merge_button_state, merge_execution_summary,
+    merge_needs_steering, merge_needs_steering_with, smart_merge_summary,
 };
 pub use planning_session::{
     ConversationEntry, ConversationType, PlanningSession, PlanningSessionError, SessionMetadata,
     SessionStatus,
 };
 pub use preflight::{
-    AcceptanceCriterion, CheckResult, CheckStatus, PreflightCheck, PreflightReport,
-    PreflightRunner, extract_acceptance_criteria, extract_all_criteria, extract_criteria_from_file,
+    AcceptanceCriterion, CheckResult, CheckStatus, DEFAULT_PREFLIGHT_CACHE_TTL,
+    PREFLIGHT_CACHE_FILE, PreflightCheck, PreflightReport, PreflightRunner,
+    extract_acceptance_criteria, extract_all_criteria, extract_criteria_from_file,
+    link_criteria_to_tasks,
 };
 #[cfg(feature = "recording")]
-pub use session_player::{PlayerConfig, ReplayMode, SessionPlayer, TimestampedRecord};
+pub use session_player::{
+    PlayerConfig, ReplayColorMode, ReplayMode, SessionPlayer, TimestampedRecord,
+};
 #[cfg(feature = "recording")]
 pub use session_recorder::{Record, SessionRecorder};
 pub use skill::{SkillEntry, SkillFrontmatter, SkillSource, parse_frontmatter};
@@ -111,14 +123,15 @@ pub use task::{Task, TaskStatus};
 pub use task_definition::{
     TaskDefinition, TaskDefinitionError, TaskSetup, TaskSuite, Verification,
 };
-pub use task_store::TaskStore;
+pub use task_store::{TaskStore, TaskStoreError};
 pub use text::{floor_char_boundary, truncate_with_ellipsis};
 pub use workspace::{
-    CleanupPolicy, TaskWorkspace, VerificationResult, WorkspaceError, WorkspaceInfo,
-    WorkspaceManager,
+    CleanupPolicy, TaskWorkspace, VerificationResult, VerificationStep, WorkspaceError,
+    WorkspaceInfo, WorkspaceManager,
 };
 pub use worktree::{
-    SyncStats, Worktree, WorktreeConfig, WorktreeError, create_worktree, ensure_gitignore,
-    list_ralph_worktrees, list_worktrees, remove_worktree, sync_working_directory_to_worktree,
+    SyncPlan, SyncStats, Worktree, WorktreeConfig, WorktreeError, create_worktree,
+    ensure_gitignore, list_ralph_worktrees, list_worktrees, remove_worktree,
+    sync_working_directory_to_worktree, sync_working_directory_to_worktree_dry_run,
     worktree_exists,
 };
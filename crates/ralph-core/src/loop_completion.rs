@@ -33,8 +33,8 @@
 //! assert!(matches!(action, CompletionAction::Enqueued { .. }));
 //! ```
 
-use crate::git_ops::auto_commit_changes;
-use crate::landing::{LandingHandler, LandingResult};
+use crate::git_ops::auto_commit_changes_as;
+use crate::landing::{LandingConfig, LandingHandler, LandingResult};
 use crate::loop_context::LoopContext;
 use crate::merge_queue::{MergeQueue, MergeQueueError};
 use tracing::{debug, info, warn};
@@ -109,6 +109,9 @@ pub enum CompletionError {
 pub struct LoopCompletionHandler {
     /// Whether auto-merge is enabled (default: true).
     auto_merge: bool,
+
+    /// Landing configuration, including the identity for completion commits.
+    landing: LandingConfig,
 }
 
 impl Default for LoopCompletionHandler {
@@ -125,7 +128,17 @@ impl LoopCompletionHandler {
     /// * `auto_merge` - If true, completed worktree loops are enqueued for merge-ralph.
     ///   If false, worktrees are left for manual merge.
     pub fn new(auto_merge: bool) -> Self {
-        Self { auto_merge }
+        Self {
+            auto_merge,
+            landing: LandingConfig::default(),
+        }
+    }
+
+    /// Uses `landing` for the landing sequence and the pre-merge auto-commit.
+    #[must_use]
+    pub fn with_landing_config(mut self, landing: LandingConfig) -> Self {
+        self.landing = landing;
+        self
     }
 
     /// Handles loop completion, taking appropriate action based on context.
@@ -177,7 +190,12 @@ impl LoopCompletionHandler {
 
         if self.auto_merge {
             // Auto-commit any uncommitted changes before enqueueing
-            match auto_commit_changes(context.workspace(), &loop_id) {
+            match auto_commit_changes_as(
+                context.workspace(),
+                &loop_id,
+                self.landing.commit_author.as_ref(),
+                self.landing.commit_committer.as_ref(),
+            ) {
                 Ok(result) => {
                     if result.committed {
                         info!(
@@ -229,7 +247,7 @@ impl LoopCompletionHandler {
     ///
     /// Returns the landing result if successful, or None if landing failed.
     fn execute_landing(&self, context: &LoopContext, prompt: &str) -> Option<LandingResult> {
-        let handler = LandingHandler::new(context.clone());
+        let handler = LandingHandler::with_config(context.clone(), self.landing.clone());
 
         match handler.land(prompt) {
             Ok(result) => {
@@ -314,6 +332,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_landing_uses_configured_commit_identity() {
+        let temp = TempDir::new().unwrap();
+        init_git_repo(temp.path());
+        let context = LoopContext::primary(temp.path().to_path_buf());
+        context.ensure_directories().unwrap();
+        std::fs::write(temp.path().join("work.txt"), "done").unwrap();
+
+        let config: crate::RalphConfig = serde_yaml::from_str(
+            r#"
+features:
+  landing:
+    commit_author: { name: "Ralph Bot", email: "ralph-bot@example.com" }
+"#,
+        )
+        .unwrap();
+        let handler = LoopCompletionHandler::new(true)
+            .with_landing_config(LandingConfig::with_identity(&config.features.landing));
+
+        let action = handler.handle_completion(&context, "test prompt").unwrap();
+        assert!(matches!(action, CompletionAction::Landed { .. }));
+
+        let output = Command::new("git")
+            .args(["log", "-1", "--format=%an <%ae>|%cn"])
+            .current_dir(temp.path())
+            .output()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            "Ralph Bot <ralph-bot@example.com>|Test User"
+        );
+    }
+
     #[test]
     fn test_worktree_loop_auto_merge_enqueues() {
         let temp = TempDir::new().unwrap();
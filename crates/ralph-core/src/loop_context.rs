@@ -29,6 +29,8 @@
 //! ├── events.jsonl
 //! ├── current-events
 //! ├── history.jsonl
+//! ├── loop-state.json
+//! ├── loop-env.json             # Per-loop environment overrides
 //! ├── diagnostics/
 //! └── planning-sessions/
 //! ```
@@ -54,6 +56,8 @@
 //!            "/project/.worktrees/loop-1234-abcd/.ralph/events.jsonl");
 //! ```
 
+use std::collections::HashMap;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
 /// Context for resolving paths within a Ralph loop.
@@ -76,6 +80,9 @@ pub struct LoopContext {
 
     /// Whether this is the primary loop (holds loop.lock).
     is_primary: bool,
+
+    /// Environment variables set on backend processes spawned by this loop.
+    env_overrides: HashMap<String, String>,
 }
 
 impl LoopContext {
@@ -89,6 +96,7 @@ impl LoopContext {
             repo_root: workspace.clone(),
             workspace,
             is_primary: true,
+            env_overrides: HashMap::new(),
         }
     }
 
@@ -112,9 +120,18 @@ impl LoopContext {
             workspace: worktree_path,
             repo_root,
             is_primary: false,
+            env_overrides: HashMap::new(),
         }
     }
 
+    /// Sets environment variables for backend processes spawned by this loop.
+    ///
+    /// Lets concurrent loops use distinct values (e.g. a unique `PORT`).
+    pub fn with_env(mut self, env: HashMap<String, String>) -> Self {
+        self.env_overrides = env;
+        self
+    }
+
     /// Returns the loop identifier, if any.
     ///
     /// Primary loops return None; worktree loops return their unique ID.
@@ -127,6 +144,11 @@ impl LoopContext {
         self.is_primary
     }
 
+    /// Returns the environment overrides for backend processes.
+    pub fn env_overrides(&self) -> &HashMap<String, String> {
+        &self.env_overrides
+    }
+
     /// Returns the workspace root for this loop.
     ///
     /// This is the directory where the loop executes:
@@ -274,6 +296,20 @@ impl LoopContext {
         self.ralph_dir().join("history.jsonl")
     }
 
+    /// Path to the persisted loop state JSON file.
+    ///
+    /// Written each iteration so a crashed loop resumes its counters on restart.
+    pub fn loop_state_path(&self) -> PathBuf {
+        self.ralph_dir().join("loop-state.json")
+    }
+
+    /// Path to the persisted environment overrides JSON file.
+    ///
+    /// Lets a restarted loop keep the environment it was started with.
+    pub fn loop_env_path(&self) -> PathBuf {
+        self.ralph_dir().join("loop-env.json")
+    }
+
     /// Path to the loop lock file (only meaningful for primary loop detection).
     pub fn loop_lock_path(&self) -> PathBuf {
         // Lock is always in the main repo root
@@ -558,6 +594,65 @@ Local state (scratchpad, runtime tasks, events) is isolated to this worktree.
         Ok(true)
     }
 
+    /// Saves the environment overrides to `.ralph/loop-env.json`.
+    ///
+    /// Does nothing if there are no overrides. Writes to a temporary sibling
+    /// file and renames it into place. Overrides may hold secrets, so on Unix
+    /// the file is only readable by the owner (mode `0600`).
+    pub fn save_env_overrides(&self) -> std::io::Result<()> {
+        if self.env_overrides.is_empty() {
+            return Ok(());
+        }
+
+        self.ensure_ralph_dir()?;
+        let path = self.loop_env_path();
+        let json = serde_json::to_string_pretty(&self.env_overrides)?;
+        let tmp_path = path.with_extension("json.tmp");
+
+        // The mode only applies on creation, so never reuse a leftover temp file
+        match std::fs::remove_file(&tmp_path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        options.open(&tmp_path)?.write_all(json.as_bytes())?;
+        std::fs::rename(&tmp_path, path)
+    }
+
+    /// Merges overrides persisted in `.ralph/loop-env.json` into this context.
+    ///
+    /// Overrides already set on the context take precedence. Returns the
+    /// (sorted) names of the overrides restored from the file. A missing file
+    /// is not an error.
+    ///
+    /// Callers should only restore overrides for worktree loops and resumed
+    /// runs, so a fresh run doesn't inherit a previous run's environment.
+    pub fn load_env_overrides(&mut self) -> std::io::Result<Vec<String>> {
+        let content = match std::fs::read_to_string(self.loop_env_path()) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        let persisted: HashMap<String, String> = serde_json::from_str(&content)?;
+        let mut restored = Vec::new();
+        for (key, value) in persisted {
+            if let std::collections::hash_map::Entry::Vacant(entry) = self.env_overrides.entry(key)
+            {
+                restored.push(entry.key().clone());
+                entry.insert(value);
+            }
+        }
+        restored.sort();
+        Ok(restored)
+    }
+
     /// Sets up all worktree symlinks (memories, specs, code tasks).
     ///
     /// Convenience method that calls all setup_*_symlink methods.
@@ -791,6 +886,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_loop_state_path() {
+        let ctx = LoopContext::primary(PathBuf::from("/project"));
+        assert_eq!(
+            ctx.loop_state_path(),
+            PathBuf::from("/project/.ralph/loop-state.json")
+        );
+    }
+
     #[test]
     fn test_planning_sessions_paths() {
         let ctx = LoopContext::primary(PathBuf::from("/project"));
@@ -982,4 +1086,84 @@ mod tests {
         assert!(ctx.specs_dir().is_symlink());
         assert!(ctx.code_tasks_dir().is_symlink());
     }
+
+    #[test]
+    fn test_env_overrides_survive_save_and_load() {
+        let temp = TempDir::new().unwrap();
+        let env = HashMap::from([("PORT".to_string(), "8081".to_string())]);
+        let ctx = LoopContext::worktree(
+            "loop-1234",
+            temp.path().join(".worktrees/loop-1234"),
+            temp.path().to_path_buf(),
+        )
+        .with_env(env);
+
+        assert_eq!(
+            ctx.env_overrides().get("PORT").map(String::as_str),
+            Some("8081")
+        );
+        ctx.save_env_overrides().unwrap();
+        assert!(ctx.loop_env_path().exists());
+
+        // A restarted loop gets a fresh context without overrides.
+        let mut restarted = LoopContext::worktree(
+            "loop-1234",
+            temp.path().join(".worktrees/loop-1234"),
+            temp.path().to_path_buf(),
+        );
+        assert!(restarted.env_overrides().is_empty());
+        assert_eq!(restarted.load_env_overrides().unwrap(), vec!["PORT"]);
+        assert_eq!(
+            restarted.env_overrides().get("PORT").map(String::as_str),
+            Some("8081")
+        );
+    }
+
+    #[test]
+    fn test_load_env_overrides_keeps_explicit_values() {
+        let temp = TempDir::new().unwrap();
+        LoopContext::primary(temp.path().to_path_buf())
+            .with_env(HashMap::from([
+                ("PORT".to_string(), "8081".to_string()),
+                ("API_KEY".to_string(), "persisted".to_string()),
+            ]))
+            .save_env_overrides()
+            .unwrap();
+
+        let mut ctx = LoopContext::primary(temp.path().to_path_buf())
+            .with_env(HashMap::from([("PORT".to_string(), "9090".to_string())]));
+        assert_eq!(ctx.load_env_overrides().unwrap(), vec!["API_KEY"]);
+
+        assert_eq!(ctx.env_overrides()["PORT"], "9090");
+        assert_eq!(ctx.env_overrides()["API_KEY"], "persisted");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_save_env_overrides_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = TempDir::new().unwrap();
+        let ctx = LoopContext::primary(temp.path().to_path_buf()).with_env(HashMap::from([(
+            "API_KEY".to_string(),
+            "secret".to_string(),
+        )]));
+        ctx.save_env_overrides().unwrap();
+        // Saving again replaces the file and keeps the mode
+        ctx.save_env_overrides().unwrap();
+
+        let mode = std::fs::metadata(ctx.loop_env_path())
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_load_env_overrides_missing_file_is_noop() {
+        let temp = TempDir::new().unwrap();
+        let mut ctx = LoopContext::primary(temp.path().to_path_buf());
+        ctx.load_env_overrides().unwrap();
+        assert!(ctx.env_overrides().is_empty());
+    }
 }
@@ -181,6 +181,19 @@ impl Memory {
                 .any(|tag| tag.to_lowercase().contains(&query_lower))
     }
 
+    /// Returns a normalized form of the content for duplicate detection.
+    ///
+    /// Content is lowercased and runs of whitespace are collapsed to a
+    /// single space, so trivially reworded copies compare equal.
+    #[must_use]
+    pub fn content_fingerprint(&self) -> String {
+        self.content
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     /// Returns true if this memory has any of the specified tags.
     #[must_use]
     pub fn has_any_tag(&self, tags: &[String]) -> bool {
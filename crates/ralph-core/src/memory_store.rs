@@ -14,6 +14,8 @@
 //! The `MarkdownMemoryStore` is Clone because it doesn't hold the lock;
 //! locks are acquired for each operation.
 
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
@@ -170,6 +172,50 @@ impl MarkdownMemoryStore {
         Ok(true)
     }
 
+    /// Collapses memories with identical normalized content.
+    ///
+    /// Memories are compared by [`Memory::content_fingerprint`]. For each
+    /// group of duplicates the most recent entry (by `created`, then by ID)
+    /// survives in the position of the first occurrence. Returns the number
+    /// of memories removed; the file is only rewritten when that is non-zero.
+    /// Uses an exclusive lock to prevent concurrent writes.
+    pub fn dedup(&self) -> io::Result<usize> {
+        if !self.exists() {
+            return Ok(0);
+        }
+
+        let lock = FileLock::new(&self.path)?;
+        let _guard = lock.exclusive()?;
+
+        let content = fs::read_to_string(&self.path)?;
+        let memories = parse_memories(&content);
+        let original_len = memories.len();
+
+        let mut kept: Vec<Memory> = Vec::with_capacity(original_len);
+        let mut index_by_fingerprint: HashMap<String, usize> = HashMap::new();
+        for memory in memories {
+            match index_by_fingerprint.entry(memory.content_fingerprint()) {
+                Entry::Occupied(slot) => {
+                    let existing = &mut kept[*slot.get()];
+                    if (&memory.created, &memory.id) > (&existing.created, &existing.id) {
+                        *existing = memory;
+                    }
+                }
+                Entry::Vacant(slot) => {
+                    slot.insert(kept.len());
+                    kept.push(memory);
+                }
+            }
+        }
+
+        let removed = original_len - kept.len();
+        if removed > 0 {
+            self.write_all_internal(&kept)?;
+        }
+
+        Ok(removed)
+    }
+
     /// Returns the memory with the given ID, if it exists.
     pub fn get(&self, id: &str) -> io::Result<Option<Memory>> {
         let memories = self.load()?;
@@ -290,7 +336,7 @@ pub fn format_memories_as_markdown(memories: &[Memory]) -> String {
         return String::new();
     }
 
-    let mut output = String::from("# Memories\n");
+    let mut output = String::from(MEMORIES_HEADER);
 
     // Group by type
     for memory_type in MemoryType::all() {
@@ -303,22 +349,33 @@ pub fn format_memories_as_markdown(memories: &[Memory]) -> String {
             continue;
         }
 
-        output.push_str(&format!("\n## {}\n", memory_type.section_name()));
+        output.push_str(&format_section_heading(*memory_type));
 
         for memory in type_memories {
-            output.push_str(&format!(
-                "\n### {}\n> {}\n<!-- tags: {} | created: {} -->\n",
-                memory.id,
-                memory.content.replace('\n', "\n> "),
-                memory.tags.join(", "),
-                memory.created
-            ));
+            output.push_str(&format_memory_block(memory));
         }
     }
 
     output
 }
 
+/// Header that starts the rendered memories markdown.
+const MEMORIES_HEADER: &str = "# Memories\n";
+
+fn format_section_heading(memory_type: MemoryType) -> String {
+    format!("\n## {}\n", memory_type.section_name())
+}
+
+fn format_memory_block(memory: &Memory) -> String {
+    format!(
+        "\n### {}\n> {}\n<!-- tags: {} | created: {} -->\n",
+        memory.id,
+        memory.content.replace('\n', "\n> "),
+        memory.tags.join(", "),
+        memory.created
+    )
+}
+
 /// Truncates memory content to approximately fit within a token budget.
 ///
 /// Uses a simple heuristic of ~4 characters per token. Tries to end
@@ -367,6 +424,114 @@ pub fn truncate_to_budget(content: &str, budget: usize) -> String {
     }
 }
 
+/// Selects whole memories that fit within a token budget.
+///
+/// Unlike [`truncate_to_budget`], this never cuts a memory mid-block: memories
+/// are dropped until the rendered markdown, plus the omission note added by
+/// [`format_memories_within_budget`], fits. Memories carry no importance
+/// signal, so they are ranked by recency: the oldest (by `created`) are
+/// dropped first and, on ties, the entry appearing later in the list.
+///
+/// Uses the same ~4 characters per token heuristic. A budget of 0 means
+/// unlimited. The relative order of the kept memories is preserved.
+#[must_use]
+pub fn select_memories_within_budget(memories: &[Memory], budget: usize) -> Vec<Memory> {
+    if budget == 0 {
+        return memories.to_vec();
+    }
+
+    let char_budget = budget * 4;
+
+    let mut drop_order: Vec<usize> = (0..memories.len()).collect();
+    drop_order.sort_by(|&a, &b| {
+        memories[a]
+            .created
+            .cmp(&memories[b].created)
+            .then(b.cmp(&a))
+    });
+
+    // Track the rendered length incrementally rather than re-rendering the
+    // selection after every drop.
+    let block_lens: Vec<usize> = memories
+        .iter()
+        .map(|memory| format_memory_block(memory).len())
+        .collect();
+    let mut section_counts: Vec<usize> = MemoryType::all()
+        .iter()
+        .map(|memory_type| {
+            memories
+                .iter()
+                .filter(|memory| memory.memory_type == *memory_type)
+                .count()
+        })
+        .collect();
+    let mut rendered_len = format_memories_as_markdown(memories).len();
+
+    let mut kept = vec![true; memories.len()];
+    let mut remaining = memories.len();
+    for index in drop_order {
+        let dropped = memories.len() - remaining;
+        let required = if dropped == 0 {
+            rendered_len
+        } else {
+            rendered_len + usize::from(remaining > 0) + omitted_memories_note(dropped, budget).len()
+        };
+        if required <= char_budget {
+            break;
+        }
+
+        kept[index] = false;
+        remaining -= 1;
+        rendered_len -= block_lens[index];
+
+        let section = MemoryType::all()
+            .iter()
+            .position(|memory_type| *memory_type == memories[index].memory_type)
+            .unwrap_or_default();
+        section_counts[section] -= 1;
+        if section_counts[section] == 0 {
+            rendered_len -= format_section_heading(memories[index].memory_type).len();
+        }
+        if remaining == 0 {
+            rendered_len -= MEMORIES_HEADER.len();
+        }
+    }
+
+    memories
+        .iter()
+        .zip(&kept)
+        .filter(|(_, keep)| **keep)
+        .map(|(memory, _)| memory.clone())
+        .collect()
+}
+
+/// Renders the memories selected by [`select_memories_within_budget`] as
+/// markdown, followed by a note counting the memories left out.
+///
+/// The result never exceeds the budget; the note itself is omitted when not
+/// even it fits.
+#[must_use]
+pub fn format_memories_within_budget(memories: &[Memory], budget: usize) -> String {
+    let selected = select_memories_within_budget(memories, budget);
+    let mut content = format_memories_as_markdown(&selected);
+
+    let dropped = memories.len() - selected.len();
+    if dropped > 0 {
+        let note = omitted_memories_note(dropped, budget);
+        let separator = if content.is_empty() { "" } else { "\n" };
+        if content.len() + separator.len() + note.len() <= budget * 4 {
+            content.push_str(separator);
+            content.push_str(&note);
+        }
+    }
+
+    content
+}
+
+fn omitted_memories_note(dropped: usize, budget: usize) -> String {
+    format!("<!-- {dropped} memories omitted: budget {budget} tokens exceeded -->")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -529,6 +694,45 @@ mod tests {
         assert!(!deleted);
     }
 
+    #[test]
+    fn test_dedup_collapses_duplicates_keeping_latest() {
+        let (_temp_dir, store) = create_temp_store();
+
+        let mut older = Memory::new(
+            MemoryType::Pattern,
+            "Use  thiserror for errors".to_string(),
+            vec![],
+        );
+        older.id = "mem-1000-0001".to_string();
+        older.created = "2025-01-10".to_string();
+        let mut newer = Memory::new(
+            MemoryType::Pattern,
+            "use thiserror\nfor ERRORS".to_string(),
+            vec![],
+        );
+        newer.id = "mem-2000-0002".to_string();
+        newer.created = "2025-02-20".to_string();
+        let distinct = Memory::new(MemoryType::Pattern, "Run clippy".to_string(), vec![]);
+
+        store.append(&older).unwrap();
+        store.append(&newer).unwrap();
+        store.append(&distinct).unwrap();
+
+        assert_eq!(store.dedup().unwrap(), 1);
+
+        let memories = store.load().unwrap();
+        assert_eq!(memories.len(), 2);
+        assert!(memories.iter().any(|m| m.id == distinct.id));
+        let survivor = memories
+            .iter()
+            .find(|m| m.content_fingerprint() == older.content_fingerprint())
+            .unwrap();
+        assert_eq!(survivor.created, "2025-02-20");
+        assert_eq!(survivor.id, "mem-2000-0002");
+
+        assert_eq!(store.dedup().unwrap(), 0);
+    }
+
     #[test]
     fn test_get_finds_memory() {
         let (_temp_dir, store) = create_temp_store();
@@ -753,4 +957,74 @@ mod tests {
         assert!(result.len() < content.len());
         assert!(result.contains("<!-- truncated:"));
     }
+
+    fn dated_memory(id: &str, content: &str, created: &str) -> Memory {
+        Memory {
+            id: id.to_string(),
+            memory_type: MemoryType::Pattern,
+            content: content.to_string(),
+            tags: vec![],
+            created: created.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_select_memories_within_budget_zero_means_unlimited() {
+        let memories = vec![
+            dated_memory("mem-1-a", &"x".repeat(500), "2025-01-01"),
+            dated_memory("mem-2-b", &"y".repeat(500), "2025-01-02"),
+        ];
+        assert_eq!(select_memories_within_budget(&memories, 0).len(), 2);
+    }
+
+    #[test]
+    fn test_select_memories_within_budget_drops_oldest_whole_memories() {
+        let memories = vec![
+            dated_memory("mem-1-old", &"old ".repeat(40), "2025-01-01"),
+            dated_memory("mem-2-new", &"new ".repeat(40), "2025-03-01"),
+            dated_memory("mem-3-mid", &"mid ".repeat(40), "2025-02-01"),
+        ];
+        // Room for the two newest memories and the omission note.
+        let two = format_memories_as_markdown(&memories[1..]).len();
+        let budget = (two + 80).div_ceil(4);
+
+        let selected = select_memories_within_budget(&memories, budget);
+        let ids: Vec<_> = selected.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["mem-2-new", "mem-3-mid"]);
+
+        let content = format_memories_within_budget(&memories, budget);
+        assert!(content.ends_with(&format!(
+            "<!-- 1 memories omitted: budget {budget} tokens exceeded -->"
+        )));
+        assert!(content.len() <= budget * 4);
+    }
+
+    #[test]
+    fn test_format_memories_within_budget_reserves_room_for_note() {
+        let memories = vec![
+            dated_memory("mem-1-old", &"old ".repeat(40), "2025-01-01"),
+            dated_memory("mem-2-new", &"new ".repeat(40), "2025-03-01"),
+        ];
+        // Exactly fits the newest memory alone, leaving no room for the note.
+        let budget = format_memories_as_markdown(&memories[1..]).len() / 4;
+
+        for budget in budget.saturating_sub(20)..=budget + 20 {
+            let content = format_memories_within_budget(&memories, budget);
+            assert!(
+                content.len() <= budget * 4,
+                "budget {budget} exceeded: {} chars",
+                content.len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_select_memories_within_budget_returns_empty_when_nothing_fits() {
+        let memories = vec![dated_memory("mem-1-a", &"x".repeat(200), "2025-01-01")];
+        assert!(select_memories_within_budget(&memories, 5).is_empty());
+        assert!(format_memories_within_budget(&memories, 5).is_empty());
+        assert!(
+            format_memories_within_budget(&memories, 20).starts_with("<!-- 1 memories omitted")
+        );
+    }
 }
@@ -43,6 +43,10 @@ use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufRead, BufReader, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::Arc;
+
+/// Callback invoked after each merge queue state change is recorded.
+pub type MergeEventHook = Arc<dyn Fn(&MergeEvent) + Send + Sync>;
 
 /// A merge queue event recorded in the JSONL log.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -65,6 +69,10 @@ pub enum MergeEventType {
     Queued {
         /// The prompt that was executed in this loop.
         prompt: String,
+
+        /// Loop IDs that must merge before this one.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        depends_on: Vec<String>,
     },
 
     /// Merge operation has started.
@@ -93,12 +101,124 @@ pub enum MergeEventType {
 }
 
 /// State of the merge button for a loop.
+///
+/// An explicit state machine: `Idle → Ready → Steering → Executing →
+/// Done/Failed`. Use [`MergeButtonState::transition`] to move between states
+/// so illegal transitions are rejected.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MergeButtonState {
+    /// Merging is not possible yet (e.g. the primary loop is running).
+    Idle { reason: String },
     /// Merge button is active (can merge now).
-    Active,
-    /// Merge button is blocked with a reason.
+    Ready,
+    /// The merge needs user steering before it can run.
+    Steering { reason: String },
+    /// A merge is in progress.
+    Executing,
+    /// The loop has been merged.
+    Done,
+    /// The merge failed and needs review; it can be retried.
+    Failed { reason: String },
+}
+
+/// Events that drive [`MergeButtonState`] transitions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeButtonEvent {
+    /// Whatever blocked the merge has cleared (`Idle → Ready`).
+    Unblocked,
+    /// Merging became impossible (`Ready/Steering → Idle`).
     Blocked { reason: String },
+    /// Heuristics asked for user input (`Ready → Steering`).
+    SteeringNeeded { reason: String },
+    /// The user answered the steering request (`Steering → Ready`).
+    SteeringResolved,
+    /// The merge started (`Ready/Steering → Executing`).
+    Started,
+    /// The merge completed (`Executing → Done`).
+    Succeeded,
+    /// The merge failed (`Executing → Failed`).
+    Failed { reason: String },
+    /// A failed merge is being retried (`Failed → Ready`).
+    Retry,
+}
+
+/// An event that is not valid in the current merge button state.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid merge button transition: {event:?} in state {from:?}")]
+pub struct MergeButtonTransitionError {
+    /// State the transition was attempted from.
+    pub from: MergeButtonState,
+    /// Rejected event.
+    pub event: MergeButtonEvent,
+}
+
+impl MergeButtonState {
+    /// Applies `event`, returning the next state.
+    ///
+    /// Returns an error (leaving `self` untouched) when the event is not
+    /// valid in the current state, e.g. `Started` while `Idle`.
+    pub fn transition(
+        &self,
+        event: MergeButtonEvent,
+    ) -> Result<MergeButtonState, MergeButtonTransitionError> {
+        use MergeButtonEvent as E;
+        use MergeButtonState as S;
+
+        let next = match (self, &event) {
+            (S::Idle { .. }, E::Unblocked)
+            | (S::Steering { .. }, E::SteeringResolved)
+            | (S::Failed { .. }, E::Retry) => S::Ready,
+            (S::Ready | S::Steering { .. }, E::Blocked { reason }) => S::Idle {
+                reason: reason.clone(),
+            },
+            (S::Ready, E::SteeringNeeded { reason }) => S::Steering {
+                reason: reason.clone(),
+            },
+            (S::Ready | S::Steering { .. }, E::Started) => S::Executing,
+            (S::Executing, E::Succeeded) => S::Done,
+            (S::Executing, E::Failed { reason }) => S::Failed {
+                reason: reason.clone(),
+            },
+            _ => {
+                return Err(MergeButtonTransitionError {
+                    from: self.clone(),
+                    event,
+                });
+            }
+        };
+        Ok(next)
+    }
+
+    /// Returns true if the merge button can be pressed.
+    ///
+    /// Failed merges stay pressable so they can be retried.
+    pub fn is_active(&self) -> bool {
+        matches!(self, Self::Ready | Self::Failed { .. })
+    }
+
+    /// Returns why the button is not pressable, or `None` if it is active.
+    pub fn blocked_reason(&self) -> Option<String> {
+        match self {
+            Self::Ready | Self::Failed { .. } => None,
+            Self::Idle { reason } | Self::Steering { reason } => Some(reason.clone()),
+            Self::Executing => Some("Merge already in progress".to_string()),
+            Self::Done => Some("Already merged".to_string()),
+        }
+    }
+}
+
+/// Heuristics for requesting user steering before a worktree merge.
+///
+/// Conflicts always require steering; these add optional size and path
+/// checks on top.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MergeSteeringConfig {
+    /// Ask for steering when the branch changes more than this many lines.
+    pub max_diff_lines: Option<usize>,
+
+    /// Path prefixes that require steering when touched (e.g. `.github/`,
+    /// `Cargo.lock`).
+    pub protected_paths: Vec<String>,
 }
 
 /// Decision about whether a merge needs user steering.
@@ -107,7 +227,11 @@ pub struct SteeringDecision {
     /// Whether user input is needed.
     pub needs_input: bool,
     /// Reason for needing input (or empty if not needed).
+    ///
+    /// When several heuristics fire, their reasons are joined with `"; "`.
     pub reason: String,
+    /// Individual reasons, one per heuristic that fired.
+    pub reasons: Vec<String>,
     /// Options for the user to choose from.
     pub options: Vec<MergeOption>,
 }
@@ -170,6 +294,9 @@ pub struct MergeEntry {
 
     /// Discard reason if discarded.
     pub discard_reason: Option<String>,
+
+    /// Loop IDs that must merge before this one.
+    pub depends_on: Vec<String>,
 }
 
 /// Errors that can occur during merge queue operations.
@@ -194,6 +321,14 @@ pub enum MergeQueueError {
     /// Platform not supported.
     #[error("File locking not supported on this platform")]
     UnsupportedPlatform,
+
+    /// Queued loops depend on each other in a cycle.
+    #[error("Dependency cycle in merge queue: {}", .0.join(" -> "))]
+    DependencyCycle(Vec<String>),
+
+    /// A git command failed unexpectedly.
+    #[error("git {0} failed: {1}")]
+    Git(String, String),
 }
 
 /// Merge queue for tracking parallel loop merges.
@@ -203,6 +338,9 @@ pub enum MergeQueueError {
 pub struct MergeQueue {
     /// Path to the merge queue file.
     queue_path: PathBuf,
+
+    /// Notification hooks, called in registration order.
+    hooks: Vec<MergeEventHook>,
 }
 
 impl MergeQueue {
@@ -213,9 +351,19 @@ impl MergeQueue {
     pub fn new(workspace_root: impl AsRef<Path>) -> Self {
         Self {
             queue_path: workspace_root.as_ref().join(Self::QUEUE_FILE),
+            hooks: Vec::new(),
         }
     }
 
+    /// Registers a hook called with every event this queue records.
+    ///
+    /// Hooks run after the event has been durably appended, so rejected
+    /// transitions never notify. Use them to forward state changes to
+    /// webhooks or chat integrations.
+    pub fn register_hook(&mut self, hook: impl Fn(&MergeEvent) + Send + Sync + 'static) {
+        self.hooks.push(Arc::new(hook));
+    }
+
     /// Enqueues a completed loop for merging.
     ///
     /// # Arguments
@@ -223,11 +371,25 @@ impl MergeQueue {
     /// * `loop_id` - The loop identifier
     /// * `prompt` - The prompt that was executed
     pub fn enqueue(&self, loop_id: &str, prompt: &str) -> Result<(), MergeQueueError> {
+        self.enqueue_with_dependencies(loop_id, prompt, &[])
+    }
+
+    /// Enqueues a completed loop that must merge after the given loops.
+    ///
+    /// See [`MergeQueue::topological_order`] for how dependencies affect
+    /// merge order.
+    pub fn enqueue_with_dependencies(
+        &self,
+        loop_id: &str,
+        prompt: &str,
+        depends_on: &[&str],
+    ) -> Result<(), MergeQueueError> {
         let event = MergeEvent {
             ts: Utc::now(),
             loop_id: loop_id.to_string(),
             event: MergeEventType::Queued {
                 prompt: prompt.to_string(),
+                depends_on: depends_on.iter().map(|id| (*id).to_string()).collect(),
             },
         };
         self.append_event(&event)
@@ -357,10 +519,35 @@ impl MergeQueue {
 
     /// Gets the next pending loop ready for merge (FIFO order).
     ///
-    /// Returns the oldest loop in `Queued` state.
+    /// Returns the oldest loop in `Queued` state whose dependencies have all
+    /// been `Merged`. Dependencies on loops not in the queue are ignored. A
+    /// dependency that was discarded (or needs review) never reaches `Merged`,
+    /// so its dependents stay held back until they are discarded themselves.
+    ///
+    /// Loops caught in a dependency cycle are never ready and are skipped, so
+    /// a cycle elsewhere in the queue doesn't block unrelated loops.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MergeQueueError::DependencyCycle`] if the dependencies of the
+    /// selected loop cannot be ordered.
     pub fn next_pending(&self) -> Result<Option<MergeEntry>, MergeQueueError> {
         let entries = self.list()?;
-        Ok(entries.into_iter().find(|e| e.state == MergeState::Queued))
+        let is_satisfied = |dep: &String| {
+            entries
+                .iter()
+                .find(|e| e.loop_id == *dep)
+                .is_none_or(|e| e.state == MergeState::Merged)
+        };
+
+        let Some(next) = entries.iter().find(|entry| {
+            entry.state == MergeState::Queued && entry.depends_on.iter().all(is_satisfied)
+        }) else {
+            return Ok(None);
+        };
+
+        order_by_dependencies(dependency_closure(&entries, &next.loop_id))?;
+        Ok(Some(next.clone()))
     }
 
     /// Gets the entry for a specific loop.
@@ -383,6 +570,47 @@ impl MergeQueue {
         Ok(entries.into_iter().filter(|e| e.state == state).collect())
     }
 
+    /// Returns all entries ordered so each loop follows the loops it depends on.
+    ///
+    /// Among entries whose dependencies are satisfied, queue (FIFO) order is
+    /// kept. Dependencies on loops not in the queue are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MergeQueueError::DependencyCycle`] with the loops forming the
+    /// cycle if the dependencies cannot be ordered.
+    pub fn topological_order(&self) -> Result<Vec<MergeEntry>, MergeQueueError> {
+        order_by_dependencies(self.list()?)
+    }
+
+    /// Reports which pairs of queued loops would conflict if both were merged.
+    ///
+    /// Runs `git merge-tree` between the `ralph/<loop_id>` branches of each pair
+    /// of `Queued` entries without touching any worktree. Only conflicting pairs
+    /// are returned, each with the paths that conflict.
+    pub fn detect_conflicts(
+        &self,
+        workspace_root: &Path,
+    ) -> Result<Vec<(MergeEntry, MergeEntry, Vec<PathBuf>)>, MergeQueueError> {
+        let pending = self.list_by_state(MergeState::Queued)?;
+        let mut conflicts = Vec::new();
+
+        for (i, first) in pending.iter().enumerate() {
+            for second in &pending[i + 1..] {
+                let paths = conflicting_paths(
+                    workspace_root,
+                    &format!("ralph/{}", first.loop_id),
+                    &format!("ralph/{}", second.loop_id),
+                )?;
+                if !paths.is_empty() {
+                    conflicts.push((first.clone(), second.clone(), paths));
+                }
+            }
+        }
+
+        Ok(conflicts)
+    }
+
     /// Reads all events from the queue file.
     fn read_all_events(&self) -> Result<Vec<MergeEvent>, MergeQueueError> {
         if !self.queue_path.exists() {
@@ -428,11 +656,13 @@ impl MergeQueue {
                     merge_commit: None,
                     failure_reason: None,
                     discard_reason: None,
+                    depends_on: Vec::new(),
                 });
 
             match &event.event {
-                MergeEventType::Queued { prompt } => {
+                MergeEventType::Queued { prompt, depends_on } => {
                     entry.prompt = prompt.clone();
+                    entry.depends_on = depends_on.clone();
                     entry.state = MergeState::Queued;
                     entry.queued_at = event.ts;
                 }
@@ -457,7 +687,7 @@ impl MergeQueue {
 
         // Sort by queued_at to maintain FIFO order
         let mut entries: Vec<_> = loop_states.into_values().collect();
-        entries.sort_by(|a, b| a.queued_at.cmp(&b.queued_at));
+        entries.sort_by_key(|e| e.queued_at);
         entries
     }
 
@@ -474,7 +704,12 @@ impl MergeQueue {
 
             file.sync_all()?;
             Ok(())
-        })
+        })?;
+
+        for hook in &self.hooks {
+            hook(event);
+        }
+        Ok(())
     }
 
     /// Executes an operation with a shared (read) lock on the queue file.
@@ -561,22 +796,30 @@ impl MergeQueue {
 
 /// Get the merge button state for a loop.
 ///
-/// Determines whether the merge button should be active or blocked based on:
-/// - Whether the primary loop is running
-/// - Whether this loop is already being merged
+/// Derives the state from the merge queue and the primary loop lock:
+/// - Merging and merged entries map to `Executing` and `Done`
+/// - A running primary loop keeps the button `Idle`, even for entries that
+///   need review, so a retry can't be offered while the primary holds the lock
+/// - Needs-review entries map to `Failed`
+/// - Otherwise the button is `Ready`
+///
+/// `Steering` is never derived here; it is entered via
+/// [`MergeButtonState::transition`] once steering heuristics fire.
 pub fn merge_button_state(
     workspace: &Path,
     loop_id: &str,
 ) -> Result<MergeButtonState, MergeQueueError> {
     let queue = MergeQueue::new(workspace);
 
-    // Check if this loop is already being merged
-    if let Some(entry) = queue.get_entry(loop_id)?
-        && entry.state == MergeState::Merging
-    {
-        return Ok(MergeButtonState::Blocked {
-            reason: "Merge already in progress".to_string(),
-        });
+    let entry = queue.get_entry(loop_id)?;
+
+    // In-flight and finished merges take precedence over the primary loop
+    if let Some(entry) = &entry {
+        match entry.state {
+            MergeState::Merging => return Ok(MergeButtonState::Executing),
+            MergeState::Merged => return Ok(MergeButtonState::Done),
+            MergeState::NeedsReview | MergeState::Queued | MergeState::Discarded => {}
+        }
     }
 
     // Check if primary loop is running by checking:
@@ -585,13 +828,23 @@ pub fn merge_button_state(
     if let Ok(Some(metadata)) = LoopLock::read_existing(workspace) {
         // Check if the PID is still running
         if is_pid_alive(metadata.pid) {
-            return Ok(MergeButtonState::Blocked {
+            return Ok(MergeButtonState::Idle {
                 reason: format!("primary loop running: {}", metadata.prompt),
             });
         }
     }
 
-    Ok(MergeButtonState::Active)
+    if let Some(entry) = entry
+        && entry.state == MergeState::NeedsReview
+    {
+        return Ok(MergeButtonState::Failed {
+            reason: entry
+                .failure_reason
+                .unwrap_or_else(|| "Merge needs review".to_string()),
+        });
+    }
+
+    Ok(MergeButtonState::Ready)
 }
 
 /// Check if a process with the given PID is still running.
@@ -663,6 +916,116 @@ pub fn smart_merge_summary(workspace: &Path, loop_id: &str) -> Result<String, Me
     Ok(summary)
 }
 
+/// Orders entries so each follows the entries it depends on, keeping FIFO
+/// order among entries whose dependencies are satisfied. Dependencies on loops
+/// not in `remaining` are ignored.
+fn order_by_dependencies(
+    mut remaining: Vec<MergeEntry>,
+) -> Result<Vec<MergeEntry>, MergeQueueError> {
+    let known: std::collections::HashSet<String> =
+        remaining.iter().map(|e| e.loop_id.clone()).collect();
+    let mut placed = std::collections::HashSet::new();
+    let mut ordered = Vec::with_capacity(remaining.len());
+
+    while !remaining.is_empty() {
+        let Some(index) = remaining.iter().position(|entry| {
+            entry
+                .depends_on
+                .iter()
+                .all(|dep| placed.contains(dep) || !known.contains(dep))
+        }) else {
+            return Err(MergeQueueError::DependencyCycle(find_cycle(&remaining)));
+        };
+        let entry = remaining.remove(index);
+        placed.insert(entry.loop_id.clone());
+        ordered.push(entry);
+    }
+
+    Ok(ordered)
+}
+
+/// Returns the entry for `loop_id` and every entry it transitively depends
+/// on, in queue order.
+fn dependency_closure(entries: &[MergeEntry], loop_id: &str) -> Vec<MergeEntry> {
+    let mut included = std::collections::HashSet::new();
+    let mut pending = vec![loop_id.to_string()];
+
+    while let Some(id) = pending.pop() {
+        if !included.insert(id.clone()) {
+            continue;
+        }
+        if let Some(entry) = entries.iter().find(|e| e.loop_id == id) {
+            pending.extend(entry.depends_on.iter().cloned());
+        }
+    }
+
+    entries
+        .iter()
+        .filter(|e| included.contains(&e.loop_id))
+        .cloned()
+        .collect()
+}
+
+/// Walks dependencies from the first blocked entry until a loop repeats,
+/// returning the cycle with its starting loop repeated at the end.
+fn find_cycle(blocked: &[MergeEntry]) -> Vec<String> {
+    let mut path: Vec<String> = Vec::new();
+    let mut current = blocked.first();
+
+    while let Some(entry) = current {
+        if let Some(start) = path.iter().position(|id| *id == entry.loop_id) {
+            let mut cycle = path.split_off(start);
+            cycle.push(entry.loop_id.clone());
+            return cycle;
+        }
+        path.push(entry.loop_id.clone());
+        // Every blocked entry has at least one blocked dependency
+        current = entry
+            .depends_on
+            .iter()
+            .find_map(|dep| blocked.iter().find(|e| e.loop_id == *dep));
+    }
+
+    path
+}
+
+/// Returns the paths that conflict when merging two branches.
+///
+/// Uses `git merge-tree --write-tree`, which exits 0 for a clean merge and 1
+/// when there are conflicts; with `--name-only` the conflicted paths follow the
+/// tree id on stdout.
+fn conflicting_paths(
+    workspace: &Path,
+    ours: &str,
+    theirs: &str,
+) -> Result<Vec<PathBuf>, MergeQueueError> {
+    let output = Command::new("git")
+        .args([
+            "merge-tree",
+            "--write-tree",
+            "--name-only",
+            "--no-messages",
+            ours,
+            theirs,
+        ])
+        .current_dir(workspace)
+        .output()?;
+
+    match output.status.code() {
+        Some(0) => Ok(Vec::new()),
+        Some(1) => Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .skip(1)
+            .filter(|line| !line.trim().is_empty())
+            .map(PathBuf::from)
+            .collect()),
+        _ => Err(MergeQueueError::Git(
+            "merge-tree".to_string(),
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        )),
+    }
+}
+
 /// Extract summary from a git log --oneline line (removes commit hash prefix).
 fn extract_summary_from_line(line: &str) -> String {
     // Format is "abc1234 commit message"
@@ -674,11 +1037,29 @@ fn extract_summary_from_line(line: &str) -> String {
 }
 
 /// Check if a merge needs user steering (e.g., due to conflicts).
+///
+/// Equivalent to [`merge_needs_steering_with`] using the default heuristics,
+/// which only consider conflicts.
 pub fn merge_needs_steering(
     workspace: &Path,
     loop_id: &str,
+) -> Result<SteeringDecision, MergeQueueError> {
+    merge_needs_steering_with(workspace, loop_id, &MergeSteeringConfig::default())
+}
+
+/// Check if a merge needs user steering using the given heuristics.
+///
+/// Steering is requested when any of these hold:
+/// - the branch conflicts with `main`
+/// - the branch changes more than `max_diff_lines` lines
+/// - the branch touches a path under one of `protected_paths`
+pub fn merge_needs_steering_with(
+    workspace: &Path,
+    loop_id: &str,
+    config: &MergeSteeringConfig,
 ) -> Result<SteeringDecision, MergeQueueError> {
     let branch_name = format!("ralph/{}", loop_id);
+    let mut reasons = Vec::new();
 
     // Check for potential conflicts by doing a dry-run merge
     let output = Command::new("git")
@@ -700,45 +1081,126 @@ pub fn merge_needs_steering(
         let files = String::from_utf8_lossy(&diff_output.stdout);
         let file_list: Vec<&str> = files.lines().take(3).collect();
 
-        let reason = if file_list.is_empty() {
+        reasons.push(if file_list.is_empty() {
             "Potential conflict detected".to_string()
         } else {
             format!("Files modified on both branches: {}", file_list.join(", "))
-        };
+        });
+    }
 
-        Ok(SteeringDecision {
-            needs_input: true,
-            reason,
-            options: vec![
-                MergeOption {
-                    label: "Use ours (main)".to_string(),
-                },
-                MergeOption {
-                    label: "Use theirs (branch)".to_string(),
-                },
-                MergeOption {
-                    label: "Manual resolution".to_string(),
-                },
-            ],
-        })
-    } else {
-        Ok(SteeringDecision {
+    if config.max_diff_lines.is_some() || !config.protected_paths.is_empty() {
+        // Only the branch's own changes since it forked from main
+        let numstat = Command::new("git")
+            .args(["diff", "--numstat", &format!("main...{}", branch_name)])
+            .current_dir(workspace)
+            .output()?;
+        let numstat = String::from_utf8_lossy(&numstat.stdout);
+
+        let mut changed_lines = 0usize;
+        let mut protected = Vec::new();
+        for line in numstat.lines() {
+            let mut fields = line.splitn(3, '\t');
+            let added = fields.next().and_then(|n| n.parse::<usize>().ok());
+            let removed = fields.next().and_then(|n| n.parse::<usize>().ok());
+            let Some(path) = fields.next() else {
+                continue;
+            };
+            // Binary files report "-" for both counts
+            changed_lines += added.unwrap_or(0) + removed.unwrap_or(0);
+            if config
+                .protected_paths
+                .iter()
+                .any(|prefix| path.starts_with(prefix.as_str()))
+            {
+                protected.push(path.to_string());
+            }
+        }
+
+        if let Some(max) = config.max_diff_lines
+            && changed_lines > max
+        {
+            reasons.push(format!(
+                "Diff exceeds {max} lines ({changed_lines} lines changed)"
+            ));
+        }
+
+        if !protected.is_empty() {
+            reasons.push(format!(
+                "Touches protected paths: {}",
+                protected.into_iter().take(3).collect::<Vec<_>>().join(", ")
+            ));
+        }
+    }
+
+    if reasons.is_empty() {
+        return Ok(SteeringDecision {
             needs_input: false,
             reason: String::new(),
+            reasons,
             options: vec![],
-        })
+        });
     }
+
+    let options = if has_conflicts {
+        vec![
+            MergeOption {
+                label: "Use ours (main)".to_string(),
+            },
+            MergeOption {
+                label: "Use theirs (branch)".to_string(),
+            },
+            MergeOption {
+                label: "Manual resolution".to_string(),
+            },
+        ]
+    } else {
+        vec![
+            MergeOption {
+                label: "Merge as-is".to_string(),
+            },
+            MergeOption {
+                label: "Manual review".to_string(),
+            },
+        ]
+    };
+
+    Ok(SteeringDecision {
+        needs_input: true,
+        reason: reasons.join("; "),
+        reasons,
+        options,
+    })
 }
 
 /// Generate an execution summary for a completed merge.
 ///
 /// Describes what was merged including commit count and key changes.
+///
+/// When the loop was queued with dependencies, the summary follows the
+/// dependency order of the loop and the loops it (transitively) depends on:
+/// commits and files already contributed by the branches it depends on are
+/// not counted again. Cycles among unrelated loops in the queue are ignored.
 pub fn merge_execution_summary(workspace: &Path, loop_id: &str) -> Result<String, MergeQueueError> {
     let branch_name = format!("ralph/{}", loop_id);
 
+    // Dependencies merge first, so exclude their branches from this loop's stats
+    let entries = MergeQueue::new(workspace).list()?;
+    let order = order_by_dependencies(dependency_closure(&entries, loop_id))?;
+    let depends_on = order
+        .iter()
+        .find(|entry| entry.loop_id == loop_id)
+        .map(|entry| entry.depends_on.clone())
+        .unwrap_or_default();
+    let dependency_branches: Vec<String> = order
+        .iter()
+        .filter(|entry| depends_on.contains(&entry.loop_id))
+        .map(|entry| format!("ralph/{}", entry.loop_id))
+        .collect();
+
     // Get commit count
     let count_output = Command::new("git")
-        .args(["rev-list", "--count", &format!("main..{}", branch_name)])
+        .args(["rev-list", "--count", &branch_name, "--not", "main"])
+        .args(&dependency_branches)
         .current_dir(workspace)
         .output()?;
 
@@ -748,13 +1210,35 @@ pub fn merge_execution_summary(workspace: &Path, loop_id: &str) -> Result<String
         .unwrap_or(0);
 
     // Get file count
-    let files_output = Command::new("git")
-        .args(["diff", "--name-only", "main", &branch_name])
-        .current_dir(workspace)
-        .output()?;
+    let file_count = if dependency_branches.is_empty() {
+        let files_output = Command::new("git")
+            .args(["diff", "--name-only", "main", &branch_name])
+            .current_dir(workspace)
+            .output()?;
 
-    let files = String::from_utf8_lossy(&files_output.stdout);
-    let file_count = files.lines().count();
+        String::from_utf8_lossy(&files_output.stdout)
+            .lines()
+            .count()
+    } else {
+        let files_output = Command::new("git")
+            .args([
+                "log",
+                "--format=",
+                "--name-only",
+                &branch_name,
+                "--not",
+                "main",
+            ])
+            .args(&dependency_branches)
+            .current_dir(workspace)
+            .output()?;
+
+        String::from_utf8_lossy(&files_output.stdout)
+            .lines()
+            .filter(|line| !line.is_empty())
+            .collect::<std::collections::HashSet<_>>()
+            .len()
+    };
 
     // Get the most descriptive commit message
     let log_output = Command::new("git")
@@ -784,6 +1268,219 @@ mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    /// Creates `ralph/<loop_id>` off `main` with one commit writing `file`.
+    fn branch_with_file(dir: &Path, loop_id: &str, file: &str, content: &str) {
+        git(
+            dir,
+            &["checkout", "-q", "-b", &format!("ralph/{loop_id}"), "main"],
+        );
+        fs::write(dir.join(file), content).unwrap();
+        git(dir, &["add", file]);
+        git(dir, &["commit", "-q", "-m", loop_id]);
+        git(dir, &["checkout", "-q", "main"]);
+    }
+
+    #[test]
+    fn test_detect_conflicts_reports_overlapping_branches() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        git(dir, &["init", "-q", "-b", "main"]);
+        fs::write(dir.join("shared.txt"), "base\n").unwrap();
+        git(dir, &["add", "shared.txt"]);
+        git(dir, &["commit", "-q", "-m", "init"]);
+
+        branch_with_file(dir, "loop-a", "shared.txt", "from a\n");
+        branch_with_file(dir, "loop-b", "shared.txt", "from b\n");
+        branch_with_file(dir, "loop-c", "other.txt", "from c\n");
+
+        let queue = MergeQueue::new(dir);
+        queue.enqueue("loop-a", "a").unwrap();
+        queue.enqueue("loop-b", "b").unwrap();
+        queue.enqueue("loop-c", "c").unwrap();
+
+        let conflicts = queue.detect_conflicts(dir).unwrap();
+        assert_eq!(conflicts.len(), 1);
+        let (first, second, paths) = &conflicts[0];
+        assert_eq!(first.loop_id, "loop-a");
+        assert_eq!(second.loop_id, "loop-b");
+        assert_eq!(paths, &vec![PathBuf::from("shared.txt")]);
+    }
+
+    #[test]
+    fn test_topological_order_linear_chain() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = MergeQueue::new(temp_dir.path());
+
+        // Queued out of order: C depends on B, B depends on A
+        queue
+            .enqueue_with_dependencies("loop-c", "c", &["loop-b"])
+            .unwrap();
+        queue
+            .enqueue_with_dependencies("loop-b", "b", &["loop-a"])
+            .unwrap();
+        queue.enqueue("loop-a", "a").unwrap();
+
+        let order: Vec<_> = queue
+            .topological_order()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.loop_id)
+            .collect();
+        assert_eq!(order, vec!["loop-a", "loop-b", "loop-c"]);
+        assert_eq!(queue.next_pending().unwrap().unwrap().loop_id, "loop-a");
+    }
+
+    #[test]
+    fn test_next_pending_waits_for_dependencies_to_merge() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = MergeQueue::new(temp_dir.path());
+
+        queue.enqueue("loop-a", "a").unwrap();
+        queue
+            .enqueue_with_dependencies("loop-b", "b", &["loop-a"])
+            .unwrap();
+        queue
+            .enqueue_with_dependencies("loop-c", "c", &["loop-b", "loop-elsewhere"])
+            .unwrap();
+
+        // While A is merging, B must not be handed out
+        queue.mark_merging("loop-a", 1).unwrap();
+        assert!(queue.next_pending().unwrap().is_none());
+
+        queue.mark_merged("loop-a", "abc123").unwrap();
+        assert_eq!(queue.next_pending().unwrap().unwrap().loop_id, "loop-b");
+
+        // Dependencies outside the queue don't hold C back
+        queue.mark_merging("loop-b", 1).unwrap();
+        queue.mark_merged("loop-b", "def456").unwrap();
+        assert_eq!(queue.next_pending().unwrap().unwrap().loop_id, "loop-c");
+    }
+
+    #[test]
+    fn test_next_pending_holds_back_dependents_of_discarded_loops() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = MergeQueue::new(temp_dir.path());
+
+        queue.enqueue("loop-a", "a").unwrap();
+        queue
+            .enqueue_with_dependencies("loop-b", "b", &["loop-a"])
+            .unwrap();
+        queue.enqueue("loop-c", "c").unwrap();
+
+        queue.discard("loop-a", Some("abandoned")).unwrap();
+        assert_eq!(queue.next_pending().unwrap().unwrap().loop_id, "loop-c");
+
+        queue.mark_merging("loop-c", 1).unwrap();
+        queue.mark_merged("loop-c", "abc123").unwrap();
+        assert!(queue.next_pending().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_next_pending_ignores_unrelated_cycle() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = MergeQueue::new(temp_dir.path());
+
+        queue
+            .enqueue_with_dependencies("loop-a", "a", &["loop-b"])
+            .unwrap();
+        queue
+            .enqueue_with_dependencies("loop-b", "b", &["loop-a"])
+            .unwrap();
+        queue.enqueue("loop-c", "c").unwrap();
+
+        assert!(queue.topological_order().is_err());
+        assert_eq!(queue.next_pending().unwrap().unwrap().loop_id, "loop-c");
+    }
+
+    #[test]
+    fn test_topological_order_reports_cycle() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = MergeQueue::new(temp_dir.path());
+
+        queue.enqueue("loop-base", "base").unwrap();
+        queue
+            .enqueue_with_dependencies("loop-a", "a", &["loop-b"])
+            .unwrap();
+        queue
+            .enqueue_with_dependencies("loop-b", "b", &["loop-a", "loop-base"])
+            .unwrap();
+
+        let err = queue.topological_order().unwrap_err();
+        match &err {
+            MergeQueueError::DependencyCycle(cycle) => {
+                assert_eq!(cycle, &vec!["loop-a", "loop-b", "loop-a"]);
+            }
+            other => panic!("expected DependencyCycle, got {other:?}"),
+        }
+        assert!(err.to_string().contains("loop-a -> loop-b -> loop-a"));
+    }
+
+    #[test]
+    fn test_execution_summary_excludes_dependency_commits() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        git(dir, &["init", "-q", "-b", "main"]);
+        git(dir, &["commit", "-q", "--allow-empty", "-m", "init"]);
+
+        branch_with_file(dir, "loop-a", "api.rs", "pub fn api() {}\n");
+        // B builds on A's branch
+        git(
+            dir,
+            &["checkout", "-q", "-b", "ralph/loop-b", "ralph/loop-a"],
+        );
+        fs::write(dir.join("client.rs"), "fn main() { api() }\n").unwrap();
+        git(dir, &["add", "client.rs"]);
+        git(dir, &["commit", "-q", "-m", "use api"]);
+        git(dir, &["checkout", "-q", "main"]);
+
+        let queue = MergeQueue::new(dir);
+        queue.enqueue("loop-a", "a").unwrap();
+        queue
+            .enqueue_with_dependencies("loop-b", "b", &["loop-a"])
+            .unwrap();
+
+        let summary = merge_execution_summary(dir, "loop-b").unwrap();
+        assert_eq!(summary, "1 commit, 1 file changed: use api");
+
+        // An unrelated cycle elsewhere in the queue doesn't break the summary
+        queue
+            .enqueue_with_dependencies("loop-x", "x", &["loop-y"])
+            .unwrap();
+        queue
+            .enqueue_with_dependencies("loop-y", "y", &["loop-x"])
+            .unwrap();
+        let summary = merge_execution_summary(dir, "loop-b").unwrap();
+        assert_eq!(summary, "1 commit, 1 file changed: use api");
+    }
+
+    #[test]
+    fn test_detect_conflicts_none_for_disjoint_branches() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        git(dir, &["init", "-q", "-b", "main"]);
+        git(dir, &["commit", "-q", "--allow-empty", "-m", "init"]);
+
+        branch_with_file(dir, "loop-a", "a.txt", "a\n");
+        branch_with_file(dir, "loop-b", "b.txt", "b\n");
+
+        let queue = MergeQueue::new(dir);
+        queue.enqueue("loop-a", "a").unwrap();
+        queue.enqueue("loop-b", "b").unwrap();
+
+        assert!(queue.detect_conflicts(dir).unwrap().is_empty());
+    }
+
     #[test]
     fn test_enqueue() {
         let temp_dir = TempDir::new().unwrap();
@@ -798,6 +1495,161 @@ mod tests {
         assert_eq!(entries[0].state, MergeState::Queued);
     }
 
+    #[test]
+    fn test_hooks_notified_in_order() {
+        use std::sync::Mutex;
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut queue = MergeQueue::new(temp_dir.path());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        queue.register_hook(move |event| {
+            sink.lock()
+                .unwrap()
+                .push((event.loop_id.clone(), event.event.clone()));
+        });
+
+        queue.enqueue("loop-hook", "prompt").unwrap();
+        queue.mark_merging("loop-hook", 42).unwrap();
+        queue.mark_needs_review("loop-hook", "conflicts").unwrap();
+        queue.mark_merging("loop-hook", 43).unwrap();
+        queue.mark_merged("loop-hook", "abc123").unwrap();
+        // Rejected transition: no notification.
+        assert!(queue.discard("loop-hook", None).is_err());
+
+        let seen = seen.lock().unwrap();
+        let events: Vec<_> = seen.iter().map(|(_, event)| event.clone()).collect();
+        assert_eq!(
+            events,
+            vec![
+                MergeEventType::Queued {
+                    prompt: "prompt".to_string(),
+                    depends_on: vec![],
+                },
+                MergeEventType::Merging { pid: 42 },
+                MergeEventType::NeedsReview {
+                    reason: "conflicts".to_string()
+                },
+                MergeEventType::Merging { pid: 43 },
+                MergeEventType::Merged {
+                    commit: "abc123".to_string()
+                },
+            ]
+        );
+        assert!(seen.iter().all(|(loop_id, _)| loop_id == "loop-hook"));
+    }
+
+    #[test]
+    fn test_merge_button_valid_transitions() {
+        let idle = MergeButtonState::Idle {
+            reason: "primary loop running".to_string(),
+        };
+        let ready = idle.transition(MergeButtonEvent::Unblocked).unwrap();
+        assert_eq!(ready, MergeButtonState::Ready);
+
+        assert_eq!(
+            ready
+                .transition(MergeButtonEvent::Blocked {
+                    reason: "busy".to_string()
+                })
+                .unwrap(),
+            MergeButtonState::Idle {
+                reason: "busy".to_string()
+            }
+        );
+
+        let steering = ready
+            .transition(MergeButtonEvent::SteeringNeeded {
+                reason: "conflicts".to_string(),
+            })
+            .unwrap();
+        assert_eq!(
+            steering,
+            MergeButtonState::Steering {
+                reason: "conflicts".to_string()
+            }
+        );
+        assert_eq!(
+            steering
+                .transition(MergeButtonEvent::SteeringResolved)
+                .unwrap(),
+            MergeButtonState::Ready
+        );
+
+        let executing = steering.transition(MergeButtonEvent::Started).unwrap();
+        assert_eq!(executing, MergeButtonState::Executing);
+        assert_eq!(
+            ready.transition(MergeButtonEvent::Started).unwrap(),
+            MergeButtonState::Executing
+        );
+        assert_eq!(
+            executing.transition(MergeButtonEvent::Succeeded).unwrap(),
+            MergeButtonState::Done
+        );
+
+        let failed = executing
+            .transition(MergeButtonEvent::Failed {
+                reason: "tests failed".to_string(),
+            })
+            .unwrap();
+        assert_eq!(
+            failed,
+            MergeButtonState::Failed {
+                reason: "tests failed".to_string()
+            }
+        );
+        assert_eq!(
+            failed.transition(MergeButtonEvent::Retry).unwrap(),
+            MergeButtonState::Ready
+        );
+    }
+
+    #[test]
+    fn test_merge_button_rejects_illegal_transition() {
+        let idle = MergeButtonState::Idle {
+            reason: "primary loop running".to_string(),
+        };
+        let err = idle.transition(MergeButtonEvent::Started).unwrap_err();
+        assert_eq!(err.from, idle);
+        assert_eq!(err.event, MergeButtonEvent::Started);
+
+        assert!(
+            MergeButtonState::Done
+                .transition(MergeButtonEvent::Retry)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_merge_button_state_derived_from_queue() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = MergeQueue::new(temp_dir.path());
+        queue.enqueue("loop-btn", "prompt").unwrap();
+        assert_eq!(
+            merge_button_state(temp_dir.path(), "loop-btn").unwrap(),
+            MergeButtonState::Ready
+        );
+
+        queue.mark_merging("loop-btn", 1).unwrap();
+        let state = merge_button_state(temp_dir.path(), "loop-btn").unwrap();
+        assert_eq!(state, MergeButtonState::Executing);
+        assert!(!state.is_active());
+        assert_eq!(
+            state.blocked_reason().as_deref(),
+            Some("Merge already in progress")
+        );
+
+        queue.mark_needs_review("loop-btn", "conflicts").unwrap();
+        let state = merge_button_state(temp_dir.path(), "loop-btn").unwrap();
+        assert_eq!(
+            state,
+            MergeButtonState::Failed {
+                reason: "conflicts".to_string()
+            }
+        );
+        assert!(state.is_active());
+    }
+
     #[test]
     fn test_full_merge_lifecycle() {
         let temp_dir = TempDir::new().unwrap();
@@ -1020,6 +1872,7 @@ mod tests {
             loop_id: "loop-test".to_string(),
             event: MergeEventType::Queued {
                 prompt: "test prompt".to_string(),
+                depends_on: vec![],
             },
         };
 
@@ -1028,7 +1881,7 @@ mod tests {
 
         assert_eq!(parsed.loop_id, event.loop_id);
         match parsed.event {
-            MergeEventType::Queued { prompt } => assert_eq!(prompt, "test prompt"),
+            MergeEventType::Queued { prompt, .. } => assert_eq!(prompt, "test prompt"),
             _ => panic!("Wrong event type"),
         }
     }
@@ -1,31 +1,55 @@
 //! Preflight checks for validating environment and configuration before running.
 
 use crate::config::ConfigWarning;
+use crate::event_loop::{BUILTIN_CONSUMED_TOPICS, BUILTIN_EMITTED_TOPICS};
+use crate::hat_registry::HatRegistry;
+use crate::hatless_ralph::{HatTopology, TopologyWarning};
+use crate::task_store::TaskStore;
 use crate::{RalphConfig, git_ops};
 use async_trait::async_trait;
-use serde::Serialize;
+use chrono::{DateTime, Utc};
+use ralph_proto::Topic;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
 use std::ffi::OsString;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::Stdio;
+use std::sync::Mutex;
 use std::time::Duration;
 
 /// Status of a preflight check.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum CheckStatus {
     Pass,
     Warn,
     Fail,
+    /// The check did not finish within its [`PreflightCheck::timeout`].
+    #[serde(rename = "timed_out")]
+    TimedOut,
+}
+
+impl CheckStatus {
+    /// Status icon used in markdown reports.
+    pub fn icon(self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "✅",
+            CheckStatus::Warn => "⚠️",
+            CheckStatus::Fail => "❌",
+            CheckStatus::TimedOut => "⏱️",
+        }
+    }
 }
 
 /// Result of a single preflight check.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CheckResult {
     pub name: String,
     pub label: String,
     pub status: CheckStatus,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
@@ -56,12 +80,48 @@ impl CheckResult {
             message: Some(message.into()),
         }
     }
+
+    pub fn timed_out(name: &str, timeout: Duration) -> Self {
+        Self {
+            name: name.to_string(),
+            label: "Timed out".to_string(),
+            status: CheckStatus::TimedOut,
+            message: Some(format!("Check did not finish within {timeout:?}")),
+        }
+    }
 }
 
 /// A single preflight check.
 #[async_trait]
 pub trait PreflightCheck: Send + Sync {
     fn name(&self) -> &'static str;
+
+    /// Key identifying the inputs this check depends on, for result caching.
+    ///
+    /// Checks that inspect mutable workspace state (git status, files on disk)
+    /// must return `None`, which is the default, so they always run.
+    fn cache_key(&self, _config: &RalphConfig) -> Option<String> {
+        None
+    }
+
+    /// Maximum time the check may run before it is reported as timed out.
+    ///
+    /// The check's future is dropped on timeout, so checks that spawn
+    /// subprocesses should use `tokio::process::Command` with
+    /// `kill_on_drop(true)` for the child to be killed. `None` (the default)
+    /// means no limit.
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Whether [`PreflightRunner::run_all`] includes this check.
+    ///
+    /// Opt-in checks (`false`) only run when requested by name, e.g.
+    /// `ralph preflight --check topology`.
+    fn enabled_by_default(&self) -> bool {
+        true
+    }
+
     async fn run(&self, config: &RalphConfig) -> CheckResult;
 }
 
@@ -71,6 +131,8 @@ pub struct PreflightReport {
     pub passed: bool,
     pub warnings: usize,
     pub failures: usize,
+    /// Checks that exceeded their timeout; counted separately from failures.
+    pub timed_out: usize,
     pub checks: Vec<CheckResult>,
 }
 
@@ -84,25 +146,164 @@ impl PreflightReport {
             .iter()
             .filter(|check| check.status == CheckStatus::Fail)
             .count();
-        let passed = failures == 0;
+        let timed_out = checks
+            .iter()
+            .filter(|check| check.status == CheckStatus::TimedOut)
+            .count();
+        let passed = failures == 0 && timed_out == 0;
 
         Self {
             passed,
             warnings,
             failures,
+            timed_out,
             checks,
         }
     }
+
+    /// Renders the report as markdown with a status icon per check.
+    ///
+    /// Check messages are rendered as indented lines under their check.
+    pub fn to_markdown(&self) -> String {
+        let verdict = if self.passed { "PASSED" } else { "FAILED" };
+        let mut out = format!(
+            "## Preflight: {verdict}\n\n{} check(s), {} warning(s), {} failure(s)",
+            self.checks.len(),
+            self.warnings,
+            self.failures
+        );
+        if self.timed_out > 0 {
+            out.push_str(&format!(", {} timed out", self.timed_out));
+        }
+        out.push_str("\n\n");
+
+        for check in &self.checks {
+            out.push_str(&format!(
+                "- {} **{}**: {}\n",
+                check.status.icon(),
+                check.name,
+                check.label
+            ));
+            if let Some(message) = &check.message {
+                for line in message.lines().filter(|line| !line.trim().is_empty()) {
+                    out.push_str(&format!("  {}\n", line.trim_end()));
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Renders the report as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Timeout for built-in checks that spawn subprocesses (backend `--version`,
+/// git status), so a hung tool can't stall preflight.
+const SUBPROCESS_CHECK_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Default location of the persisted preflight cache, relative to the workspace root.
+pub const PREFLIGHT_CACHE_FILE: &str = ".ralph/preflight-cache.json";
+
+/// How long persisted preflight results stay valid by default.
+pub const DEFAULT_PREFLIGHT_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// A cached check result and when it was stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedResult {
+    stored_at: DateTime<Utc>,
+    result: CheckResult,
+}
+
+/// Cache of successful check results keyed by check name and inputs.
+///
+/// With a `path`, entries are loaded from and written back to disk so results
+/// survive across process invocations (each `ralph run` is a new process).
+struct PreflightCache {
+    ttl: Duration,
+    path: Option<PathBuf>,
+    entries: Mutex<HashMap<String, CachedResult>>,
+}
+
+impl PreflightCache {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            path: None,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Loads a cache persisted at `path`; a missing or unreadable file starts empty.
+    fn persistent(path: PathBuf, ttl: Duration) -> Self {
+        let entries = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            ttl,
+            path: Some(path),
+            entries: Mutex::new(entries),
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<CheckResult> {
+        let entries = self.entries.lock().ok()?;
+        let cached = entries.get(key)?;
+        let age = (Utc::now() - cached.stored_at).to_std().ok()?;
+        (age < self.ttl).then(|| cached.result.clone())
+    }
+
+    fn store(&self, key: String, result: &CheckResult) {
+        if result.status != CheckStatus::Pass {
+            return;
+        }
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+        entries.insert(
+            key,
+            CachedResult {
+                stored_at: Utc::now(),
+                result: result.clone(),
+            },
+        );
+
+        if let Some(path) = &self.path
+            && let Err(e) = Self::write(path, &entries)
+        {
+            tracing::warn!(path = %path.display(), error = %e, "Failed to persist preflight cache");
+        }
+    }
+
+    /// Writes entries to a temporary sibling file and renames it into place.
+    fn write(path: &Path, entries: &HashMap<String, CachedResult>) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(entries).map_err(std::io::Error::other)?;
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, json)?;
+        std::fs::rename(&tmp_path, path)
+    }
 }
 
 /// Runs a set of preflight checks.
 pub struct PreflightRunner {
     checks: Vec<Box<dyn PreflightCheck>>,
+    cache: Option<PreflightCache>,
 }
 
 impl PreflightRunner {
     pub fn default_checks() -> Self {
         Self {
+            cache: None,
             checks: vec![
                 Box::new(ConfigValidCheck),
                 Box::new(BackendAvailableCheck),
@@ -111,16 +312,50 @@ impl PreflightRunner {
                 Box::new(PathsExistCheck),
                 Box::new(ToolsInPathCheck::default()),
                 Box::new(SpecCompletenessCheck),
+                Box::new(TopologyCheck),
             ],
         }
     }
 
+    /// Caches passing results of cacheable checks for `ttl`.
+    ///
+    /// Within the TTL, a check whose [`PreflightCheck::cache_key`] is unchanged
+    /// is skipped and its previous result reused. Warnings and failures are
+    /// never cached.
+    #[must_use]
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache = Some(PreflightCache::new(ttl));
+        self
+    }
+
+    /// Like [`with_cache_ttl`](Self::with_cache_ttl), but persists cached
+    /// results to `path` so they are reused by later runs.
+    #[must_use]
+    pub fn with_persistent_cache(mut self, path: impl Into<PathBuf>, ttl: Duration) -> Self {
+        self.cache = Some(PreflightCache::persistent(path.into(), ttl));
+        self
+    }
+
     pub fn check_names(&self) -> Vec<&str> {
         self.checks.iter().map(|check| check.name()).collect()
     }
 
+    /// Names of the checks [`run_all`](Self::run_all) would run.
+    pub fn default_check_names(&self) -> Vec<&str> {
+        self.checks
+            .iter()
+            .filter(|check| check.enabled_by_default())
+            .map(|check| check.name())
+            .collect()
+    }
+
+    /// Runs every check that is enabled by default.
     pub async fn run_all(&self, config: &RalphConfig) -> PreflightReport {
-        Self::run_checks(self.checks.iter(), config).await
+        let checks = self
+            .checks
+            .iter()
+            .filter(|check| check.enabled_by_default());
+        self.run_checks(checks, config).await
     }
 
     pub async fn run_selected(&self, config: &RalphConfig, names: &[String]) -> PreflightReport {
@@ -130,20 +365,48 @@ impl PreflightRunner {
             .iter()
             .filter(|check| requested.contains(&check.name().to_lowercase()));
 
-        Self::run_checks(checks, config).await
+        self.run_checks(checks, config).await
     }
 
-    async fn run_checks<'a, I>(checks: I, config: &RalphConfig) -> PreflightReport
+    async fn run_checks<'a, I>(&self, checks: I, config: &RalphConfig) -> PreflightReport
     where
         I: IntoIterator<Item = &'a Box<dyn PreflightCheck>>,
     {
         let mut results = Vec::new();
         for check in checks {
-            results.push(check.run(config).await);
+            results.push(self.run_check(check.as_ref(), config).await);
         }
 
         PreflightReport::from_results(results)
     }
+
+    async fn run_check(&self, check: &dyn PreflightCheck, config: &RalphConfig) -> CheckResult {
+        let Some((cache, key)) = self.cache.as_ref().and_then(|cache| {
+            let key = check.cache_key(config)?;
+            Some((cache, format!("{}:{key}", check.name())))
+        }) else {
+            return Self::run_with_timeout(check, config).await;
+        };
+
+        if let Some(result) = cache.get(&key) {
+            return result;
+        }
+
+        let result = Self::run_with_timeout(check, config).await;
+        cache.store(key, &result);
+        result
+    }
+
+    /// Runs `check`, giving up once its timeout (if any) elapses.
+    async fn run_with_timeout(check: &dyn PreflightCheck, config: &RalphConfig) -> CheckResult {
+        let Some(timeout) = check.timeout() else {
+            return check.run(config).await;
+        };
+
+        tokio::time::timeout(timeout, check.run(config))
+            .await
+            .unwrap_or_else(|_| CheckResult::timed_out(check.name(), timeout))
+    }
 }
 
 struct ConfigValidCheck;
@@ -181,13 +444,27 @@ impl PreflightCheck for BackendAvailableCheck {
         "backend"
     }
 
+    fn cache_key(&self, config: &RalphConfig) -> Option<String> {
+        Some(format!(
+            "{}|{:?}|{:?}|{:?}",
+            config.cli.backend.trim(),
+            config.cli.command,
+            config.get_agent_priority(),
+            env::var_os("PATH")
+        ))
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        Some(SUBPROCESS_CHECK_TIMEOUT)
+    }
+
     async fn run(&self, config: &RalphConfig) -> CheckResult {
         let backend = config.cli.backend.trim();
         if backend.eq_ignore_ascii_case("auto") {
-            return check_auto_backend(self.name(), config);
+            return check_auto_backend(self.name(), config).await;
         }
 
-        check_named_backend(self.name(), config, backend)
+        check_named_backend(self.name(), config, backend).await
     }
 }
 
@@ -199,6 +476,13 @@ impl PreflightCheck for TelegramTokenCheck {
         "telegram"
     }
 
+    fn cache_key(&self, config: &RalphConfig) -> Option<String> {
+        // Hash the token so it is never held in the cache key
+        let mut hasher = DefaultHasher::new();
+        config.robot.resolve_bot_token().hash(&mut hasher);
+        Some(format!("{}|{:x}", config.robot.enabled, hasher.finish()))
+    }
+
     async fn run(&self, config: &RalphConfig) -> CheckResult {
         if !config.robot.enabled {
             return CheckResult::pass(self.name(), "RObot disabled (skipping)");
@@ -229,34 +513,43 @@ impl PreflightCheck for GitCleanCheck {
         "git"
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        Some(SUBPROCESS_CHECK_TIMEOUT)
+    }
+
     async fn run(&self, config: &RalphConfig) -> CheckResult {
-        let root = &config.core.workspace_root;
-        if !is_git_workspace(root) {
+        let root = config.core.workspace_root.clone();
+        if !is_git_workspace(&root) {
             return CheckResult::pass(self.name(), "Not a git repository (skipping)");
         }
 
-        let branch = match git_ops::get_current_branch(root) {
-            Ok(branch) => branch,
-            Err(err) => {
-                return CheckResult::fail(
-                    self.name(),
-                    "Git repository unavailable",
-                    format!("{err}"),
-                );
-            }
-        };
+        // git_ops is blocking: run it off the runtime so the check's timeout can
+        // fire, and bound the git commands themselves so they get killed too
+        let name = self.name();
+        tokio::task::spawn_blocking(move || {
+            git_ops::with_git_timeout(SUBPROCESS_CHECK_TIMEOUT, || git_clean_result(name, &root))
+        })
+        .await
+        .unwrap_or_else(|err| CheckResult::fail(name, "Git check failed", format!("{err}")))
+    }
+}
 
-        match git_ops::is_working_tree_clean(root) {
-            Ok(true) => CheckResult::pass(self.name(), format!("Working tree clean ({branch})")),
-            Ok(false) => CheckResult::warn(
-                self.name(),
-                "Working tree has uncommitted changes",
-                "Commit or stash changes before running for clean diffs",
-            ),
-            Err(err) => {
-                CheckResult::fail(self.name(), "Unable to read git status", format!("{err}"))
-            }
+fn git_clean_result(name: &str, root: &Path) -> CheckResult {
+    let branch = match git_ops::get_current_branch(root) {
+        Ok(branch) => branch,
+        Err(err) => {
+            return CheckResult::fail(name, "Git repository unavailable", format!("{err}"));
         }
+    };
+
+    match git_ops::is_working_tree_clean(root) {
+        Ok(true) => CheckResult::pass(name, format!("Working tree clean ({branch})")),
+        Ok(false) => CheckResult::warn(
+            name,
+            "Working tree has uncommitted changes",
+            "Commit or stash changes before running for clean diffs",
+        ),
+        Err(err) => CheckResult::fail(name, "Unable to read git status", format!("{err}")),
     }
 }
 
@@ -335,6 +628,16 @@ impl PreflightCheck for ToolsInPathCheck {
         "tools"
     }
 
+    fn cache_key(&self, config: &RalphConfig) -> Option<String> {
+        Some(format!(
+            "{}|{:?}|{:?}|{:?}",
+            config.core.workspace_root.display(),
+            self.required,
+            self.optional,
+            env::var_os("PATH")
+        ))
+    }
+
     async fn run(&self, config: &RalphConfig) -> CheckResult {
         if !is_git_workspace(&config.core.workspace_root) {
             return CheckResult::pass(self.name(), "Not a git repository (skipping)");
@@ -457,6 +760,74 @@ impl PreflightCheck for SpecCompletenessCheck {
     }
 }
 
+/// Warns about hat topics that are never published or never consumed.
+///
+/// Builds the hat topology and reports [`TopologyWarning`]s, ignoring
+/// subscriptions satisfied by the loop's built-in emitters (including
+/// `event_loop.starting_event`) and publishes the loop consumes itself
+/// (including the completion promise). Ralph's `*` catch-all is not counted
+/// as a subscriber.
+///
+/// Opt-in: in hat mode Ralph coordinates by publishing hat triggers and
+/// absorbing anything hats publish, so most presets have "dangling" topics
+/// that still work. Run it with `--check topology` when designing a topology.
+///
+/// [`TopologyWarning`]: crate::hatless_ralph::TopologyWarning
+struct TopologyCheck;
+
+#[async_trait]
+impl PreflightCheck for TopologyCheck {
+    fn name(&self) -> &'static str {
+        "topology"
+    }
+
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
+
+    async fn run(&self, config: &RalphConfig) -> CheckResult {
+        let registry = HatRegistry::from_config(config);
+        if registry.is_empty() {
+            return CheckResult::pass(self.name(), "No hats configured (skipping)");
+        }
+
+        let mut emitted: Vec<&str> = BUILTIN_EMITTED_TOPICS.to_vec();
+        if let Some(starting_event) = &config.event_loop.starting_event {
+            emitted.push(starting_event);
+        }
+        let completion_promise = config.event_loop.completion_promise.as_str();
+
+        let warnings: Vec<String> = HatTopology::from_registry(&registry)
+            .validate()
+            .into_iter()
+            .filter(|warning| match warning {
+                TopologyWarning::UnreachableSubscription { topic, .. } => {
+                    let pattern = Topic::new(topic.as_str());
+                    !emitted.iter().any(|builtin| pattern.matches_str(builtin))
+                }
+                TopologyWarning::DeadEndPublish { topic, .. } => {
+                    topic != completion_promise
+                        && !BUILTIN_CONSUMED_TOPICS.contains(&topic.as_str())
+                }
+            })
+            .map(|warning| warning.to_string())
+            .collect();
+
+        if warnings.is_empty() {
+            CheckResult::pass(
+                self.name(),
+                format!("{} hat(s) fully connected", registry.len()),
+            )
+        } else {
+            CheckResult::warn(
+                self.name(),
+                format!("{} topology issue(s)", warnings.len()),
+                warnings.join("\n"),
+            )
+        }
+    }
+}
+
 /// Recursively collect all `.spec.md` files under a directory.
 fn collect_spec_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
     let mut files = Vec::new();
@@ -567,6 +938,22 @@ pub struct AcceptanceCriterion {
     pub when: Option<String>,
     /// The expected outcome (Then clause).
     pub then: String,
+    /// Spec file the criterion was extracted from (empty for in-memory content).
+    pub source_file: PathBuf,
+}
+
+impl AcceptanceCriterion {
+    /// Distinct lowercase keywords from the Given/When/Then text.
+    fn keywords(&self) -> Vec<String> {
+        let mut text = self.given.clone();
+        if let Some(when) = &self.when {
+            text.push(' ');
+            text.push_str(when);
+        }
+        text.push(' ');
+        text.push_str(&self.then);
+        keywords(&text)
+    }
 }
 
 /// Extract structured Given/When/Then acceptance criteria from spec content.
@@ -599,6 +986,7 @@ pub fn extract_acceptance_criteria(content: &str) -> Vec<AcceptanceCriterion> {
                     given,
                     when: current_when.take(),
                     then: text,
+                    source_file: PathBuf::new(),
                 });
             }
             // Reset for next criterion
@@ -652,6 +1040,12 @@ pub fn extract_criteria_from_file(path: &Path) -> Vec<AcceptanceCriterion> {
     }
 
     extract_acceptance_criteria(&content)
+        .into_iter()
+        .map(|criterion| AcceptanceCriterion {
+            source_file: path.to_path_buf(),
+            ..criterion
+        })
+        .collect()
 }
 
 /// Extract acceptance criteria from all spec files in a directory.
@@ -679,6 +1073,51 @@ pub fn extract_all_criteria(
     Ok(results)
 }
 
+/// Links acceptance criteria to the tasks they most plausibly verify.
+///
+/// A criterion is linked to every task whose title or description shares at
+/// least one keyword (three or more characters, common words ignored) with the
+/// criterion's Given/When/Then text. The result maps task IDs to their linked
+/// criteria; tasks with no matching criteria are omitted.
+pub fn link_criteria_to_tasks(
+    criteria: &[AcceptanceCriterion],
+    store: &TaskStore,
+) -> HashMap<String, Vec<AcceptanceCriterion>> {
+    let criterion_keywords: Vec<Vec<String>> =
+        criteria.iter().map(AcceptanceCriterion::keywords).collect();
+
+    let mut links = HashMap::new();
+    for task in store.all() {
+        let mut text = task.title.clone();
+        if let Some(description) = &task.description {
+            text.push(' ');
+            text.push_str(description);
+        }
+        let task_keywords = keywords(&text);
+
+        let linked: Vec<AcceptanceCriterion> = criteria
+            .iter()
+            .zip(&criterion_keywords)
+            .filter(|(_, words)| words.iter().any(|word| task_keywords.contains(word)))
+            .map(|(criterion, _)| criterion.clone())
+            .collect();
+        if !linked.is_empty() {
+            links.insert(task.id.clone(), linked);
+        }
+    }
+    links
+}
+
+/// Splits text into distinct lowercase keywords for fuzzy matching.
+///
+/// On top of the shared stop words, drops the boilerplate of Given/When/Then
+/// specs so criteria match tasks on their substance.
+fn keywords(text: &str) -> Vec<String> {
+    const SPEC_STOP_WORDS: &[&str] = &["given", "has", "have", "not", "its", "user", "implement"];
+
+    crate::text::keywords(text, SPEC_STOP_WORDS)
+}
+
 #[derive(Debug)]
 struct TelegramBotInfo {
     username: String,
@@ -720,7 +1159,7 @@ async fn telegram_get_me(token: &str) -> anyhow::Result<TelegramBotInfo> {
     Ok(TelegramBotInfo { username })
 }
 
-fn check_auto_backend(name: &str, config: &RalphConfig) -> CheckResult {
+async fn check_auto_backend(name: &str, config: &RalphConfig) -> CheckResult {
     let priority = config.get_agent_priority();
     if priority.is_empty() {
         return CheckResult::fail(
@@ -742,7 +1181,7 @@ fn check_auto_backend(name: &str, config: &RalphConfig) -> CheckResult {
         };
         checked.push(format!("{backend} ({command})"));
         if command_supports_version(backend) {
-            if command_available(&command) {
+            if command_available(&command).await {
                 return CheckResult::pass(name, format!("Auto backend available ({backend})"));
             }
         } else if find_executable(&command).is_some() {
@@ -765,7 +1204,7 @@ fn check_auto_backend(name: &str, config: &RalphConfig) -> CheckResult {
     )
 }
 
-fn check_named_backend(name: &str, config: &RalphConfig, backend: &str) -> CheckResult {
+async fn check_named_backend(name: &str, config: &RalphConfig, backend: &str) -> CheckResult {
     let command_override = config.cli.command.as_deref();
     let Some(command) = backend_command(backend, command_override) else {
         return CheckResult::fail(
@@ -787,7 +1226,7 @@ fn check_named_backend(name: &str, config: &RalphConfig, backend: &str) -> Check
         );
     }
 
-    if command_available(&command) {
+    if command_available(&command).await {
         CheckResult::pass(name, format!("Backend CLI available ({})", command))
     } else {
         CheckResult::fail(
@@ -820,10 +1259,15 @@ fn command_supports_version(backend: &str) -> bool {
     !backend.eq_ignore_ascii_case("custom")
 }
 
-fn command_available(command: &str) -> bool {
-    Command::new(command)
+/// Runs `<command> --version`; the child is killed if the future is dropped
+/// (e.g. when the check times out).
+async fn command_available(command: &str) -> bool {
+    tokio::process::Command::new(command)
         .arg("--version")
+        .stdin(Stdio::null())
+        .kill_on_drop(true)
         .output()
+        .await
         .map(|output| output.status.success())
         .unwrap_or(false)
 }
@@ -899,6 +1343,230 @@ fn format_config_warnings(warnings: &[ConfigWarning]) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Instant;
+
+    struct CountingCheck {
+        runs: Arc<AtomicUsize>,
+        cacheable: bool,
+    }
+
+    #[async_trait]
+    impl PreflightCheck for CountingCheck {
+        fn name(&self) -> &'static str {
+            "counting"
+        }
+
+        fn cache_key(&self, _config: &RalphConfig) -> Option<String> {
+            self.cacheable.then(|| "inputs".to_string())
+        }
+
+        async fn run(&self, _config: &RalphConfig) -> CheckResult {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            CheckResult::pass(self.name(), "ok")
+        }
+    }
+
+    fn counting_runner(cacheable: bool, ttl: Duration) -> (PreflightRunner, Arc<AtomicUsize>) {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let runner = PreflightRunner {
+            checks: vec![Box::new(CountingCheck {
+                runs: runs.clone(),
+                cacheable,
+            })],
+            cache: None,
+        }
+        .with_cache_ttl(ttl);
+        (runner, runs)
+    }
+
+    #[tokio::test]
+    async fn cached_check_skipped_within_ttl() {
+        let config = RalphConfig::default();
+        let (runner, runs) = counting_runner(true, Duration::from_secs(60));
+
+        assert!(runner.run_all(&config).await.passed);
+        assert!(runner.run_all(&config).await.passed);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn cached_check_rerun_after_expiry() {
+        let config = RalphConfig::default();
+        let (runner, runs) = counting_runner(true, Duration::from_millis(20));
+
+        runner.run_all(&config).await;
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        runner.run_all(&config).await;
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn persistent_cache_reused_across_runners() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache_path = temp_dir.path().join(PREFLIGHT_CACHE_FILE);
+        let config = RalphConfig::default();
+        let runs = Arc::new(AtomicUsize::new(0));
+        let runner = || PreflightRunner {
+            checks: vec![Box::new(CountingCheck {
+                runs: runs.clone(),
+                cacheable: true,
+            })],
+            cache: None,
+        };
+
+        runner()
+            .with_persistent_cache(&cache_path, Duration::from_secs(60))
+            .run_all(&config)
+            .await;
+        assert!(cache_path.exists());
+
+        // A fresh runner (as in the next `ralph run`) reuses the stored result
+        let report = runner()
+            .with_persistent_cache(&cache_path, Duration::from_secs(60))
+            .run_all(&config)
+            .await;
+        assert!(report.passed);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        // Expired entries on disk are re-run
+        runner()
+            .with_persistent_cache(&cache_path, Duration::ZERO)
+            .run_all(&config)
+            .await;
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn uncacheable_check_always_runs() {
+        let config = RalphConfig::default();
+        let (runner, runs) = counting_runner(false, Duration::from_secs(60));
+
+        runner.run_all(&config).await;
+        runner.run_all(&config).await;
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+        assert!(GitCleanCheck.cache_key(&config).is_none());
+    }
+
+    struct SleepCheck;
+
+    #[async_trait]
+    impl PreflightCheck for SleepCheck {
+        fn name(&self) -> &'static str {
+            "sleep"
+        }
+
+        fn timeout(&self) -> Option<Duration> {
+            Some(Duration::from_millis(100))
+        }
+
+        async fn run(&self, _config: &RalphConfig) -> CheckResult {
+            let status = tokio::process::Command::new("sleep")
+                .arg("10")
+                .kill_on_drop(true)
+                .status()
+                .await;
+            match status {
+                Ok(_) => CheckResult::pass(self.name(), "slept"),
+                Err(err) => CheckResult::fail(self.name(), "sleep failed", err.to_string()),
+            }
+        }
+    }
+
+    #[test]
+    fn subprocess_checks_have_default_timeout() {
+        assert_eq!(
+            BackendAvailableCheck.timeout(),
+            Some(SUBPROCESS_CHECK_TIMEOUT)
+        );
+        assert_eq!(GitCleanCheck.timeout(), Some(SUBPROCESS_CHECK_TIMEOUT));
+        assert_eq!(ConfigValidCheck.timeout(), None);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn command_available_kills_child_when_dropped() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let marker = temp_dir.path().join("finished");
+        let script = temp_dir.path().join("slow-cli");
+        std::fs::write(
+            &script,
+            format!("#!/bin/sh\nsleep 1\ntouch '{}'\n", marker.display()),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let result = tokio::time::timeout(
+            Duration::from_millis(100),
+            command_available(script.to_str().unwrap()),
+        )
+        .await;
+        assert!(result.is_err());
+
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        assert!(!marker.exists(), "timed-out backend probe kept running");
+    }
+
+    #[tokio::test]
+    async fn check_exceeding_timeout_reports_timed_out() {
+        let runner = PreflightRunner {
+            checks: vec![Box::new(SleepCheck)],
+            cache: None,
+        };
+
+        let started = Instant::now();
+        let report = runner.run_all(&RalphConfig::default()).await;
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(report.checks[0].status, CheckStatus::TimedOut);
+        assert_eq!(report.timed_out, 1);
+        assert_eq!(report.failures, 0);
+        assert!(!report.passed);
+        assert!(
+            report
+                .to_markdown()
+                .contains("0 failure(s), 1 timed out\n\n- ⏱️ **sleep**: Timed out")
+        );
+    }
+
+    #[test]
+    fn report_renders_markdown_icons_and_json() {
+        let report = PreflightReport::from_results(vec![
+            CheckResult::pass("config", "Configuration valid"),
+            CheckResult::warn("git", "Working tree dirty", "2 files modified"),
+            CheckResult::fail("backend", "Backend missing", "claude not found\nInstall it"),
+        ]);
+
+        let markdown = report.to_markdown();
+        assert!(markdown.starts_with("## Preflight: FAILED"));
+        assert!(markdown.contains("- ✅ **config**: Configuration valid"));
+        assert!(markdown.contains("- ⚠️ **git**: Working tree dirty\n  2 files modified"));
+        assert!(
+            markdown
+                .contains("- ❌ **backend**: Backend missing\n  claude not found\n  Install it")
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["passed"], false);
+        let statuses: Vec<_> = json["checks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|check| {
+                (
+                    check["name"].as_str().unwrap(),
+                    check["status"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![("config", "pass"), ("git", "warn"), ("backend", "fail")]
+        );
+    }
 
     #[tokio::test]
     async fn report_counts_statuses() {
@@ -1357,6 +2025,124 @@ Some other text here.
         assert!(criteria.is_empty());
     }
 
+    #[tokio::test]
+    async fn topology_check_warns_on_dangling_subscription() {
+        let yaml = r#"
+event_loop:
+  starting_event: "build.task"
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+  deployer:
+    name: "Deployer"
+    triggers: ["deploy.start"]
+    publishes: ["LOOP_COMPLETE"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).expect("config");
+
+        let result = TopologyCheck.run(&config).await;
+
+        assert_eq!(result.status, CheckStatus::Warn);
+        let message = result.message.expect("message");
+        assert!(message.contains("Hat 'Deployer' subscribes to 'deploy.start'"));
+        assert!(message.contains("Hat 'Builder' publishes 'build.done'"));
+        assert!(!message.contains("LOOP_COMPLETE"), "{message}");
+        assert!(!message.contains("'build.task'"), "{message}");
+    }
+
+    #[tokio::test]
+    async fn topology_check_passes_fully_connected_topology() {
+        let yaml = r#"
+event_loop:
+  starting_event: "plan.start"
+hats:
+  planner:
+    name: "Planner"
+    triggers: ["plan.start", "build.done"]
+    publishes: ["build.task", "LOOP_COMPLETE"]
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).expect("config");
+
+        let result = TopologyCheck.run(&config).await;
+
+        assert_eq!(result.status, CheckStatus::Pass, "{:?}", result.message);
+    }
+
+    #[tokio::test]
+    async fn topology_check_is_opt_in_for_shipped_presets() {
+        let presets_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../presets");
+        let mut presets: Vec<PathBuf> = std::fs::read_dir(&presets_dir)
+            .expect("presets dir")
+            .map(|entry| entry.expect("entry").path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "yml"))
+            .collect();
+        presets.sort();
+        assert!(!presets.is_empty());
+
+        let defaults = PreflightRunner::default_checks();
+        assert!(defaults.check_names().contains(&"topology"));
+        assert!(!defaults.default_check_names().contains(&"topology"));
+
+        let runner = PreflightRunner {
+            cache: None,
+            checks: vec![Box::new(TopologyCheck)],
+        };
+        for preset in presets {
+            let config = RalphConfig::from_file(&preset)
+                .unwrap_or_else(|e| panic!("{}: {e}", preset.display()));
+
+            let default_report = runner.run_all(&config).await;
+            assert!(
+                default_report.checks.is_empty(),
+                "{}: topology ran by default",
+                preset.display()
+            );
+
+            let report = runner
+                .run_selected(&config, &["topology".to_string()])
+                .await;
+            assert_eq!(report.checks.len(), 1);
+            assert_ne!(
+                report.checks[0].status,
+                CheckStatus::Fail,
+                "{}: {:?}",
+                preset.display(),
+                report.checks[0].message
+            );
+        }
+    }
+
+    #[test]
+    fn link_criteria_to_tasks_matches_by_keyword() {
+        use crate::task::Task;
+
+        let temp = tempfile::tempdir().expect("tempdir");
+        let mut store = TaskStore::load(&temp.path().join("tasks.jsonl")).expect("store");
+        let login = store
+            .add(Task::new("Implement login flow".to_string(), 1))
+            .id
+            .clone();
+        let export = store
+            .add(Task::new("Add CSV export".to_string(), 2))
+            .id
+            .clone();
+
+        let criteria = extract_acceptance_criteria(
+            "**Given** a registered account\n**When** the user submits valid credentials on the login page\n**Then** a session is created\n",
+        );
+        let links = link_criteria_to_tasks(&criteria, &store);
+
+        assert_eq!(links.get(&login).map(Vec::len), Some(1));
+        assert!(links[&login][0].then.contains("session"));
+        assert!(!links.contains_key(&export));
+    }
+
     #[test]
     fn extract_criteria_from_file_skips_implemented() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -1400,6 +2186,7 @@ status: draft
         let criteria = extract_criteria_from_file(&path);
         assert_eq!(criteria.len(), 1);
         assert_eq!(criteria[0].given, "the system is ready");
+        assert_eq!(criteria[0].source_file, path);
     }
 
     #[test]
@@ -19,6 +19,18 @@ pub enum ReplayMode {
     Text,
 }
 
+/// How ANSI color sequences are treated in terminal replay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReplayColorMode {
+    /// Pass color sequences through unchanged.
+    #[default]
+    Preserve,
+    /// Remove color/style (SGR) sequences, keeping other control sequences.
+    Strip,
+    /// Downsample 24-bit truecolor to the 256-color palette.
+    Ansi256,
+}
+
 /// Configuration for session playback.
 #[derive(Debug, Clone)]
 pub struct PlayerConfig {
@@ -33,6 +45,9 @@ pub struct PlayerConfig {
 
     /// Filter to specific event types (empty = all events).
     pub event_filter: Vec<String>,
+
+    /// Color handling in terminal replay mode.
+    pub color_mode: ReplayColorMode,
 }
 
 impl Default for PlayerConfig {
@@ -42,6 +57,7 @@ impl Default for PlayerConfig {
             step_mode: false,
             replay_mode: ReplayMode::Terminal,
             event_filter: Vec::new(),
+            color_mode: ReplayColorMode::Preserve,
         }
     }
 }
@@ -77,6 +93,12 @@ impl PlayerConfig {
         self.event_filter = events;
         self
     }
+
+    /// Sets how colors are handled in terminal replay.
+    pub fn with_color_mode(mut self, color_mode: ReplayColorMode) -> Self {
+        self.color_mode = color_mode;
+        self
+    }
 }
 
 /// A parsed record with timing information for replay.
@@ -256,10 +278,14 @@ impl SessionPlayer {
         })?;
 
         match self.config.replay_mode {
-            ReplayMode::Terminal => {
+            ReplayMode::Terminal => match self.config.color_mode {
                 // Output raw bytes (preserves ANSI sequences)
-                writer.write_all(&bytes)?;
-            }
+                ReplayColorMode::Preserve => writer.write_all(&bytes)?,
+                ReplayColorMode::Strip => writer.write_all(&map_sgr(&bytes, |_| None))?,
+                ReplayColorMode::Ansi256 => {
+                    writer.write_all(&map_sgr(&bytes, |params| Some(downsample_sgr(params))))?;
+                }
+            },
             ReplayMode::Text => {
                 // Strip ANSI sequences
                 let stripped = strip_ansi(&bytes);
@@ -374,6 +400,79 @@ fn strip_ansi(bytes: &[u8]) -> Vec<u8> {
     result
 }
 
+/// Rewrites SGR sequences (`ESC [ params m`) with `f`, dropping any for which
+/// it returns `None`. All other bytes, including non-SGR escapes, pass through.
+fn map_sgr(bytes: &[u8], f: impl Fn(&str) -> Option<String>) -> Vec<u8> {
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == 0x1b && bytes.get(i + 1) == Some(&b'[') {
+            let mut end = i + 2;
+            while end < bytes.len() && !(0x40..=0x7E).contains(&bytes[end]) {
+                end += 1;
+            }
+            if end < bytes.len() && bytes[end] == b'm' {
+                let params = String::from_utf8_lossy(&bytes[i + 2..end]);
+                if let Some(rewritten) = f(&params) {
+                    result.extend_from_slice(b"\x1b[");
+                    result.extend_from_slice(rewritten.as_bytes());
+                    result.push(b'm');
+                }
+                i = end + 1;
+                continue;
+            }
+        }
+        result.push(bytes[i]);
+        i += 1;
+    }
+
+    result
+}
+
+/// Converts `38;2;r;g;b` / `48;2;r;g;b` truecolor parameters to their
+/// `38;5;n` / `48;5;n` 256-color equivalents.
+fn downsample_sgr(params: &str) -> String {
+    let parts: Vec<&str> = params.split(';').collect();
+    let mut out: Vec<String> = Vec::with_capacity(parts.len());
+    let mut i = 0;
+
+    while i < parts.len() {
+        if matches!(parts[i], "38" | "48")
+            && parts.get(i + 1) == Some(&"2")
+            && let Some(rgb) = parts.get(i + 2..i + 5).and_then(|rgb| {
+                rgb.iter()
+                    .map(|c| c.parse::<u8>().ok())
+                    .collect::<Option<Vec<_>>>()
+            })
+        {
+            out.push(parts[i].to_string());
+            out.push("5".to_string());
+            out.push(rgb_to_256(rgb[0], rgb[1], rgb[2]).to_string());
+            i += 5;
+        } else {
+            out.push(parts[i].to_string());
+            i += 1;
+        }
+    }
+
+    out.join(";")
+}
+
+/// Maps an RGB color to the nearest xterm 256-color palette index.
+fn rgb_to_256(r: u8, g: u8, b: u8) -> u8 {
+    if r == g && g == b {
+        return match r {
+            0..8 => 16,
+            249.. => 231,
+            _ => 232 + ((u16::from(r) - 3) / 10).min(23) as u8,
+        };
+    }
+
+    let cube = |v: u8| ((u16::from(v) * 5 + 127) / 255) as u8;
+    16 + 36 * cube(r) + 6 * cube(g) + cube(b)
+}
+
 /// Escapes ANSI sequences for visibility in snapshots.
 ///
 /// Converts \x1b to `\x1b` literal string for readable diff comparisons.
@@ -503,6 +602,44 @@ mod tests {
         assert_eq!(String::from_utf8(output).unwrap(), "Green");
     }
 
+    fn replay_with_color_mode(bytes: &[u8], color_mode: ReplayColorMode) -> String {
+        let line = make_write_record(bytes, true, 0, 1000);
+        let mut player = SessionPlayer::from_bytes(line.as_bytes())
+            .unwrap()
+            .with_config(PlayerConfig::terminal().with_color_mode(color_mode));
+
+        let mut output = Vec::new();
+        player.replay_terminal(&mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_replay_color_mode_preserve_keeps_colors() {
+        let raw = b"\x1b[38;2;255;0;0mRed\x1b[0m";
+        assert_eq!(
+            replay_with_color_mode(raw, ReplayColorMode::Preserve),
+            "\x1b[38;2;255;0;0mRed\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_replay_color_mode_strip_removes_only_colors() {
+        let raw = b"\x1b[2J\x1b[1;32mGreen\x1b[0m";
+        assert_eq!(
+            replay_with_color_mode(raw, ReplayColorMode::Strip),
+            "\x1b[2JGreen"
+        );
+    }
+
+    #[test]
+    fn test_replay_color_mode_ansi256_downsamples_truecolor() {
+        let raw = b"\x1b[1;38;2;255;0;0;48;2;128;128;128mRed\x1b[32mGreen";
+        assert_eq!(
+            replay_with_color_mode(raw, ReplayColorMode::Ansi256),
+            "\x1b[1;38;5;196;48;5;244mRed\x1b[32mGreen"
+        );
+    }
+
     #[test]
     fn test_player_config_builder() {
         let config = PlayerConfig::terminal()
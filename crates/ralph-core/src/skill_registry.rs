@@ -231,6 +231,16 @@ impl SkillRegistry {
         self.skills.get(name)
     }
 
+    /// Get a skill by name if it is visible to any of the given hats (and the active backend).
+    ///
+    /// Used by prompt injection so that hat restrictions from frontmatter or
+    /// `skills.overrides.<name>.hats` gate which iterations receive the skill.
+    pub fn get_for_hats(&self, name: &str, hat_ids: &[&str]) -> Option<&SkillEntry> {
+        self.skills
+            .get(name)
+            .filter(|s| self.is_visible_to_any(s, hat_ids))
+    }
+
     /// Get all skills visible to a specific hat (filtered by hat + backend).
     pub fn skills_for_hat(&self, hat_id: Option<&str>) -> Vec<&SkillEntry> {
         self.skills
@@ -247,6 +257,24 @@ impl SkillRegistry {
             .collect()
     }
 
+    /// Get all auto-inject skills visible to any of the given hats (filtered by hat + backend).
+    pub fn auto_inject_skills_for_hats(&self, hat_ids: &[&str]) -> Vec<&SkillEntry> {
+        self.skills
+            .values()
+            .filter(|s| s.auto_inject && self.is_visible_to_any(s, hat_ids))
+            .collect()
+    }
+
+    /// Check if a skill is visible to at least one of the given hats.
+    ///
+    /// An empty hat list behaves like `is_visible(skill, None)`.
+    fn is_visible_to_any(&self, skill: &SkillEntry, hat_ids: &[&str]) -> bool {
+        if hat_ids.is_empty() {
+            return self.is_visible(skill, None);
+        }
+        hat_ids.iter().any(|hat| self.is_visible(skill, Some(hat)))
+    }
+
     /// Check if a skill is visible given the current hat and backend.
     fn is_visible(&self, skill: &SkillEntry, hat_id: Option<&str>) -> bool {
         // Backend filtering
@@ -482,6 +510,35 @@ mod tests {
         assert_eq!(skill.hats, vec!["builder"]);
     }
 
+    #[test]
+    fn test_get_for_hats_honors_hat_restriction() {
+        let mut registry = SkillRegistry::new(None);
+        registry.register_builtins().unwrap();
+
+        let mut overrides = HashMap::new();
+        overrides.insert(
+            "ralph-tools".to_string(),
+            SkillOverride {
+                hats: vec!["builder".to_string()],
+                ..Default::default()
+            },
+        );
+        registry.apply_overrides(&overrides);
+
+        assert!(registry.get_for_hats("ralph-tools", &["ralph"]).is_none());
+        assert!(
+            registry
+                .get_for_hats("ralph-tools", &["ralph", "builder"])
+                .is_some()
+        );
+        // Unrestricted skills are visible to any hat
+        assert!(
+            registry
+                .get_for_hats("robot-interaction", &["ralph"])
+                .is_some()
+        );
+    }
+
     #[test]
     fn test_override_sets_auto_inject() {
         let mut registry = SkillRegistry::new(None);
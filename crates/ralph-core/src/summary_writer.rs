@@ -315,10 +315,20 @@ mod tests {
             abandoned_tasks: Vec::new(),
             abandoned_task_redispatches: 0,
             consecutive_malformed_events: 0,
+            valid_events_since_malformed: 0,
             completion_requested: false,
+            verify_passed_seen: false,
             hat_activation_counts: std::collections::HashMap::new(),
+            hat_costs: std::collections::HashMap::new(),
+            topic_counts: std::collections::HashMap::new(),
+            soft_limit_warned: false,
             exhausted_hats: std::collections::HashSet::new(),
             last_checkin_at: None,
+            dry_run: false,
+            iteration_checkpoint_at: Instant::now(),
+            iteration_checkpoint_cost: 0.0,
+            last_iteration_cost: 0.0,
+            last_iteration_duration: std::time::Duration::ZERO,
         }
     }
 
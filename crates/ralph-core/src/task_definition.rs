@@ -350,6 +350,12 @@ impl TaskDefinitionBuilder {
         self
     }
 
+    /// Sets the `.gitignore` entries applied to the workspace.
+    pub fn gitignore_template(mut self, entries: Vec<String>) -> Self {
+        self.setup.gitignore_template = entries;
+        self
+    }
+
     /// Adds tags.
     pub fn tags(mut self, tags: Vec<String>) -> Self {
         self.tags = tags;
@@ -426,12 +432,18 @@ pub struct TaskSetup {
     /// Paths relative to the task suite file.
     #[serde(default)]
     pub files: Vec<String>,
+
+    /// Entries appended to the workspace `.gitignore` (e.g. `*.log`, `target/`).
+    ///
+    /// Entries already present are not duplicated.
+    #[serde(default)]
+    pub gitignore_template: Vec<String>,
 }
 
 impl TaskSetup {
     /// Returns true if there is any setup to perform.
     pub fn has_setup(&self) -> bool {
-        self.script.is_some() || !self.files.is_empty()
+        self.script.is_some() || !self.files.is_empty() || !self.gitignore_template.is_empty()
     }
 }
 
@@ -691,14 +703,22 @@ mod tests {
         let with_script = TaskSetup {
             script: Some("setup.sh".to_string()),
             files: vec![],
+            gitignore_template: vec![],
         };
         assert!(with_script.has_setup());
 
         let with_files = TaskSetup {
             script: None,
             files: vec!["file.py".to_string()],
+            gitignore_template: vec![],
         };
         assert!(with_files.has_setup());
+
+        let with_gitignore = TaskSetup {
+            gitignore_template: vec!["*.log".to_string()],
+            ..TaskSetup::default()
+        };
+        assert!(with_gitignore.has_setup());
     }
 
     #[test]
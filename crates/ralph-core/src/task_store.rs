@@ -13,18 +13,67 @@
 //!
 //! Use `load()` and `save()` for simple single-operation access, or use
 //! `with_exclusive_lock()` for read-modify-write operations that need atomicity.
+//! The validated mutation helpers (`add_task`, `close_task`, `set_status`,
+//! `add_dependency`) do the latter for you and persist immediately. Callers
+//! that only hold a path can use `append_task` (append-only, never rewrites
+//! existing lines) and `update_status_locked`.
+//!
+//! Writes go to a temporary file that is renamed over `tasks.jsonl`, so a
+//! crash mid-write never leaves a truncated file.
+//!
+//! The mutation helpers also record `task.added`, `task.closed`, and
+//! `task.failed` events; drain them with `take_events()` to publish them
+//! (e.g. so the TUI can react live).
 
+use crate::config::ReadyTaskOrder;
 use crate::file_lock::FileLock;
 use crate::task::{Task, TaskStatus};
+use ralph_proto::Event;
 use std::io;
 use std::path::Path;
 use tracing::warn;
 
+/// Errors from validated task store mutations.
+#[derive(Debug, thiserror::Error)]
+pub enum TaskStoreError {
+    /// IO error reading or writing the tasks file.
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    /// No task with the given ID exists.
+    #[error("Unknown task: {0}")]
+    UnknownTask(String),
+
+    /// A task with the given ID already exists.
+    #[error("Duplicate task ID: {0}")]
+    DuplicateTask(String),
+
+    /// A `blocked_by` entry references a task that does not exist.
+    #[error("Task {task} depends on unknown task {dependency}")]
+    UnknownDependency {
+        /// The task being modified.
+        task: String,
+        /// The missing dependency.
+        dependency: String,
+    },
+
+    /// The dependency would make a task (transitively) block itself.
+    #[error("Dependency {task} -> {dependency} would create a cycle")]
+    DependencyCycle {
+        /// The task being modified.
+        task: String,
+        /// The dependency that closes the cycle.
+        dependency: String,
+    },
+}
+
 /// A store for managing tasks with JSONL persistence and file locking.
 pub struct TaskStore {
     path: std::path::PathBuf,
     tasks: Vec<Task>,
     lock: FileLock,
+    /// Events recorded by mutation helpers, drained by `take_events()`.
+    events: Vec<Event>,
 }
 
 /// Parses a JSONL line into a Task, logging a warning on failure.
@@ -42,6 +91,51 @@ fn parse_task_line(line: &str) -> Option<Task> {
     }
 }
 
+/// Reads all tasks from `path`, returning an empty list if it doesn't exist.
+fn read_tasks(path: &Path) -> io::Result<Vec<Task>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(path)?;
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(parse_task_line)
+        .collect())
+}
+
+/// Writes tasks to `path` as JSONL via a temporary file and rename.
+///
+/// The caller must hold the exclusive lock.
+fn write_tasks(path: &Path, tasks: &[Task]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut content = String::new();
+    for task in tasks {
+        let line = serde_json::to_string(task).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("task serialization failed: {e}"),
+            )
+        })?;
+        content.push_str(&line);
+        content.push('\n');
+    }
+    let tmp_path = path.with_extension("jsonl.tmp");
+    std::fs::write(&tmp_path, content)?;
+    std::fs::rename(&tmp_path, path)
+}
+
+/// Sets `status`, recording a `closed` timestamp for terminal statuses
+/// and clearing it otherwise.
+fn apply_status(task: &mut Task, status: TaskStatus) {
+    task.status = status;
+    task.closed = status
+        .is_terminal()
+        .then(|| chrono::Utc::now().to_rfc3339());
+}
+
 impl TaskStore {
     /// Loads tasks from the JSONL file at the given path.
     ///
@@ -53,22 +147,24 @@ impl TaskStore {
         let lock = FileLock::new(path)?;
         let _guard = lock.shared()?;
 
-        let tasks = if path.exists() {
-            let content = std::fs::read_to_string(path)?;
-            content
-                .lines()
-                .filter(|line| !line.trim().is_empty())
-                .filter_map(|line| parse_task_line(line))
-                .collect()
-        } else {
-            Vec::new()
-        };
+        let tasks = read_tasks(path)?;
 
-        Ok(Self {
+        let store = Self {
             path: path.to_path_buf(),
             tasks,
             lock,
-        })
+            events: Vec::new(),
+        };
+        if let Err(cycles) = store.validate_dependencies() {
+            for cycle in cycles {
+                warn!(
+                    "Task dependency cycle in {}: {} (these tasks will never become ready)",
+                    path.display(),
+                    cycle.join(" -> ")
+                );
+            }
+        }
+        Ok(store)
     }
 
     /// Saves all tasks to the JSONL file.
@@ -77,31 +173,7 @@ impl TaskStore {
     /// Uses an exclusive lock to prevent concurrent writes.
     pub fn save(&self) -> io::Result<()> {
         let _guard = self.lock.exclusive()?;
-
-        if let Some(parent) = self.path.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-        let content: String = self
-            .tasks
-            .iter()
-            .map(|t| {
-                serde_json::to_string(t).map_err(|e| {
-                    io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("task serialization failed: {e}"),
-                    )
-                })
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .join("\n");
-        std::fs::write(
-            &self.path,
-            if content.is_empty() {
-                String::new()
-            } else {
-                content + "\n"
-            },
-        )
+        write_tasks(&self.path, &self.tasks)
     }
 
     /// Reloads tasks from disk, useful after external modifications.
@@ -111,16 +183,7 @@ impl TaskStore {
     pub fn reload(&mut self) -> io::Result<()> {
         let _guard = self.lock.shared()?;
 
-        self.tasks = if self.path.exists() {
-            let content = std::fs::read_to_string(&self.path)?;
-            content
-                .lines()
-                .filter(|line| !line.trim().is_empty())
-                .filter_map(|line| parse_task_line(line))
-                .collect()
-        } else {
-            Vec::new()
-        };
+        self.tasks = read_tasks(&self.path)?;
 
         Ok(())
     }
@@ -146,45 +209,13 @@ impl TaskStore {
         let _guard = self.lock.exclusive()?;
 
         // Reload to get latest changes from other loops
-        self.tasks = if self.path.exists() {
-            let content = std::fs::read_to_string(&self.path)?;
-            content
-                .lines()
-                .filter(|line| !line.trim().is_empty())
-                .filter_map(|line| parse_task_line(line))
-                .collect()
-        } else {
-            Vec::new()
-        };
+        self.tasks = read_tasks(&self.path)?;
 
         // Execute the user function
         let result = f(self);
 
         // Save changes
-        if let Some(parent) = self.path.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-        let content: String = self
-            .tasks
-            .iter()
-            .map(|t| {
-                serde_json::to_string(t).map_err(|e| {
-                    io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!("task serialization failed: {e}"),
-                    )
-                })
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .join("\n");
-        std::fs::write(
-            &self.path,
-            if content.is_empty() {
-                String::new()
-            } else {
-                content + "\n"
-            },
-        )?;
+        write_tasks(&self.path, &self.tasks)?;
 
         Ok(result)
     }
@@ -195,6 +226,184 @@ impl TaskStore {
         self.tasks.last().unwrap()
     }
 
+    /// Adds a task after validating it, persisting the store atomically.
+    ///
+    /// Unlike [`TaskStore::add`], this reloads under an exclusive lock, rejects
+    /// duplicate IDs and `blocked_by` entries that reference unknown tasks (or
+    /// the task itself), and writes the result before returning.
+    pub fn add_task(&mut self, task: Task) -> Result<Task, TaskStoreError> {
+        self.mutate_and_save(|tasks| {
+            if tasks.iter().any(|t| t.id == task.id) {
+                return Err(TaskStoreError::DuplicateTask(task.id.clone()));
+            }
+            for dependency in &task.blocked_by {
+                if *dependency == task.id {
+                    return Err(TaskStoreError::DependencyCycle {
+                        task: task.id.clone(),
+                        dependency: dependency.clone(),
+                    });
+                }
+                if !tasks.iter().any(|t| t.id == *dependency) {
+                    return Err(TaskStoreError::UnknownDependency {
+                        task: task.id.clone(),
+                        dependency: dependency.clone(),
+                    });
+                }
+            }
+            tasks.push(task.clone());
+            Ok(task)
+        })
+        .inspect(|task| self.record_event("task.added", task))
+    }
+
+    /// Closes a task by ID, persisting the store atomically.
+    pub fn close_task(&mut self, id: &str) -> Result<Task, TaskStoreError> {
+        self.set_status(id, TaskStatus::Closed)
+    }
+
+    /// Sets a task's status, persisting the store atomically.
+    ///
+    /// Terminal statuses record a `closed` timestamp; non-terminal ones clear it.
+    pub fn set_status(&mut self, id: &str, status: TaskStatus) -> Result<Task, TaskStoreError> {
+        self.mutate_and_save(|tasks| {
+            let task = tasks
+                .iter_mut()
+                .find(|t| t.id == id)
+                .ok_or_else(|| TaskStoreError::UnknownTask(id.to_string()))?;
+            apply_status(task, status);
+            Ok(task.clone())
+        })
+        .inspect(|task| match task.status {
+            TaskStatus::Closed => self.record_event("task.closed", task),
+            TaskStatus::Failed => self.record_event("task.failed", task),
+            TaskStatus::Open | TaskStatus::InProgress => {}
+        })
+    }
+
+    /// Appends a single task to the JSONL file at `path` under an exclusive lock.
+    ///
+    /// Unlike [`TaskStore::add_task`], existing content is never rewritten:
+    /// one line is appended, so concurrent writers (e.g. the CLI and a running
+    /// loop) cannot lose each other's updates. No duplicate-ID validation is
+    /// performed.
+    pub fn append_task(path: &Path, task: &Task) -> Result<(), TaskStoreError> {
+        use std::io::{Read, Seek, SeekFrom, Write};
+
+        let lock = FileLock::new(path)?;
+        let _guard = lock.exclusive()?;
+
+        let line = serde_json::to_string(task).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("task serialization failed: {e}"),
+            )
+        })?;
+
+        let mut file = std::fs::OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)?;
+
+        // Keep the new record on its own line if the file lacks a trailing newline
+        let mut content = String::new();
+        if file.metadata()?.len() > 0 {
+            let mut last = [0u8; 1];
+            file.seek(SeekFrom::End(-1))?;
+            file.read_exact(&mut last)?;
+            if last[0] != b'\n' {
+                content.push('\n');
+            }
+        }
+        content.push_str(&line);
+        content.push('\n');
+
+        file.write_all(content.as_bytes())?;
+        Ok(())
+    }
+
+    /// Sets the status of task `id` in the file at `path` under an exclusive lock.
+    ///
+    /// Reads, updates, and rewrites the file while holding the lock, so it is
+    /// safe to call alongside [`TaskStore::append_task`] from other processes.
+    pub fn update_status_locked(
+        path: &Path,
+        id: &str,
+        status: TaskStatus,
+    ) -> Result<Task, TaskStoreError> {
+        let lock = FileLock::new(path)?;
+        let _guard = lock.exclusive()?;
+
+        let mut tasks = read_tasks(path)?;
+        let task = tasks
+            .iter_mut()
+            .find(|t| t.id == id)
+            .ok_or_else(|| TaskStoreError::UnknownTask(id.to_string()))?;
+        apply_status(task, status);
+        let updated = task.clone();
+        write_tasks(path, &tasks)?;
+        Ok(updated)
+    }
+
+    /// Drains events recorded by the mutation helpers, oldest first.
+    ///
+    /// Payloads are JSON objects with the task `id` and `title`.
+    pub fn take_events(&mut self) -> Vec<Event> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn record_event(&mut self, topic: &str, task: &Task) {
+        let payload = serde_json::json!({ "id": task.id, "title": task.title });
+        self.events.push(Event::new(topic, payload.to_string()));
+    }
+
+    /// Makes `id` blocked by `dependency`, persisting the store atomically.
+    ///
+    /// Rejects unknown tasks and dependencies that would create a cycle.
+    /// Adding an existing dependency is a no-op.
+    pub fn add_dependency(&mut self, id: &str, dependency: &str) -> Result<Task, TaskStoreError> {
+        self.mutate_and_save(|tasks| {
+            if !tasks.iter().any(|t| t.id == id) {
+                return Err(TaskStoreError::UnknownTask(id.to_string()));
+            }
+            if !tasks.iter().any(|t| t.id == dependency) {
+                return Err(TaskStoreError::UnknownDependency {
+                    task: id.to_string(),
+                    dependency: dependency.to_string(),
+                });
+            }
+            if id == dependency || depends_on(tasks, dependency, id) {
+                return Err(TaskStoreError::DependencyCycle {
+                    task: id.to_string(),
+                    dependency: dependency.to_string(),
+                });
+            }
+            let task = tasks
+                .iter_mut()
+                .find(|t| t.id == id)
+                .expect("task existence checked above");
+            if !task.blocked_by.iter().any(|d| d == dependency) {
+                task.blocked_by.push(dependency.to_string());
+            }
+            Ok(task.clone())
+        })
+    }
+
+    /// Reloads under an exclusive lock, applies `f`, and writes on success.
+    ///
+    /// On error nothing is written and the in-memory tasks reflect the
+    /// reloaded file.
+    fn mutate_and_save<T>(
+        &mut self,
+        f: impl FnOnce(&mut Vec<Task>) -> Result<T, TaskStoreError>,
+    ) -> Result<T, TaskStoreError> {
+        let _guard = self.lock.exclusive()?;
+        self.tasks = read_tasks(&self.path)?;
+        let result = f(&mut self.tasks)?;
+        write_tasks(&self.path, &self.tasks)?;
+        Ok(result)
+    }
+
     /// Gets a task by ID (immutable reference).
     pub fn get(&self, id: &str) -> Option<&Task> {
         self.tasks.iter().find(|t| t.id == id)
@@ -246,6 +455,112 @@ impl TaskStore {
             .collect()
     }
 
+    /// Returns ready tasks sorted according to `order`.
+    ///
+    /// Sorting is stable, so ties keep store order.
+    pub fn ready_sorted(&self, order: ReadyTaskOrder) -> Vec<&Task> {
+        let mut ready = self.ready();
+        match order {
+            ReadyTaskOrder::Fifo => {}
+            ReadyTaskOrder::Priority => ready.sort_by_key(|t| t.priority),
+            ReadyTaskOrder::DependencyDepth => {
+                ready.sort_by_key(|t| (std::cmp::Reverse(self.unblock_depth(&t.id)), t.priority));
+            }
+            ReadyTaskOrder::PriorityThenId => {
+                ready.sort_by(|a, b| (a.priority, &a.id).cmp(&(b.priority, &b.id)));
+            }
+            ReadyTaskOrder::CreatedAt => ready.sort_by(|a, b| a.created.cmp(&b.created)),
+            ReadyTaskOrder::PriorityThenCreated => {
+                ready.sort_by(|a, b| (a.priority, &a.created).cmp(&(b.priority, &b.created)));
+            }
+        }
+        ready
+    }
+
+    /// Length of the longest chain of open tasks waiting (transitively) on `id`.
+    fn unblock_depth(&self, id: &str) -> usize {
+        fn depth<'a>(
+            tasks: &'a [Task],
+            id: &'a str,
+            visiting: &mut std::collections::HashSet<&'a str>,
+        ) -> usize {
+            if !visiting.insert(id) {
+                return 0; // Cycle guard
+            }
+            let max = tasks
+                .iter()
+                .filter(|t| !t.status.is_terminal() && t.blocked_by.iter().any(|b| b == id))
+                .map(|t| 1 + depth(tasks, &t.id, visiting))
+                .max()
+                .unwrap_or(0);
+            visiting.remove(id);
+            max
+        }
+        depth(&self.tasks, id, &mut std::collections::HashSet::new())
+    }
+
+    /// Checks `blocked_by` relationships for cycles.
+    ///
+    /// Only unclosed tasks take part, since a closed blocker no longer holds
+    /// anything back; references to unknown task IDs are ignored. Each cycle
+    /// is returned as the chain of task IDs, ending with the ID it started
+    /// from (e.g. `["a", "b", "a"]`).
+    pub fn validate_dependencies(&self) -> Result<(), Vec<Vec<String>>> {
+        fn visit<'a>(
+            tasks: &'a [Task],
+            id: &'a str,
+            path: &mut Vec<&'a str>,
+            done: &mut std::collections::HashSet<&'a str>,
+            cycles: &mut Vec<Vec<String>>,
+        ) {
+            if let Some(start) = path.iter().position(|p| *p == id) {
+                let mut cycle: Vec<String> =
+                    path[start..].iter().map(ToString::to_string).collect();
+                cycle.push(id.to_string());
+                cycles.push(cycle);
+                return;
+            }
+            if done.contains(id) {
+                return;
+            }
+            let Some(task) = tasks
+                .iter()
+                .find(|t| t.id == id && t.status != TaskStatus::Closed)
+            else {
+                return;
+            };
+            path.push(id);
+            for blocker in &task.blocked_by {
+                visit(tasks, blocker, path, done, cycles);
+            }
+            path.pop();
+            done.insert(id);
+        }
+
+        let mut cycles = Vec::new();
+        let mut done = std::collections::HashSet::new();
+        for task in &self.tasks {
+            visit(
+                &self.tasks,
+                &task.id,
+                &mut Vec::new(),
+                &mut done,
+                &mut cycles,
+            );
+        }
+
+        if cycles.is_empty() {
+            Ok(())
+        } else {
+            Err(cycles)
+        }
+    }
+
+    /// Returns true if any `blocked_by` chain loops back on itself.
+    pub fn has_dependency_cycle(&self) -> bool {
+        self.validate_dependencies().is_err()
+    }
+
     /// Returns true if there are any open tasks.
     ///
     /// A task is considered open if it is not Closed. This includes Failed tasks.
@@ -260,6 +575,68 @@ impl TaskStore {
     pub fn has_pending_tasks(&self) -> bool {
         self.tasks.iter().any(|t| !t.status.is_terminal())
     }
+
+    /// Renders the task dependency graph as a Mermaid flowchart.
+    ///
+    /// Each task becomes a node labelled with its title, and each
+    /// `blocked_by` entry becomes an edge from the blocker to the blocked
+    /// task. Closed and failed tasks get their own node classes.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("graph TD\n");
+
+        for task in &self.tasks {
+            let label = task.title.replace('"', "#quot;");
+            out.push_str(&format!("    {}[\"{}\"]\n", mermaid_id(&task.id), label));
+        }
+
+        for task in &self.tasks {
+            for blocker in &task.blocked_by {
+                out.push_str(&format!(
+                    "    {} --> {}\n",
+                    mermaid_id(blocker),
+                    mermaid_id(&task.id)
+                ));
+            }
+        }
+
+        for task in &self.tasks {
+            let class = match task.status {
+                TaskStatus::Closed => "closed",
+                TaskStatus::Failed => "failed",
+                TaskStatus::Open | TaskStatus::InProgress => continue,
+            };
+            out.push_str(&format!("    class {} {class}\n", mermaid_id(&task.id)));
+        }
+
+        out.push_str("    classDef closed fill:#d3f9d8,stroke:#2b8a3e,color:#495057\n");
+        out.push_str("    classDef failed fill:#ffe3e3,stroke:#c92a2a,color:#495057\n");
+        out
+    }
+}
+
+/// Converts a task ID into a Mermaid-safe node identifier.
+fn mermaid_id(id: &str) -> String {
+    id.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// Returns true if `from` is (transitively) blocked by `target`.
+fn depends_on(tasks: &[Task], from: &str, target: &str) -> bool {
+    let mut stack = vec![from];
+    let mut seen = std::collections::HashSet::new();
+    while let Some(id) = stack.pop() {
+        if id == target {
+            return true;
+        }
+        if !seen.insert(id) {
+            continue;
+        }
+        if let Some(task) = tasks.iter().find(|t| t.id == id) {
+            stack.extend(task.blocked_by.iter().map(String::as_str));
+        }
+    }
+    false
 }
 
 #[cfg(test)]
@@ -511,4 +888,339 @@ mod tests {
         assert_eq!(loaded.all().len(), 1);
         assert_eq!(loaded.all()[0].title, "Valid task");
     }
+
+    #[test]
+    fn test_add_task_with_dependency_and_close() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+
+        let base = store.add_task(Task::new("Base".to_string(), 1)).unwrap();
+        let dependent = store
+            .add_task(Task::new("Dependent".to_string(), 1).with_blocker(base.id.clone()))
+            .unwrap();
+
+        // Persisted immediately
+        let reloaded = TaskStore::load(&path).unwrap();
+        assert_eq!(reloaded.all().len(), 2);
+        assert!(reloaded.ready().iter().all(|t| t.id != dependent.id));
+
+        let closed = store.close_task(&base.id).unwrap();
+        assert_eq!(closed.status, TaskStatus::Closed);
+        assert!(closed.closed.is_some());
+
+        let reloaded = TaskStore::load(&path).unwrap();
+        assert_eq!(reloaded.ready()[0].id, dependent.id);
+        assert!(!path.with_extension("jsonl.tmp").exists());
+    }
+
+    #[test]
+    fn test_add_task_rejects_unknown_dependency() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+
+        let err = store
+            .add_task(Task::new("Orphan".to_string(), 1).with_blocker("task-missing".to_string()))
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            TaskStoreError::UnknownDependency { ref dependency, .. } if dependency == "task-missing"
+        ));
+        assert!(store.all().is_empty());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_add_dependency_rejects_cycle() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+
+        let a = store.add_task(Task::new("A".to_string(), 1)).unwrap();
+        let b = store
+            .add_task(Task::new("B".to_string(), 1).with_blocker(a.id.clone()))
+            .unwrap();
+        let c = store.add_task(Task::new("C".to_string(), 1)).unwrap();
+
+        store.add_dependency(&c.id, &b.id).unwrap();
+        let err = store.add_dependency(&a.id, &c.id).unwrap_err();
+        assert!(matches!(err, TaskStoreError::DependencyCycle { .. }));
+
+        assert!(matches!(
+            store.add_dependency(&a.id, "task-missing"),
+            Err(TaskStoreError::UnknownDependency { .. })
+        ));
+        assert!(matches!(
+            store.set_status("task-missing", TaskStatus::InProgress),
+            Err(TaskStoreError::UnknownTask(_))
+        ));
+    }
+
+    #[test]
+    fn test_mutation_helpers_record_task_events() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+
+        let task = store.add_task(Task::new("Ship it".to_string(), 1)).unwrap();
+        store.close_task(&task.id).unwrap();
+        // Failed validations record nothing
+        let _ = store.close_task("task-missing");
+
+        let events = store.take_events();
+        let topics: Vec<&str> = events.iter().map(|e| e.topic.as_str()).collect();
+        assert_eq!(topics, vec!["task.added", "task.closed"]);
+
+        let payload: serde_json::Value = serde_json::from_str(&events[1].payload).unwrap();
+        assert_eq!(payload["id"], task.id.as_str());
+        assert_eq!(payload["title"], "Ship it");
+
+        assert!(store.take_events().is_empty(), "events are drained");
+    }
+
+    #[test]
+    fn test_validate_dependencies_two_node_cycle() {
+        let temp = TempDir::new().unwrap();
+        let mut store = TaskStore::load(&temp.path().join("tasks.jsonl")).unwrap();
+        let mut a = Task::new("A".to_string(), 1);
+        a.id = "task-a".to_string();
+        let mut b = Task::new("B".to_string(), 1);
+        b.id = "task-b".to_string();
+        store.add(a.with_blocker("task-b".to_string()));
+        store.add(b.with_blocker("task-a".to_string()));
+
+        assert!(store.has_dependency_cycle());
+        assert_eq!(
+            store.validate_dependencies(),
+            Err(vec![vec![
+                "task-a".to_string(),
+                "task-b".to_string(),
+                "task-a".to_string()
+            ]])
+        );
+    }
+
+    #[test]
+    fn test_validate_dependencies_three_node_cycle() {
+        let temp = TempDir::new().unwrap();
+        let mut store = TaskStore::load(&temp.path().join("tasks.jsonl")).unwrap();
+        for (id, blocker) in [("a", "c"), ("b", "a"), ("c", "b")] {
+            let mut task = Task::new(id.to_uppercase(), 1).with_blocker(blocker.to_string());
+            task.id = id.to_string();
+            store.add(task);
+        }
+
+        let cycles = store.validate_dependencies().unwrap_err();
+        assert_eq!(cycles, vec![vec!["a", "c", "b", "a"]]);
+        assert!(store.has_dependency_cycle());
+    }
+
+    #[test]
+    fn test_validate_dependencies_accepts_dag() {
+        let temp = TempDir::new().unwrap();
+        let mut store = TaskStore::load(&temp.path().join("tasks.jsonl")).unwrap();
+        let root = Task::new("Root".to_string(), 1);
+        let left = Task::new("Left".to_string(), 1).with_blocker(root.id.clone());
+        let right = Task::new("Right".to_string(), 1).with_blocker(root.id.clone());
+        let join = Task::new("Join".to_string(), 1)
+            .with_blocker(left.id.clone())
+            .with_blocker(right.id.clone());
+        store.add(root);
+        store.add(left);
+        store.add(right);
+        store.add(join);
+
+        assert_eq!(store.validate_dependencies(), Ok(()));
+        assert!(!store.has_dependency_cycle());
+    }
+
+    #[test]
+    fn test_append_task_concurrent_writers_lose_nothing() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("tasks.jsonl");
+
+        let handles: Vec<_> = (0..8)
+            .map(|worker| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    for n in 0..5 {
+                        let mut task = Task::new(format!("Task {worker}-{n}"), 2);
+                        task.id = format!("task-{worker}-{n}");
+                        TaskStore::append_task(&path, &task).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let store = TaskStore::load(&path).unwrap();
+        assert_eq!(store.all().len(), 40);
+        for worker in 0..8 {
+            for n in 0..5 {
+                assert!(store.get(&format!("task-{worker}-{n}")).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn test_append_task_preserves_existing_content() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("tasks.jsonl");
+        let first = Task::new("First".to_string(), 1);
+        let line = serde_json::to_string(&first).unwrap();
+        std::fs::write(&path, &line).unwrap(); // no trailing newline
+
+        let second = Task::new("Second".to_string(), 1);
+        TaskStore::append_task(&path, &second).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with(&format!("{line}\n")));
+        let titles: Vec<_> = TaskStore::load(&path)
+            .unwrap()
+            .all()
+            .iter()
+            .map(|t| t.title.clone())
+            .collect();
+        assert_eq!(titles, vec!["First", "Second"]);
+    }
+
+    #[test]
+    fn test_update_status_locked() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("tasks.jsonl");
+        let task = Task::new("Ship it".to_string(), 1);
+        TaskStore::append_task(&path, &task).unwrap();
+
+        let updated = TaskStore::update_status_locked(&path, &task.id, TaskStatus::Closed).unwrap();
+        assert_eq!(updated.status, TaskStatus::Closed);
+        assert!(updated.closed.is_some());
+        assert_eq!(
+            TaskStore::load(&path)
+                .unwrap()
+                .get(&task.id)
+                .unwrap()
+                .status,
+            TaskStatus::Closed
+        );
+
+        assert!(matches!(
+            TaskStore::update_status_locked(&path, "task-missing", TaskStatus::Closed),
+            Err(TaskStoreError::UnknownTask(id)) if id == "task-missing"
+        ));
+    }
+
+    #[test]
+    fn test_ready_sorted_by_priority() {
+        let temp = TempDir::new().unwrap();
+        let mut store = TaskStore::load(&temp.path().join("tasks.jsonl")).unwrap();
+        store.add(Task::new("Low".to_string(), 3));
+        store.add(Task::new("Medium".to_string(), 2));
+        store.add(Task::new("High".to_string(), 1));
+
+        let titles = |order| {
+            store
+                .ready_sorted(order)
+                .iter()
+                .map(|t| t.title.clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            titles(ReadyTaskOrder::Priority),
+            vec!["High", "Medium", "Low"]
+        );
+        assert_eq!(titles(ReadyTaskOrder::Fifo), vec!["Low", "Medium", "High"]);
+    }
+
+    #[test]
+    fn test_ready_sorted_by_created_at() {
+        let temp = TempDir::new().unwrap();
+        let mut store = TaskStore::load(&temp.path().join("tasks.jsonl")).unwrap();
+        let mut newest = Task::new("Newest".to_string(), 1);
+        newest.created = "2025-03-01T00:00:00Z".to_string();
+        let mut oldest = Task::new("Oldest".to_string(), 3);
+        oldest.created = "2025-01-01T00:00:00Z".to_string();
+        let mut middle = Task::new("Middle".to_string(), 1);
+        middle.created = "2025-02-01T00:00:00Z".to_string();
+        store.add(newest);
+        store.add(oldest);
+        store.add(middle);
+
+        let titles = |order| {
+            store
+                .ready_sorted(order)
+                .iter()
+                .map(|t| t.title.clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            titles(ReadyTaskOrder::CreatedAt),
+            vec!["Oldest", "Middle", "Newest"]
+        );
+        assert_eq!(
+            titles(ReadyTaskOrder::PriorityThenCreated),
+            vec!["Middle", "Newest", "Oldest"]
+        );
+    }
+
+    #[test]
+    fn test_ready_sorted_by_dependency_depth() {
+        let temp = TempDir::new().unwrap();
+        let mut store = TaskStore::load(&temp.path().join("tasks.jsonl")).unwrap();
+        let leaf = Task::new("Leaf".to_string(), 1);
+        let root = Task::new("Root".to_string(), 3);
+        let middle = Task::new("Middle".to_string(), 3).with_blocker(root.id.clone());
+        let top = Task::new("Top".to_string(), 3).with_blocker(middle.id.clone());
+        store.add(leaf);
+        store.add(root);
+        store.add(middle);
+        store.add(top);
+
+        let ready: Vec<_> = store
+            .ready_sorted(ReadyTaskOrder::DependencyDepth)
+            .iter()
+            .map(|t| t.title.clone())
+            .collect();
+
+        // Root unblocks a two-task chain, so it beats the higher-priority leaf.
+        assert_eq!(ready, vec!["Root", "Leaf"]);
+    }
+
+    #[test]
+    fn test_to_mermaid_renders_nodes_and_edges() {
+        let temp = TempDir::new().unwrap();
+        let mut store = TaskStore::load(&temp.path().join("tasks.jsonl")).unwrap();
+        let mut design = Task::new("Design \"API\"".to_string(), 1);
+        design.status = TaskStatus::Closed;
+        let build = Task::new("Build".to_string(), 2).with_blocker(design.id.clone());
+        let ship = Task::new("Ship".to_string(), 3)
+            .with_blocker(design.id.clone())
+            .with_blocker(build.id.clone());
+        let (design_id, build_id, ship_id) = (
+            mermaid_id(&design.id),
+            mermaid_id(&build.id),
+            mermaid_id(&ship.id),
+        );
+        store.add(design);
+        store.add(build);
+        store.add(ship);
+
+        let mermaid = store.to_mermaid();
+
+        assert!(mermaid.starts_with("graph TD\n"));
+        assert!(mermaid.contains(&format!("{design_id}[\"Design #quot;API#quot;\"]")));
+        assert!(mermaid.contains(&format!("{build_id}[\"Build\"]")));
+        assert!(mermaid.contains(&format!("{ship_id}[\"Ship\"]")));
+        assert!(mermaid.contains(&format!("{design_id} --> {build_id}")));
+        assert!(mermaid.contains(&format!("{design_id} --> {ship_id}")));
+        assert!(mermaid.contains(&format!("{build_id} --> {ship_id}")));
+        assert_eq!(mermaid.matches(" --> ").count(), 3);
+        assert!(mermaid.contains(&format!("class {design_id} closed")));
+        assert!(!mermaid.contains(&format!("class {build_id}")));
+    }
 }
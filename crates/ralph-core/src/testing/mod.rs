@@ -9,9 +9,10 @@ pub mod smoke_runner;
 
 pub use mock_backend::{ExecutionRecord, MockBackend};
 #[cfg(feature = "recording")]
-pub use replay_backend::{ReplayBackend, ReplayTimingMode};
+pub use replay_backend::{DEFAULT_TERMINAL_SIZE, ReplayBackend, ReplayFrame, ReplayTimingMode};
 pub use scenario::{ExecutionTrace, Scenario, ScenarioRunner};
 #[cfg(feature = "recording")]
 pub use smoke_runner::{
-    SmokeRunner, SmokeTestConfig, SmokeTestError, SmokeTestResult, TerminationReason, list_fixtures,
+    SmokeRunner, SmokeTestConfig, SmokeTestError, SmokeTestResult, TerminationReason, fixture_tags,
+    list_fixtures, list_fixtures_tagged,
 };
@@ -26,13 +26,46 @@ use std::path::Path;
 use std::time::Duration;
 
 /// Timing mode for replay.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum ReplayTimingMode {
     /// Serve all output immediately without timing delays.
     #[default]
     Instant,
     /// Honor recorded timing delays between outputs.
     Realistic,
+    /// Honor recorded delays multiplied by the factor (`0.5` replays twice as fast).
+    Scaled(f64),
+}
+
+impl ReplayTimingMode {
+    /// Returns how long to wait for a recorded gap of `delay_ms` milliseconds.
+    fn scale(self, delay_ms: u64) -> Duration {
+        let delay = Duration::from_millis(delay_ms);
+        match self {
+            ReplayTimingMode::Instant => Duration::ZERO,
+            ReplayTimingMode::Realistic => delay,
+            ReplayTimingMode::Scaled(factor) if factor > 0.0 => delay.mul_f64(factor),
+            ReplayTimingMode::Scaled(_) => Duration::ZERO,
+        }
+    }
+}
+
+/// Default virtual terminal size for [`ReplayBackend::final_frame`] (columns, rows).
+pub const DEFAULT_TERMINAL_SIZE: (u16, u16) = (80, 24);
+
+/// Smallest virtual terminal dimension `vt100` handles without overflowing
+/// when wrapping text or placing wide characters.
+const MIN_TERMINAL_DIMENSION: u16 = 2;
+
+/// Screen contents reconstructed by replaying a session into a virtual terminal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayFrame {
+    /// Terminal width in columns when replay finished.
+    pub width: u16,
+    /// Terminal height in rows when replay finished.
+    pub height: u16,
+    /// Plain-text contents of each visible row.
+    pub rows: Vec<String>,
 }
 
 /// A backend that replays recorded JSONL session output.
@@ -52,6 +85,8 @@ pub struct ReplayBackend {
     terminal_write_indices: Vec<usize>,
     /// Last offset for timing calculations.
     last_offset_ms: u64,
+    /// Virtual terminal size (columns, rows) before any recorded resize.
+    terminal_size: (u16, u16),
 }
 
 impl ReplayBackend {
@@ -89,6 +124,7 @@ impl ReplayBackend {
             timing_mode: ReplayTimingMode::default(),
             terminal_write_indices,
             last_offset_ms: 0,
+            terminal_size: DEFAULT_TERMINAL_SIZE,
         })
     }
 
@@ -107,10 +143,57 @@ impl ReplayBackend {
         self
     }
 
+    /// Sets the virtual terminal size used by [`final_frame`](Self::final_frame)
+    /// until the first recorded resize.
+    pub fn with_terminal_size(mut self, width: u16, height: u16) -> Self {
+        self.terminal_size = (width, height);
+        self
+    }
+
+    /// Replays every terminal write and resize into a virtual terminal and
+    /// returns the resulting screen.
+    ///
+    /// Resize events change the terminal dimensions at the point they were
+    /// recorded, so later writes wrap and clear against the new size.
+    /// Degenerate sizes (e.g. a 0x0 resize captured while minimized) are
+    /// clamped to 2x2, the smallest screen the virtual terminal supports.
+    pub fn final_frame(&self) -> ReplayFrame {
+        let (width, height) = self.terminal_size;
+        let mut parser = vt100::Parser::new(
+            height.max(MIN_TERMINAL_DIMENSION),
+            width.max(MIN_TERMINAL_DIMENSION),
+            0,
+        );
+
+        for record in self.player.records() {
+            match Self::parse_ux_event(&record.record) {
+                Some(UxEvent::TerminalWrite(write)) => {
+                    if let Ok(bytes) = write.decode_bytes() {
+                        parser.process(&bytes);
+                    }
+                }
+                Some(UxEvent::TerminalResize(resize)) => {
+                    parser.set_size(
+                        resize.height.max(MIN_TERMINAL_DIMENSION),
+                        resize.width.max(MIN_TERMINAL_DIMENSION),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        let (height, width) = parser.screen().size();
+        ReplayFrame {
+            width,
+            height,
+            rows: parser.screen().rows(0, width).collect(),
+        }
+    }
+
     /// Returns the next terminal output chunk, or `None` if exhausted.
     ///
-    /// In `Realistic` timing mode, this will sleep for the recorded delay
-    /// between outputs.
+    /// In `Realistic` and `Scaled` timing modes, this will sleep for the
+    /// (scaled) recorded delay between outputs.
     pub fn next_output(&mut self) -> Option<Vec<u8>> {
         if self.position >= self.terminal_write_indices.len() {
             return None;
@@ -119,11 +202,13 @@ impl ReplayBackend {
         let record_idx = self.terminal_write_indices[self.position];
         let record = &self.player.records()[record_idx];
 
-        // Handle timing in realistic mode
-        if self.timing_mode == ReplayTimingMode::Realistic && self.position > 0 {
-            let delay_ms = record.offset_ms.saturating_sub(self.last_offset_ms);
-            if delay_ms > 0 {
-                std::thread::sleep(Duration::from_millis(delay_ms));
+        // Sleep for the recorded gap since the previous output
+        if self.position > 0 {
+            let delay = self
+                .timing_mode
+                .scale(record.offset_ms.saturating_sub(self.last_offset_ms));
+            if !delay.is_zero() {
+                std::thread::sleep(delay);
             }
         }
         self.last_offset_ms = record.offset_ms;
@@ -175,17 +260,20 @@ impl ReplayBackend {
         self.collect_remaining()
     }
 
-    /// Parses a Record's data field as terminal write bytes.
-    fn parse_terminal_write(&self, record: &crate::session_recorder::Record) -> Option<Vec<u8>> {
+    /// Parses a Record's data field as a UX event.
+    fn parse_ux_event(record: &crate::session_recorder::Record) -> Option<UxEvent> {
         // Reconstruct the tagged format for UxEvent deserialization
         let tagged = serde_json::json!({
             "event": record.event,
             "data": record.data,
         });
 
-        let ux_event: UxEvent = serde_json::from_value(tagged).ok()?;
+        serde_json::from_value(tagged).ok()
+    }
 
-        if let UxEvent::TerminalWrite(write) = ux_event {
+    /// Parses a Record's data field as terminal write bytes.
+    fn parse_terminal_write(&self, record: &crate::session_recorder::Record) -> Option<Vec<u8>> {
+        if let UxEvent::TerminalWrite(write) = Self::parse_ux_event(record)? {
             write.decode_bytes().ok()
         } else {
             None
@@ -219,6 +307,62 @@ mod tests {
         serde_json::to_string(&record).unwrap()
     }
 
+    /// Helper to create a JSONL line for a terminal resize event.
+    fn make_resize_record(width: u16, height: u16, offset_ms: u64, base_ts: u64) -> String {
+        let resize = ralph_proto::TerminalResize::new(width, height, offset_ms);
+        let record = Record {
+            ts: base_ts + offset_ms,
+            event: "ux.terminal.resize".to_string(),
+            data: serde_json::to_value(&resize).unwrap(),
+        };
+        serde_json::to_string(&record).unwrap()
+    }
+
+    #[test]
+    fn test_final_frame_without_resize_uses_initial_size() {
+        let line = make_write_record(b"abcdefgh", true, 0, 1000);
+        let backend = ReplayBackend::from_bytes(line.as_bytes())
+            .unwrap()
+            .with_terminal_size(10, 3);
+
+        let frame = backend.final_frame();
+        assert_eq!((frame.width, frame.height), (10, 3));
+        assert_eq!(frame.rows, vec!["abcdefgh", "", ""]);
+    }
+
+    #[test]
+    fn test_final_frame_applies_resize_between_writes() {
+        let jsonl = [
+            make_write_record(b"before resize", true, 0, 1000),
+            make_resize_record(5, 3, 10, 1000),
+            make_write_record(b"\x1b[2J\x1b[Habcdefgh", true, 20, 1000),
+        ]
+        .join("\n");
+        let backend = ReplayBackend::from_bytes(jsonl.as_bytes())
+            .unwrap()
+            .with_terminal_size(20, 3);
+
+        let frame = backend.final_frame();
+        assert_eq!((frame.width, frame.height), (5, 3));
+        assert_eq!(frame.rows, vec!["abcde", "fgh", ""]);
+    }
+
+    #[test]
+    fn test_final_frame_clamps_zero_size_resize() {
+        let jsonl = [
+            make_resize_record(0, 0, 0, 1000),
+            make_write_record("abc🦀".as_bytes(), true, 10, 1000),
+        ]
+        .join("\n");
+        let backend = ReplayBackend::from_bytes(jsonl.as_bytes())
+            .unwrap()
+            .with_terminal_size(0, 0);
+
+        let frame = backend.final_frame();
+        assert_eq!((frame.width, frame.height), (2, 2));
+        assert_eq!(frame.rows, vec!["c", "🦀"]);
+    }
+
     #[test]
     fn test_from_reader_loads_valid_jsonl() {
         let line1 = make_write_record(b"Hello", true, 0, 1000);
@@ -277,6 +421,43 @@ mod tests {
         assert_eq!(backend.outputs_served(), 1);
     }
 
+    fn timed_backend(mode: ReplayTimingMode) -> ReplayBackend {
+        let line1 = make_write_record(b"A", true, 0, 1000);
+        let line2 = make_write_record(b"B", true, 100, 1000);
+        ReplayBackend::from_bytes(format!("{line1}\n{line2}\n").as_bytes())
+            .unwrap()
+            .with_timing(mode)
+    }
+
+    #[test]
+    fn test_scaled_by_zero_is_near_instant() {
+        let mut backend = timed_backend(ReplayTimingMode::Scaled(0.0));
+
+        let start = std::time::Instant::now();
+        assert_eq!(backend.collect_remaining(), b"AB");
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_realistic_mode_waits_recorded_delay() {
+        let mut backend = timed_backend(ReplayTimingMode::Realistic);
+
+        let start = std::time::Instant::now();
+        assert_eq!(backend.collect_remaining(), b"AB");
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_scaled_mode_multiplies_recorded_delay() {
+        let mut backend = timed_backend(ReplayTimingMode::Scaled(0.1));
+
+        let start = std::time::Instant::now();
+        assert_eq!(backend.collect_remaining(), b"AB");
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(10));
+        assert!(elapsed < Duration::from_millis(100), "took {elapsed:?}");
+    }
+
     #[test]
     fn test_instant_mode_serves_all_immediately() {
         let line1 = make_write_record(b"A", true, 0, 1000);
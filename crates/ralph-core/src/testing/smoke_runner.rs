@@ -19,6 +19,9 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use super::ReplayBackend;
+use crate::config::RalphConfig;
+use crate::hat_registry::HatRegistry;
+use ralph_proto::Topic;
 
 /// Configuration for a smoke test run.
 #[derive(Debug, Clone)]
@@ -31,6 +34,17 @@ pub struct SmokeTestConfig {
     pub expected_iterations: Option<u32>,
     /// Expected termination reason (for validation, optional).
     pub expected_termination: Option<String>,
+    /// Completion promise that ends the run (defaults to `LOOP_COMPLETE`).
+    pub completion_promise: String,
+    /// Reassemble output across chunk boundaries before parsing.
+    ///
+    /// When false (the default) each recorded chunk is parsed on its own, so an
+    /// `<event>` tag or UTF-8 character split across two chunks is lost.
+    pub streaming: bool,
+    /// Hat configuration used to record which hat each parsed event routes to (optional).
+    pub routing_config: Option<RalphConfig>,
+    /// Event topics the fixture must emit (optional).
+    pub expected_topics: Option<Vec<String>>,
 }
 
 impl SmokeTestConfig {
@@ -41,6 +55,10 @@ impl SmokeTestConfig {
             timeout: Duration::from_secs(30),
             expected_iterations: None,
             expected_termination: None,
+            completion_promise: "LOOP_COMPLETE".to_string(),
+            streaming: false,
+            routing_config: None,
+            expected_topics: None,
         }
     }
 
@@ -61,11 +79,37 @@ impl SmokeTestConfig {
         self.expected_termination = Some(reason.into());
         self
     }
+
+    /// Sets the completion promise the fixture is expected to emit.
+    pub fn with_completion_promise(mut self, promise: impl Into<String>) -> Self {
+        self.completion_promise = promise.into();
+        self
+    }
+
+    /// Feeds chunks incrementally, parsing only once tags are complete.
+    pub fn with_streaming(mut self, streaming: bool) -> Self {
+        self.streaming = streaming;
+        self
+    }
+
+    /// Records the hat selected for each parsed event using `config`'s hats.
+    pub fn with_routing_config(mut self, config: RalphConfig) -> Self {
+        self.routing_config = Some(config);
+        self
+    }
+
+    /// Requires every topic in `topics` to be emitted before the run ends.
+    pub fn expect_topics(mut self, topics: Vec<String>) -> Self {
+        self.expected_topics = Some(topics);
+        self
+    }
 }
 
 /// Result of a smoke test run.
 #[derive(Debug, Clone)]
 pub struct SmokeTestResult {
+    /// Fixture the run replayed.
+    fixture: PathBuf,
     /// Number of event loop iterations executed.
     iterations: u32,
     /// Number of events parsed from the fixture.
@@ -74,6 +118,8 @@ pub struct SmokeTestResult {
     termination_reason: TerminationReason,
     /// Total output bytes processed.
     output_bytes: usize,
+    /// Hat selected for each parsed event, in order (empty without a routing config).
+    hat_sequence: Vec<String>,
 }
 
 /// Reason the smoke test terminated.
@@ -91,7 +137,43 @@ pub enum TerminationReason {
     Error(String),
 }
 
+impl TerminationReason {
+    /// Returns the reason's spec string (e.g. `completed`, `fixture_exhausted`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TerminationReason::Completed => "completed",
+            TerminationReason::FixtureExhausted => "fixture_exhausted",
+            TerminationReason::Timeout => "timeout",
+            TerminationReason::MaxIterations => "max_iterations",
+            TerminationReason::Error(_) => "error",
+        }
+    }
+}
+
+impl serde::Serialize for TerminationReason {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 impl SmokeTestResult {
+    /// Returns the fixture the run replayed.
+    pub fn fixture(&self) -> &Path {
+        &self.fixture
+    }
+
+    /// Returns a machine-readable summary of the run for CI reports.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "fixture": self.fixture.display().to_string(),
+            "termination_reason": self.termination_reason,
+            "iterations": self.iterations,
+            "event_count": self.events_parsed,
+            "output_bytes": self.output_bytes,
+            "completed": self.completed_successfully(),
+        })
+    }
+
     /// Returns true if the test completed successfully.
     pub fn completed_successfully(&self) -> bool {
         matches!(
@@ -119,6 +201,16 @@ impl SmokeTestResult {
     pub fn output_bytes(&self) -> usize {
         self.output_bytes
     }
+
+    /// Returns the hat ID each parsed event is routed to, in order.
+    ///
+    /// This is the highest-priority subscribing hat, not the hat that executes
+    /// (Ralph coordinates in multi-hat mode). Events no hat subscribes to route
+    /// to `ralph`. Empty unless the run was
+    /// configured with [`SmokeTestConfig::with_routing_config`].
+    pub fn hat_sequence(&self) -> &[String] {
+        &self.hat_sequence
+    }
 }
 
 /// Error types for smoke test operations.
@@ -139,6 +231,41 @@ pub enum SmokeTestError {
     /// Timeout during execution.
     #[error("Timeout after {0:?}")]
     Timeout(Duration),
+
+    /// Expected event topics were not emitted.
+    #[error("Missing expected topics {missing:?} (unexpected: {extra:?})")]
+    UnexpectedTopics {
+        /// Expected topics that never appeared.
+        missing: Vec<String>,
+        /// Observed topics that were not expected.
+        extra: Vec<String>,
+    },
+}
+
+/// Fails when the run did not emit every topic in `config.expected_topics`.
+fn check_expected_topics(
+    config: &SmokeTestConfig,
+    observed: &std::collections::BTreeSet<String>,
+) -> Result<(), SmokeTestError> {
+    let Some(expected) = &config.expected_topics else {
+        return Ok(());
+    };
+
+    let missing: Vec<String> = expected
+        .iter()
+        .filter(|topic| !observed.contains(*topic))
+        .cloned()
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let extra = observed
+        .iter()
+        .filter(|topic| !expected.contains(topic))
+        .cloned()
+        .collect();
+    Err(SmokeTestError::UnexpectedTopics { missing, extra })
 }
 
 /// Lists available fixtures in a directory.
@@ -161,6 +288,103 @@ pub fn list_fixtures(dir: impl AsRef<Path>) -> std::io::Result<Vec<PathBuf>> {
     Ok(fixtures)
 }
 
+/// Buffers streamed bytes until UTF-8 sequences are complete.
+#[derive(Default)]
+struct ChunkAssembler {
+    pending: Vec<u8>,
+}
+
+impl ChunkAssembler {
+    /// Appends `chunk` and returns the text decoded so far, holding back an
+    /// incomplete trailing UTF-8 sequence.
+    ///
+    /// Invalid byte sequences are replaced with U+FFFD so they can't stall
+    /// decoding of the output that follows them.
+    fn push(&mut self, chunk: &[u8]) -> String {
+        self.pending.extend_from_slice(chunk);
+
+        let mut text = String::new();
+        loop {
+            match std::str::from_utf8(&self.pending) {
+                Ok(valid) => {
+                    text.push_str(valid);
+                    self.pending.clear();
+                    return text;
+                }
+                Err(err) => {
+                    let valid = err.valid_up_to();
+                    text.push_str(&String::from_utf8_lossy(&self.pending[..valid]));
+                    let Some(invalid) = err.error_len() else {
+                        // Incomplete sequence at the end: wait for the next chunk
+                        self.pending.drain(..valid);
+                        return text;
+                    };
+                    text.push(char::REPLACEMENT_CHARACTER);
+                    self.pending.drain(..valid + invalid);
+                }
+            }
+        }
+    }
+
+    /// Returns whatever is still held back, lossily decoded, at end of output.
+    fn finish(&mut self) -> Option<String> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        let text = String::from_utf8_lossy(&self.pending).into_owned();
+        self.pending.clear();
+        Some(text)
+    }
+}
+
+/// Returns the ID of the hat whose subscription an event on `topic` targets.
+///
+/// Subscribers are ordered with the same [`HatRegistry::compare_priority`] the
+/// event loop uses to pick among hats with pending events; unclaimed topics
+/// fall back to Ralph. This is the routing target, not the executing hat: in
+/// multi-hat mode the event loop always runs Ralph as coordinator
+/// ([`EventLoop::next_hat`](crate::EventLoop::next_hat)), with the targeted
+/// hat's instructions in its prompt.
+fn select_hat(registry: &HatRegistry, topic: &Topic) -> String {
+    registry
+        .subscribers(topic)
+        .into_iter()
+        .min_by(|a, b| registry.compare_priority(&a.id, &b.id))
+        .map_or_else(|| "ralph".to_string(), |hat| hat.id.as_str().to_string())
+}
+
+/// Reads the tags for a fixture from its `.tags` sidecar.
+///
+/// The sidecar sits next to the fixture with the same stem (e.g.
+/// `basic.tags` for `basic.jsonl`) and lists tags separated by commas,
+/// whitespace or newlines. A missing sidecar means the fixture is untagged.
+pub fn fixture_tags(fixture: impl AsRef<Path>) -> std::io::Result<Vec<String>> {
+    let sidecar = fixture.as_ref().with_extension("tags");
+    match std::fs::read_to_string(&sidecar) {
+        Ok(content) => Ok(content
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|tag| !tag.is_empty())
+            .map(str::to_lowercase)
+            .collect()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Lists fixtures in a directory whose `.tags` sidecar contains `tag`.
+///
+/// Tag comparison is case-insensitive. See [`fixture_tags`] for the sidecar format.
+pub fn list_fixtures_tagged(dir: impl AsRef<Path>, tag: &str) -> std::io::Result<Vec<PathBuf>> {
+    let tag = tag.to_lowercase();
+    let mut tagged = Vec::new();
+    for fixture in list_fixtures(dir)? {
+        if fixture_tags(&fixture)?.contains(&tag) {
+            tagged.push(fixture);
+        }
+    }
+    Ok(tagged)
+}
+
 /// The smoke test runner.
 pub struct SmokeRunner;
 
@@ -186,54 +410,110 @@ impl SmokeRunner {
         let mut iterations = 0u32;
         let mut events_parsed = 0usize;
         let mut output_bytes = 0usize;
+        let mut hat_sequence = Vec::new();
+        let mut topics = std::collections::BTreeSet::new();
+        let registry = config.routing_config.as_ref().map(HatRegistry::from_config);
 
-        let start_time = std::time::Instant::now();
+        let mut assembler = ChunkAssembler::default();
+        let mut streaming_parser = crate::StreamingEventParser::new();
 
-        // Process all output chunks
-        while let Some(chunk) = backend.next_output() {
-            // Check timeout
-            if start_time.elapsed() > config.timeout {
-                return Ok(SmokeTestResult {
-                    iterations,
-                    events_parsed,
-                    termination_reason: TerminationReason::Timeout,
-                    output_bytes,
-                });
-            }
-
-            output_bytes += chunk.len();
+        let start_time = std::time::Instant::now();
 
-            // Convert chunk to string and parse events
-            if let Ok(output) = String::from_utf8(chunk) {
-                let parser = crate::EventParser::new();
-                let events = parser.parse(&output);
+        // Process all output chunks, then flush a truncated trailing sequence
+        loop {
+            let (output, flushed) = match backend.next_output() {
+                Some(chunk) => {
+                    // Check timeout
+                    if start_time.elapsed() > config.timeout {
+                        return Ok(SmokeTestResult {
+                            fixture: config.fixture_path.clone(),
+                            iterations,
+                            events_parsed,
+                            termination_reason: TerminationReason::Timeout,
+                            output_bytes,
+                            hat_sequence,
+                        });
+                    }
+
+                    output_bytes += chunk.len();
+
+                    // Convert chunk to string and parse events
+                    let output = if config.streaming {
+                        Some(assembler.push(&chunk))
+                    } else {
+                        String::from_utf8(chunk).ok()
+                    };
+                    (output, false)
+                }
+                None => match assembler.finish() {
+                    Some(rest) => (Some(rest), true),
+                    None => break,
+                },
+            };
+            if let Some(output) = output {
+                let events = if config.streaming {
+                    streaming_parser.push(&output)
+                } else {
+                    crate::EventParser::new().parse(&output)
+                };
                 events_parsed += events.len();
+                topics.extend(events.iter().map(|event| event.topic.to_string()));
+
+                if let Some(registry) = &registry {
+                    hat_sequence.extend(
+                        events
+                            .iter()
+                            .map(|event| select_hat(registry, &event.topic)),
+                    );
+                }
 
-                // Check for completion event (must be emitted as an event)
-                if events
-                    .iter()
-                    .any(|event| event.topic.as_str() == "LOOP_COMPLETE")
+                // Completion is an event on the promise topic. Unlike the live loop,
+                // which only honors the completion event, the smoke runner also
+                // accepts the promise as the final line outside event tags
+                let promise = config.completion_promise.as_str();
+                if events.iter().any(|event| event.topic.as_str() == promise)
+                    || crate::EventParser::contains_promise(&output, promise)
                 {
+                    check_expected_topics(config, &topics)?;
                     return Ok(SmokeTestResult {
+                        fixture: config.fixture_path.clone(),
                         iterations,
                         events_parsed,
                         termination_reason: TerminationReason::Completed,
                         output_bytes,
+                        hat_sequence,
                     });
                 }
             }
 
-            iterations += 1;
+            if !flushed {
+                iterations += 1;
+            }
         }
 
         // Fixture exhausted
+        check_expected_topics(config, &topics)?;
         Ok(SmokeTestResult {
+            fixture: config.fixture_path.clone(),
             iterations,
             events_parsed,
             termination_reason: TerminationReason::FixtureExhausted,
             output_bytes,
+            hat_sequence,
         })
     }
+
+    /// Runs every fixture in `dir` with the default configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from listing the directory or running a fixture.
+    pub fn run_all(dir: impl AsRef<Path>) -> Result<Vec<SmokeTestResult>, SmokeTestError> {
+        list_fixtures(dir)?
+            .iter()
+            .map(|fixture| Self::run(&SmokeTestConfig::new(fixture)))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -252,10 +532,15 @@ mod tests {
 
     /// Creates a terminal write JSONL line.
     fn make_write_line(text: &str, offset_ms: u64) -> String {
+        make_write_line_bytes(text.as_bytes(), offset_ms)
+    }
+
+    /// Creates a terminal write JSONL line from raw (possibly invalid UTF-8) bytes.
+    fn make_write_line_bytes(bytes: &[u8], offset_ms: u64) -> String {
         use crate::session_recorder::Record;
         use ralph_proto::TerminalWrite;
 
-        let write = TerminalWrite::new(text.as_bytes(), true, offset_ms);
+        let write = TerminalWrite::new(bytes, true, offset_ms);
         let record = Record {
             ts: 1000 + offset_ms,
             event: "ux.terminal.write".to_string(),
@@ -438,6 +723,155 @@ coverage: pass
         assert!(fixtures.is_empty());
     }
 
+    #[test]
+    fn test_list_fixtures_tagged_filters_by_sidecar() {
+        let temp_dir = TempDir::new().unwrap();
+
+        create_fixture(temp_dir.path(), "kiro_basic.jsonl", "{}");
+        create_fixture(temp_dir.path(), "kiro_basic.tags", "kiro, regression\n");
+        create_fixture(temp_dir.path(), "claude_basic.jsonl", "{}");
+        create_fixture(temp_dir.path(), "claude_basic.tags", "claude\nRegression");
+        create_fixture(temp_dir.path(), "untagged.jsonl", "{}");
+
+        let names = |tag| {
+            list_fixtures_tagged(temp_dir.path(), tag)
+                .unwrap()
+                .iter()
+                .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names("kiro"), vec!["kiro_basic.jsonl"]);
+        assert_eq!(
+            names("regression"),
+            vec!["claude_basic.jsonl", "kiro_basic.jsonl"]
+        );
+        assert!(names("gemini").is_empty());
+        assert!(
+            fixture_tags(temp_dir.path().join("untagged.jsonl"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_custom_completion_promise() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let line1 = make_write_line(
+            r#"<event topic="build.task">Mention SHIP_IT here</event>"#,
+            0,
+        );
+        let line2 = make_write_line("All done.\nSHIP_IT\n", 100);
+        let content = format!("{line1}\n{line2}\n");
+        let fixture_path = create_fixture(temp_dir.path(), "custom.jsonl", &content);
+
+        let config = SmokeTestConfig::new(&fixture_path).with_completion_promise("SHIP_IT");
+        let result = SmokeRunner::run(&config).unwrap();
+        assert_eq!(result.termination_reason(), &TerminationReason::Completed);
+        assert_eq!(
+            result.iterations_run(),
+            1,
+            "promise inside event tag is ignored"
+        );
+
+        let config = SmokeTestConfig::new(&fixture_path).with_completion_promise("DONE_DONE");
+        let result = SmokeRunner::run(&config).unwrap();
+        assert_eq!(
+            result.termination_reason(),
+            &TerminationReason::FixtureExhausted
+        );
+    }
+
+    #[test]
+    fn test_streaming_reassembles_event_split_across_chunks() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let line1 = make_write_line(r#"Working <event topic="build.ta"#, 0);
+        let line2 = make_write_line(r#"sk">Fix the pärser</event> done"#, 100);
+        let content = format!("{line1}\n{line2}\n");
+        let fixture_path = create_fixture(temp_dir.path(), "split.jsonl", &content);
+
+        let streamed =
+            SmokeRunner::run(&SmokeTestConfig::new(&fixture_path).with_streaming(true)).unwrap();
+        assert_eq!(streamed.event_count(), 1);
+
+        let chunked = SmokeRunner::run(&SmokeTestConfig::new(&fixture_path)).unwrap();
+        assert_eq!(chunked.event_count(), 0);
+    }
+
+    #[test]
+    fn test_streaming_recovers_after_invalid_utf8() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let line1 = make_write_line_bytes(b"garbage \xff\xfe ", 0);
+        let line2 = make_write_line(r#"<event topic="build.task">Fix it</event>"#, 100);
+        let content = format!("{line1}\n{line2}\n");
+        let fixture_path = create_fixture(temp_dir.path(), "invalid.jsonl", &content);
+
+        let result =
+            SmokeRunner::run(&SmokeTestConfig::new(&fixture_path).with_streaming(true)).unwrap();
+        assert_eq!(result.event_count(), 1);
+    }
+
+    #[test]
+    fn test_chunk_assembler_holds_partial_utf8() {
+        let mut assembler = ChunkAssembler::default();
+        let bytes = "ä <event".as_bytes();
+
+        assert_eq!(assembler.push(&bytes[..1]), "");
+        assert_eq!(assembler.push(&bytes[1..]), "ä <event");
+        assert_eq!(assembler.finish(), None);
+    }
+
+    #[test]
+    fn test_chunk_assembler_replaces_invalid_utf8() {
+        let mut assembler = ChunkAssembler::default();
+
+        assert_eq!(assembler.push(b"ok \xff<event"), "ok \u{FFFD}<event");
+        assert_eq!(assembler.push(b" topic=\"a\">"), " topic=\"a\">");
+
+        // A truncated sequence at end of output is flushed lossily
+        assert_eq!(assembler.push(&"ä".as_bytes()[..1]), "");
+        assert_eq!(assembler.finish().as_deref(), Some("\u{FFFD}"));
+    }
+
+    #[test]
+    fn test_hat_sequence_follows_routing() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let line1 = make_write_line(r#"<event topic="build.task">Task 1</event>"#, 0);
+        let line2 = make_write_line(r#"<event topic="build.done">tests: pass</event>"#, 100);
+        let line3 = make_write_line(r#"<event topic="review.done">ok</event>"#, 200);
+        let line4 = make_write_line(r#"<event topic="LOOP_COMPLETE">done</event>"#, 300);
+        let content = format!("{line1}\n{line2}\n{line3}\n{line4}\n");
+        let fixture_path = create_fixture(temp_dir.path(), "routing.jsonl", &content);
+
+        let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+  reviewer:
+    name: "Reviewer"
+    triggers: ["build.done"]
+    publishes: ["review.done"]
+"#;
+        let ralph_config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let config = SmokeTestConfig::new(&fixture_path).with_routing_config(ralph_config);
+        let result = SmokeRunner::run(&config).unwrap();
+
+        assert_eq!(
+            result.hat_sequence(),
+            ["builder", "reviewer", "ralph", "ralph"]
+        );
+        assert_eq!(result.termination_reason(), &TerminationReason::Completed);
+
+        let unrouted = SmokeRunner::run(&SmokeTestConfig::new(&fixture_path)).unwrap();
+        assert!(unrouted.hat_sequence().is_empty());
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Additional edge cases
     // ─────────────────────────────────────────────────────────────────────────
@@ -475,10 +909,12 @@ coverage: pass
     #[test]
     fn test_result_accessors() {
         let result = SmokeTestResult {
+            fixture: PathBuf::from("test.jsonl"),
             iterations: 5,
             events_parsed: 3,
             termination_reason: TerminationReason::Completed,
             output_bytes: 1024,
+            hat_sequence: Vec::new(),
         };
 
         assert_eq!(result.iterations_run(), 5);
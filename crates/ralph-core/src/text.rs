@@ -79,6 +79,34 @@ pub fn truncate_with_ellipsis(s: &str, max_chars: usize) -> String {
     }
 }
 
+/// Common English words too generic to be useful as keywords.
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "with", "that", "this", "from", "into", "when", "then", "than", "are",
+    "was", "will", "should", "must", "can", "all", "any", "add", "use", "make",
+];
+
+/// Splits text into distinct lowercase keywords, in order of first appearance.
+///
+/// Splits on non-alphanumeric characters (keeping `-` and `_`) and drops
+/// words shorter than three bytes, the shared stop words, and any
+/// `extra_stop_words`.
+pub(crate) fn keywords(text: &str, extra_stop_words: &[&str]) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    for word in text
+        .split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+        .map(str::to_lowercase)
+    {
+        if word.len() >= 3
+            && !STOP_WORDS.contains(&word.as_str())
+            && !extra_stop_words.contains(&word.as_str())
+            && !words.contains(&word)
+        {
+            words.push(word);
+        }
+    }
+    words
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,4 +201,12 @@ mod tests {
         assert_eq!(truncate_with_ellipsis("hello", 1), "h...");
         assert_eq!(truncate_with_ellipsis("🎉hello", 1), "🎉...");
     }
+
+    #[test]
+    fn test_keywords_drops_short_stop_and_duplicate_words() {
+        assert_eq!(
+            keywords("Add the user-login flow; login FLOW for users", &["users"]),
+            vec!["user-login", "flow", "login"]
+        );
+    }
 }
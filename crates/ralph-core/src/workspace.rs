@@ -28,12 +28,22 @@
 //! # Ok::<(), ralph_core::workspace::WorkspaceError>(())
 //! ```
 
+use crate::loop_lock::LoopLock;
 use crate::task_definition::{TaskDefinition, Verification};
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default maximum workspace age for `on_age` when parsed from a string (24 hours).
+pub const DEFAULT_CLEANUP_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Default size limit for `larger_than` when parsed from a string (1 GiB).
+pub const DEFAULT_CLEANUP_MAX_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// Default free-space threshold for [`CleanupPolicy::OnDiskPressure`] (1 GiB).
+pub const DEFAULT_MIN_FREE_BYTES: u64 = 1024 * 1024 * 1024;
 
 /// Cleanup policy for workspace directories.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -50,6 +60,20 @@ pub enum CleanupPolicy {
 
     /// Keep all workspaces (manual cleanup).
     Never,
+
+    /// Delete workspaces older than the given age, regardless of outcome.
+    OnAge(Duration),
+
+    /// Delete when free space on the workspace filesystem drops below the
+    /// manager's threshold (see [`WorkspaceManager::with_min_free_bytes`]).
+    OnDiskPressure,
+
+    /// Delete workspaces whose on-disk size exceeds the given number of bytes.
+    LargerThan(u64),
+
+    /// Delete workspaces with no loop activity for longer than the given
+    /// duration, skipping any that still hold a live [`LoopLock`].
+    IdleLongerThan(Duration),
 }
 
 impl CleanupPolicy {
@@ -61,6 +85,10 @@ impl CleanupPolicy {
             "on_success" => CleanupPolicy::OnSuccess,
             "always" => CleanupPolicy::Always,
             "never" => CleanupPolicy::Never,
+            "on_age" => CleanupPolicy::OnAge(DEFAULT_CLEANUP_MAX_AGE),
+            "on_disk_pressure" => CleanupPolicy::OnDiskPressure,
+            "larger_than" => CleanupPolicy::LargerThan(DEFAULT_CLEANUP_MAX_BYTES),
+            "idle_longer_than" => CleanupPolicy::IdleLongerThan(DEFAULT_CLEANUP_MAX_AGE),
             _ => CleanupPolicy::OnSuccess,
         }
     }
@@ -169,6 +197,8 @@ impl TaskWorkspace {
     /// 1. The prompt file as `PROMPT.md`
     /// 2. Any setup files specified in the task definition
     ///
+    /// and appends the task's `gitignore_template` entries to `.gitignore`.
+    ///
     /// # Arguments
     ///
     /// * `task` - The task definition containing setup configuration
@@ -237,6 +267,8 @@ impl TaskWorkspace {
             }
         }
 
+        self.apply_gitignore_template(&task.setup.gitignore_template)?;
+
         // Create initial git commit
         Command::new("git")
             .args(["add", "-A"])
@@ -259,6 +291,37 @@ impl TaskWorkspace {
         Ok(())
     }
 
+    /// Appends template entries missing from the workspace `.gitignore`.
+    ///
+    /// Idempotent: entries already present (ignoring surrounding whitespace)
+    /// are skipped, so provisioning the same workspace twice is harmless.
+    fn apply_gitignore_template(&self, entries: &[String]) -> Result<(), WorkspaceError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let gitignore_path = self.path.join(".gitignore");
+        let mut contents = fs::read_to_string(&gitignore_path).unwrap_or_default();
+        let original_len = contents.len();
+
+        for entry in entries {
+            let entry = entry.trim();
+            if entry.is_empty() || contents.lines().any(|line| line.trim() == entry) {
+                continue;
+            }
+            if !contents.is_empty() && !contents.ends_with('\n') {
+                contents.push('\n');
+            }
+            contents.push_str(entry);
+            contents.push('\n');
+        }
+
+        if contents.len() != original_len {
+            fs::write(&gitignore_path, contents)?;
+        }
+        Ok(())
+    }
+
     /// Cleans up (removes) the workspace directory.
     ///
     /// # Errors
@@ -281,6 +344,15 @@ impl TaskWorkspace {
     pub fn is_cleaned_up(&self) -> bool {
         self.cleaned_up
     }
+
+    /// Returns how long ago the workspace was created.
+    pub fn age(&self) -> Duration {
+        let created = Duration::from_millis(self.created_at);
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|now| now.saturating_sub(created))
+            .unwrap_or_default()
+    }
 }
 
 impl Drop for TaskWorkspace {
@@ -296,6 +368,28 @@ impl Drop for TaskWorkspace {
     }
 }
 
+/// Number of trailing output lines kept per verification step.
+const OUTPUT_TAIL_LINES: usize = 20;
+
+/// Outcome of a single verification step.
+#[derive(Debug, Clone)]
+pub struct VerificationStep {
+    /// The command that was run.
+    pub command: String,
+
+    /// Actual exit code from the command (-1 if killed by a signal).
+    pub exit_code: i32,
+
+    /// Expected exit code for success.
+    pub expected_exit_code: i32,
+
+    /// Whether the step passed (exit code matched expected).
+    pub passed: bool,
+
+    /// Last lines of combined stdout and stderr, for diagnosing failures.
+    pub output_tail: String,
+}
+
 /// Result of running a verification command.
 #[derive(Debug, Clone)]
 pub struct VerificationResult {
@@ -313,6 +407,11 @@ pub struct VerificationResult {
 
     /// Stderr output from the command.
     pub stderr: String,
+
+    /// Per-step results in execution order.
+    ///
+    /// Execution stops at the first failing step, so a failure is always last.
+    pub steps: Vec<VerificationStep>,
 }
 
 impl VerificationResult {
@@ -320,6 +419,11 @@ impl VerificationResult {
     pub fn summary(&self) -> String {
         if self.passed {
             format!("PASSED (exit code {})", self.exit_code)
+        } else if let Some(step) = self.failed_step() {
+            format!(
+                "FAILED (exit code {}, expected {}) running `{}`",
+                step.exit_code, step.expected_exit_code, step.command
+            )
         } else {
             format!(
                 "FAILED (exit code {}, expected {})",
@@ -327,6 +431,11 @@ impl VerificationResult {
             )
         }
     }
+
+    /// Returns the step that failed, if any.
+    pub fn failed_step(&self) -> Option<&VerificationStep> {
+        self.steps.iter().find(|step| !step.passed)
+    }
 }
 
 impl TaskWorkspace {
@@ -350,52 +459,85 @@ impl TaskWorkspace {
         &self,
         verification: &Verification,
     ) -> Result<VerificationResult, WorkspaceError> {
-        if verification.command.is_empty() {
-            // No verification command - consider it passed
-            return Ok(VerificationResult {
-                passed: true,
-                exit_code: 0,
-                expected_exit_code: 0,
-                stdout: String::new(),
-                stderr: String::new(),
-            });
-        }
+        self.run_verification_steps(std::slice::from_ref(verification))
+    }
 
-        tracing::debug!(
-            "Running verification in {}: {}",
-            self.path.display(),
-            verification.command
-        );
+    /// Runs verification steps in order, stopping at the first failure.
+    ///
+    /// Steps with an empty command are skipped. The top-level exit code and
+    /// output of the returned `VerificationResult` come from the last step run;
+    /// each step's outcome is recorded in `steps`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WorkspaceError::Verification` if a command fails to execute.
+    pub fn run_verification_steps(
+        &self,
+        steps: &[Verification],
+    ) -> Result<VerificationResult, WorkspaceError> {
+        let mut result = VerificationResult {
+            passed: true,
+            exit_code: 0,
+            expected_exit_code: 0,
+            stdout: String::new(),
+            stderr: String::new(),
+            steps: Vec::new(),
+        };
 
-        let output = Command::new("bash")
-            .args(["-c", &verification.command])
-            .current_dir(&self.path)
-            .output()
-            .map_err(|e| WorkspaceError::Verification(format!("Failed to execute: {}", e)))?;
+        for verification in steps.iter().filter(|v| !v.command.is_empty()) {
+            tracing::debug!(
+                "Running verification in {}: {}",
+                self.path.display(),
+                verification.command
+            );
 
-        let exit_code = output.status.code().unwrap_or(-1);
-        let passed = exit_code == verification.success_exit_code;
+            let output = Command::new("bash")
+                .args(["-c", &verification.command])
+                .current_dir(&self.path)
+                .output()
+                .map_err(|e| WorkspaceError::Verification(format!("Failed to execute: {}", e)))?;
 
-        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            let exit_code = output.status.code().unwrap_or(-1);
+            let passed = exit_code == verification.success_exit_code;
 
-        tracing::debug!(
-            "Verification result: {} (exit code {}, expected {})",
-            if passed { "PASSED" } else { "FAILED" },
-            exit_code,
-            verification.success_exit_code
-        );
+            let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
 
-        Ok(VerificationResult {
-            passed,
-            exit_code,
-            expected_exit_code: verification.success_exit_code,
-            stdout,
-            stderr,
-        })
+            tracing::debug!(
+                "Verification result: {} (exit code {}, expected {})",
+                if passed { "PASSED" } else { "FAILED" },
+                exit_code,
+                verification.success_exit_code
+            );
+
+            result.steps.push(VerificationStep {
+                command: verification.command.clone(),
+                exit_code,
+                expected_exit_code: verification.success_exit_code,
+                passed,
+                output_tail: output_tail(&stdout, &stderr, OUTPUT_TAIL_LINES),
+            });
+            result.passed = passed;
+            result.exit_code = exit_code;
+            result.expected_exit_code = verification.success_exit_code;
+            result.stdout = stdout;
+            result.stderr = stderr;
+
+            if !passed {
+                break;
+            }
+        }
+
+        Ok(result)
     }
 }
 
+/// Returns the last `max_lines` lines of stdout followed by stderr.
+fn output_tail(stdout: &str, stderr: &str, max_lines: usize) -> String {
+    let lines: Vec<&str> = stdout.lines().chain(stderr.lines()).collect();
+    lines[lines.len().saturating_sub(max_lines)..].join("\n")
+}
+
 /// Manages workspace cleanup according to a policy.
 #[derive(Debug)]
 pub struct WorkspaceManager {
@@ -404,6 +546,9 @@ pub struct WorkspaceManager {
 
     /// Cleanup policy to apply.
     policy: CleanupPolicy,
+
+    /// Free-space threshold used by [`CleanupPolicy::OnDiskPressure`].
+    min_free_bytes: u64,
 }
 
 impl WorkspaceManager {
@@ -412,9 +557,16 @@ impl WorkspaceManager {
         Self {
             base_dir: base_dir.into(),
             policy,
+            min_free_bytes: DEFAULT_MIN_FREE_BYTES,
         }
     }
 
+    /// Sets the free-space threshold for [`CleanupPolicy::OnDiskPressure`].
+    pub fn with_min_free_bytes(mut self, min_free_bytes: u64) -> Self {
+        self.min_free_bytes = min_free_bytes;
+        self
+    }
+
     /// Returns the base directory.
     pub fn base_dir(&self) -> &Path {
         &self.base_dir
@@ -464,7 +616,107 @@ impl WorkspaceManager {
                 self.rotate_workspaces(keep_last_n)?;
                 Ok(false)
             }
+            CleanupPolicy::OnAge(max_age) => {
+                self.remove_workspaces_older_than(max_age)?;
+                if workspace.age() >= max_age {
+                    workspace.cleanup()?;
+                    return Ok(true);
+                }
+                Ok(false)
+            }
+            CleanupPolicy::OnDiskPressure => match available_bytes(&self.base_dir) {
+                Some(free) if free < self.min_free_bytes => {
+                    tracing::debug!(
+                        "Disk pressure ({} bytes free, threshold {}), removing {}",
+                        free,
+                        self.min_free_bytes,
+                        workspace.path().display()
+                    );
+                    workspace.cleanup()?;
+                    Ok(true)
+                }
+                _ => Ok(false),
+            },
+            CleanupPolicy::LargerThan(max_bytes) => {
+                let oversized = dir_size(workspace.path()) > max_bytes;
+                if oversized {
+                    workspace.cleanup()?;
+                }
+                self.remove_workspaces_larger_than(max_bytes)?;
+                Ok(oversized)
+            }
+            CleanupPolicy::IdleLongerThan(max_idle) => {
+                let idle = is_idle_and_unlocked(workspace.path(), max_idle);
+                if idle {
+                    workspace.cleanup()?;
+                }
+                self.remove_workspaces_idle_longer_than(max_idle)?;
+                Ok(idle)
+            }
+        }
+    }
+
+    /// Removes workspaces with no loop activity for longer than `max_idle`.
+    ///
+    /// Workspaces without any activity files, or whose loop lock is still
+    /// held, are left in place.
+    pub fn remove_workspaces_idle_longer_than(
+        &self,
+        max_idle: Duration,
+    ) -> Result<(), WorkspaceError> {
+        for info in self.list_workspaces()? {
+            if is_idle_and_unlocked(&info.path, max_idle) {
+                tracing::debug!("Removing idle workspace: {}", info.path.display());
+                fs::remove_dir_all(&info.path)?;
+            }
         }
+
+        Ok(())
+    }
+
+    /// Removes workspaces whose on-disk size exceeds `max_bytes`.
+    pub fn remove_workspaces_larger_than(&self, max_bytes: u64) -> Result<(), WorkspaceError> {
+        for info in self.list_workspaces()? {
+            let size_bytes = info.size_bytes();
+            if size_bytes > max_bytes {
+                tracing::debug!(
+                    "Removing oversized workspace ({} bytes): {}",
+                    size_bytes,
+                    info.path.display()
+                );
+                fs::remove_dir_all(&info.path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the combined on-disk size of all workspaces, in bytes.
+    pub fn total_disk_usage(&self) -> Result<u64, WorkspaceError> {
+        Ok(self
+            .list_workspaces()?
+            .iter()
+            .map(WorkspaceInfo::size_bytes)
+            .sum())
+    }
+
+    /// Removes workspaces whose directory timestamp is older than `max_age`.
+    pub fn remove_workspaces_older_than(&self, max_age: Duration) -> Result<(), WorkspaceError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        for info in self.list_workspaces()? {
+            let Some(ts) = info.timestamp else {
+                continue;
+            };
+            if now.saturating_sub(Duration::from_millis(ts)) >= max_age {
+                tracing::debug!("Removing aged workspace: {}", info.path.display());
+                fs::remove_dir_all(&info.path)?;
+            }
+        }
+
+        Ok(())
     }
 
     /// Rotates old workspaces, keeping only the last N.
@@ -495,7 +747,7 @@ impl WorkspaceManager {
         }
 
         // Sort by timestamp (newest first)
-        workspaces.sort_by(|a, b| b.1.cmp(&a.1));
+        workspaces.sort_by_key(|w| std::cmp::Reverse(w.1));
 
         // Delete workspaces beyond keep_last_n
         for (path, _) in workspaces.into_iter().skip(keep_last_n) {
@@ -529,15 +781,17 @@ impl WorkspaceManager {
             }
             let timestamp = extract_timestamp(name);
             let task_name = extract_task_name(name);
+            let last_activity = last_activity(&path);
             workspaces.push(WorkspaceInfo {
                 path,
                 task_name,
                 timestamp,
+                last_activity,
             });
         }
 
         // Sort by timestamp (newest first)
-        workspaces.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        workspaces.sort_by_key(|w| std::cmp::Reverse(w.timestamp));
 
         Ok(workspaces)
     }
@@ -554,6 +808,19 @@ pub struct WorkspaceInfo {
 
     /// Timestamp extracted from directory name.
     pub timestamp: Option<u64>,
+
+    /// Newest modification time among `.ralph/events*.jsonl` and the scratchpad.
+    pub last_activity: Option<SystemTime>,
+}
+
+impl WorkspaceInfo {
+    /// Returns the total size of the files under the workspace directory, in bytes.
+    ///
+    /// Walks the whole tree on each call, so callers that only need names or
+    /// timestamps should not ask for it.
+    pub fn size_bytes(&self) -> u64 {
+        dir_size(&self.path)
+    }
 }
 
 /// Errors that can occur during workspace operations.
@@ -603,6 +870,77 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> io::Result<()> {
     Ok(())
 }
 
+/// Returns the total size of regular files under `path`, in bytes.
+///
+/// Symlinks are not followed, and entries that disappear or cannot be read
+/// mid-walk are skipped rather than failing the whole measurement.
+fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let metadata = fs::symlink_metadata(entry.path()).ok()?;
+            if metadata.is_dir() {
+                Some(dir_size(&entry.path()))
+            } else if metadata.is_file() {
+                Some(metadata.len())
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+/// Returns the newest modification time among the workspace's event logs
+/// (`.ralph/events*.jsonl`) and its scratchpad.
+fn last_activity(workspace: &Path) -> Option<SystemTime> {
+    let ralph_dir = workspace.join(".ralph");
+    let event_logs = fs::read_dir(&ralph_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "jsonl")
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with("events"))
+        });
+
+    event_logs
+        .chain(std::iter::once(ralph_dir.join("agent/scratchpad.md")))
+        .filter_map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .max()
+}
+
+/// Returns true when the workspace has been idle longer than `max_idle` and
+/// no loop holds its lock. Lock probe failures count as locked.
+fn is_idle_and_unlocked(workspace: &Path, max_idle: Duration) -> bool {
+    let Some(last) = last_activity(workspace) else {
+        return false;
+    };
+    let idle_for = SystemTime::now().duration_since(last).unwrap_or_default();
+    idle_for > max_idle && !LoopLock::is_locked(workspace).unwrap_or(true)
+}
+
+/// Returns the bytes available to unprivileged users on the filesystem
+/// containing `path`, or `None` if it cannot be determined.
+#[cfg(unix)]
+fn available_bytes(path: &Path) -> Option<u64> {
+    let stat = nix::sys::statvfs::statvfs(path).ok()?;
+    #[allow(clippy::unnecessary_cast)] // Field widths differ across platforms
+    Some(stat.blocks_available() as u64 * stat.fragment_size() as u64)
+}
+
+#[cfg(not(unix))]
+fn available_bytes(_path: &Path) -> Option<u64> {
+    None
+}
+
 /// Extracts timestamp from workspace directory name.
 ///
 /// Format: `ralph-bench-{task_name}-{timestamp}`
@@ -724,6 +1062,30 @@ mod tests {
         assert!(workspace.path().join("helper.py").exists());
     }
 
+    #[test]
+    fn test_workspace_setup_applies_gitignore_template_idempotently() {
+        let temp_dir = TempDir::new().unwrap();
+        let tasks_dir = TempDir::new().unwrap();
+
+        let prompt_dir = tasks_dir.path().join("tasks/test");
+        fs::create_dir_all(&prompt_dir).unwrap();
+        fs::write(prompt_dir.join("PROMPT.md"), "# Test").unwrap();
+
+        let task = TaskDefinition::builder("gitignore-test", "tasks/test/PROMPT.md", "DONE")
+            .verification_command("echo ok")
+            .gitignore_template(vec!["*.log".to_string(), "target/".to_string()])
+            .build();
+
+        let workspace = TaskWorkspace::create(&task, temp_dir.path()).unwrap();
+        fs::write(workspace.path().join(".gitignore"), "target/").unwrap();
+
+        workspace.setup(&task, tasks_dir.path()).unwrap();
+        workspace.setup(&task, tasks_dir.path()).unwrap();
+
+        let gitignore = fs::read_to_string(workspace.path().join(".gitignore")).unwrap();
+        assert_eq!(gitignore, "target/\n*.log\n");
+    }
+
     #[test]
     fn test_workspace_setup_missing_prompt() {
         let temp_dir = TempDir::new().unwrap();
@@ -759,6 +1121,22 @@ mod tests {
             CleanupPolicy::from_str("ROTATE", Some(3)),
             CleanupPolicy::Rotate(3)
         );
+        assert_eq!(
+            CleanupPolicy::from_str("on_age", None),
+            CleanupPolicy::OnAge(DEFAULT_CLEANUP_MAX_AGE)
+        );
+        assert_eq!(
+            CleanupPolicy::from_str("on_disk_pressure", None),
+            CleanupPolicy::OnDiskPressure
+        );
+        assert_eq!(
+            CleanupPolicy::from_str("larger_than", None),
+            CleanupPolicy::LargerThan(DEFAULT_CLEANUP_MAX_BYTES)
+        );
+        assert_eq!(
+            CleanupPolicy::from_str("idle_longer_than", None),
+            CleanupPolicy::IdleLongerThan(DEFAULT_CLEANUP_MAX_AGE)
+        );
         assert_eq!(
             CleanupPolicy::from_str("unknown", None),
             CleanupPolicy::OnSuccess
@@ -857,6 +1235,169 @@ mod tests {
         assert!(path_failure.exists());
     }
 
+    #[test]
+    fn test_workspace_manager_apply_cleanup_never() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = WorkspaceManager::new(temp_dir.path(), CleanupPolicy::Never);
+        let task = make_test_task("never-cleanup");
+
+        let mut ws = manager.create_workspace(&task).unwrap();
+        assert!(!manager.apply_cleanup(&mut ws, true).unwrap());
+        assert!(ws.path().exists());
+    }
+
+    #[test]
+    fn test_workspace_manager_apply_cleanup_on_age() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = WorkspaceManager::new(
+            temp_dir.path(),
+            CleanupPolicy::OnAge(Duration::from_secs(3600)),
+        );
+        let task = make_test_task("on-age-cleanup");
+
+        // Fresh workspace survives, even on success
+        let mut fresh = manager.create_workspace(&task).unwrap();
+        assert!(!manager.apply_cleanup(&mut fresh, true).unwrap());
+        assert!(fresh.path().exists());
+
+        // A stale sibling left by an earlier run is swept
+        let stale_dir = temp_dir.path().join("ralph-bench-old-1000");
+        fs::create_dir_all(&stale_dir).unwrap();
+
+        // A workspace older than the limit is removed, even on failure
+        let mut old = manager.create_workspace(&task).unwrap();
+        old.created_at = 0;
+        assert!(manager.apply_cleanup(&mut old, false).unwrap());
+        assert!(!old.path().exists());
+        assert!(!stale_dir.exists());
+        assert!(fresh.path().exists());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_workspace_manager_apply_cleanup_on_disk_pressure() {
+        let temp_dir = TempDir::new().unwrap();
+        let task = make_test_task("disk-pressure-cleanup");
+
+        // Threshold of zero: never under pressure
+        let relaxed = WorkspaceManager::new(temp_dir.path(), CleanupPolicy::OnDiskPressure)
+            .with_min_free_bytes(0);
+        let mut ws = relaxed.create_workspace(&task).unwrap();
+        assert!(!relaxed.apply_cleanup(&mut ws, true).unwrap());
+        assert!(ws.path().exists());
+
+        // Unreachable threshold: always under pressure
+        let pressured = WorkspaceManager::new(temp_dir.path(), CleanupPolicy::OnDiskPressure)
+            .with_min_free_bytes(u64::MAX);
+        assert!(pressured.apply_cleanup(&mut ws, false).unwrap());
+        assert!(!ws.path().exists());
+    }
+
+    #[test]
+    fn test_workspace_manager_reports_disk_usage() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = WorkspaceManager::new(temp_dir.path(), CleanupPolicy::Never);
+
+        let big = temp_dir.path().join("ralph-bench-big-1000");
+        fs::create_dir_all(big.join("nested")).unwrap();
+        fs::write(big.join("nested/blob.bin"), vec![0u8; 64 * 1024]).unwrap();
+        let small = temp_dir.path().join("ralph-bench-small-2000");
+        fs::create_dir_all(&small).unwrap();
+        fs::write(small.join("note.txt"), "0123456789").unwrap();
+
+        let list = manager.list_workspaces().unwrap();
+        let size_of = |dir: &Path| list.iter().find(|i| i.path == dir).unwrap().size_bytes();
+        assert_eq!(size_of(&big), 64 * 1024);
+        assert_eq!(size_of(&small), 10);
+        assert_eq!(manager.total_disk_usage().unwrap(), 64 * 1024 + 10);
+    }
+
+    #[test]
+    fn test_workspace_manager_apply_cleanup_larger_than() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager =
+            WorkspaceManager::new(temp_dir.path(), CleanupPolicy::LargerThan(1024 * 1024));
+        let task = make_test_task("larger-than-cleanup");
+
+        let big = temp_dir.path().join("ralph-bench-big-1000");
+        fs::create_dir_all(&big).unwrap();
+        fs::write(big.join("blob.bin"), vec![0u8; 2 * 1024 * 1024]).unwrap();
+        let small = temp_dir.path().join("ralph-bench-small-2000");
+        fs::create_dir_all(&small).unwrap();
+        fs::write(small.join("note.txt"), "tiny").unwrap();
+
+        // The current workspace is small, so it survives; the large sibling is swept
+        let mut ws = manager.create_workspace(&task).unwrap();
+        fs::write(ws.path().join("out.txt"), "result").unwrap();
+        let current_size = dir_size(ws.path());
+        assert!(
+            current_size < 1024 * 1024,
+            "fresh workspace is {current_size} bytes"
+        );
+        assert!(!manager.apply_cleanup(&mut ws, true).unwrap());
+        assert!(ws.path().exists());
+        assert!(!big.exists());
+        assert!(small.exists());
+
+        // Once the current workspace grows past the limit, it is removed
+        fs::write(ws.path().join("artifact.bin"), vec![0u8; 2 * 1024 * 1024]).unwrap();
+        assert!(manager.apply_cleanup(&mut ws, false).unwrap());
+        assert!(!ws.path().exists());
+        assert!(small.exists());
+    }
+
+    fn backdate(path: &Path, age: Duration) {
+        let file = fs::File::options().append(true).open(path).unwrap();
+        file.set_modified(SystemTime::now() - age).unwrap();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_workspace_manager_apply_cleanup_idle_longer_than() {
+        let temp_dir = TempDir::new().unwrap();
+        let max_idle = Duration::from_secs(3600);
+        let manager =
+            WorkspaceManager::new(temp_dir.path(), CleanupPolicy::IdleLongerThan(max_idle));
+        let task = make_test_task("idle-cleanup");
+        let two_hours = Duration::from_secs(2 * 3600);
+
+        // Idle sibling whose newest event log is two hours old
+        let idle = temp_dir.path().join("ralph-bench-idle-1000");
+        fs::create_dir_all(idle.join(".ralph")).unwrap();
+        fs::write(idle.join(".ralph/events-20240101.jsonl"), "{}\n").unwrap();
+        backdate(&idle.join(".ralph/events-20240101.jsonl"), two_hours);
+
+        // Equally idle sibling, but a loop still holds its lock
+        let locked = manager.create_workspace(&task).unwrap();
+        backdate(&locked.path().join(".ralph/agent/scratchpad.md"), two_hours);
+        let _guard = LoopLock::try_acquire(locked.path(), "idle").unwrap();
+
+        let info = manager
+            .list_workspaces()
+            .unwrap()
+            .into_iter()
+            .find(|i| i.path == idle)
+            .unwrap();
+        let idle_for = SystemTime::now()
+            .duration_since(info.last_activity.unwrap())
+            .unwrap();
+        assert!(idle_for >= Duration::from_secs(3600));
+
+        // A freshly touched workspace survives while the idle sibling is swept
+        std::thread::sleep(Duration::from_millis(10));
+        let mut fresh = manager.create_workspace(&task).unwrap();
+        assert!(!manager.apply_cleanup(&mut fresh, true).unwrap());
+        assert!(fresh.path().exists());
+        assert!(!idle.exists());
+        assert!(locked.path().exists());
+
+        // Once the current workspace goes idle, it is removed
+        backdate(&fresh.path().join(".ralph/agent/scratchpad.md"), two_hours);
+        assert!(manager.apply_cleanup(&mut fresh, false).unwrap());
+        assert!(!fresh.path().exists());
+        assert!(locked.path().exists());
+    }
+
     #[test]
     fn test_workspace_manager_list_workspaces() {
         let temp_dir = TempDir::new().unwrap();
@@ -990,6 +1531,38 @@ mod tests {
         assert!(result.stderr.contains("stderr message"));
     }
 
+    #[test]
+    fn test_run_verification_steps_captures_failing_step() {
+        let temp_dir = TempDir::new().unwrap();
+        let task = make_test_task("verify-steps");
+        let workspace = TaskWorkspace::create(&task, temp_dir.path()).unwrap();
+
+        let steps = [
+            Verification::new("echo lint ok"),
+            Verification::new("for i in $(seq 1 30); do echo line $i; done; echo boom >&2; exit 3"),
+            Verification::new("echo never runs"),
+        ];
+
+        let result = workspace.run_verification_steps(&steps).unwrap();
+
+        assert!(!result.passed);
+        assert_eq!(
+            result.steps.len(),
+            2,
+            "execution stops at the first failure"
+        );
+        assert!(result.steps[0].passed);
+
+        let failed = result.failed_step().unwrap();
+        assert_eq!(failed.exit_code, 3);
+        assert_eq!(failed.expected_exit_code, 0);
+        assert!(failed.command.contains("exit 3"));
+        assert_eq!(failed.output_tail.lines().count(), OUTPUT_TAIL_LINES);
+        assert!(failed.output_tail.ends_with("line 30\nboom"));
+        assert!(!failed.output_tail.contains("line 10\n"));
+        assert!(result.summary().contains("exit 3"));
+    }
+
     #[test]
     fn test_verification_result_summary() {
         let passed_result = VerificationResult {
@@ -998,6 +1571,7 @@ mod tests {
             expected_exit_code: 0,
             stdout: String::new(),
             stderr: String::new(),
+            steps: Vec::new(),
         };
         assert_eq!(passed_result.summary(), "PASSED (exit code 0)");
 
@@ -1007,6 +1581,7 @@ mod tests {
             expected_exit_code: 0,
             stdout: String::new(),
             stderr: String::new(),
+            steps: Vec::new(),
         };
         assert_eq!(failed_result.summary(), "FAILED (exit code 1, expected 0)");
     }
@@ -83,7 +83,7 @@ pub struct Worktree {
 }
 
 /// Statistics about files synced to a worktree.
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct SyncStats {
     /// Number of untracked files copied.
     pub untracked_copied: usize,
@@ -95,6 +95,35 @@ pub struct SyncStats {
     pub errors: usize,
 }
 
+impl SyncStats {
+    /// Returns the statistics the real sync would report for `plan`.
+    ///
+    /// Counts follow the sync's own classification: untracked files count as
+    /// untracked copies even when already present in the worktree, and
+    /// excluded or vanished paths count as skipped.
+    pub fn from_plan(plan: &SyncPlan) -> Self {
+        plan.stats.clone()
+    }
+}
+
+/// Preview of what syncing the working directory into a worktree would change.
+///
+/// Paths are relative to the repository root.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SyncPlan {
+    /// Files that do not yet exist in the worktree.
+    pub to_create: Vec<PathBuf>,
+    /// Files that already exist in the worktree and would be replaced.
+    pub to_overwrite: Vec<PathBuf>,
+    /// Files in the worktree that are missing from the working directory:
+    /// files deleted there, and extra files only the worktree has.
+    ///
+    /// [`sync_working_directory_to_worktree`] leaves these in place.
+    pub to_delete: Vec<PathBuf>,
+    /// Statistics the real sync would report; see [`SyncStats::from_plan`].
+    pub stats: SyncStats,
+}
+
 /// Errors that can occur during worktree operations.
 #[derive(Debug, thiserror::Error)]
 pub enum WorktreeError {
@@ -594,65 +623,25 @@ pub fn sync_working_directory_to_worktree(
 ) -> Result<SyncStats, WorktreeError> {
     let mut stats = SyncStats::default();
 
-    // Get the worktree directory name for exclusion
-    let worktree_dir = &config.worktree_dir;
-
-    // Helper to check if a path should be excluded
-    let should_exclude = |path: &Path| -> bool {
-        let path_str = path.to_string_lossy();
-        // Exclude .git directory
-        if path_str.starts_with(".git/") || path_str == ".git" {
-            return true;
-        }
-        // Exclude the worktree directory itself
-        let worktree_dir_str = worktree_dir.to_string_lossy();
-        if path_str.starts_with(&*worktree_dir_str)
-            || path_str.starts_with(&format!("{}/", worktree_dir_str))
-        {
-            return true;
-        }
-        false
-    };
-
-    // Get untracked files
-    let untracked = get_untracked_files(repo_root)?;
-    for file in untracked {
-        if should_exclude(&file) {
-            stats.skipped += 1;
-            continue;
-        }
-        match copy_file_with_structure(repo_root, worktree_path, &file) {
-            Ok(true) => {
-                tracing::trace!("Copied untracked file: {}", file.display());
-                stats.untracked_copied += 1;
-            }
-            Ok(false) => {
+    for candidate in sync_candidates(repo_root, config)? {
+        let (file, kind, copied) = match candidate {
+            SyncCandidate::Excluded => {
                 stats.skipped += 1;
+                continue;
             }
-            Err(e) => {
-                tracing::warn!("Failed to copy untracked file {}: {}", file.display(), e);
-                stats.errors += 1;
-            }
-        }
-    }
-
-    // Get unstaged modified files
-    let modified = get_unstaged_modified_files(repo_root)?;
-    for file in modified {
-        if should_exclude(&file) {
-            stats.skipped += 1;
-            continue;
-        }
+            SyncCandidate::Untracked(file) => (file, "untracked", &mut stats.untracked_copied),
+            SyncCandidate::Modified(file) => (file, "modified", &mut stats.modified_copied),
+        };
         match copy_file_with_structure(repo_root, worktree_path, &file) {
             Ok(true) => {
-                tracing::trace!("Copied modified file: {}", file.display());
-                stats.modified_copied += 1;
+                tracing::trace!("Copied {} file: {}", kind, file.display());
+                *copied += 1;
             }
             Ok(false) => {
                 stats.skipped += 1;
             }
             Err(e) => {
-                tracing::warn!("Failed to copy modified file {}: {}", file.display(), e);
+                tracing::warn!("Failed to copy {} file {}: {}", kind, file.display(), e);
                 stats.errors += 1;
             }
         }
@@ -669,6 +658,112 @@ pub fn sync_working_directory_to_worktree(
     Ok(stats)
 }
 
+/// Preview [`sync_working_directory_to_worktree`] without touching the filesystem.
+///
+/// Uses the same file selection and exclusions as the real sync, then
+/// classifies each file by whether it exists in the source and the worktree.
+/// Untracked files in the worktree that the working directory lacks are
+/// reported in [`SyncPlan::to_delete`] as well.
+pub fn sync_working_directory_to_worktree_dry_run(
+    repo_root: &Path,
+    worktree_path: &Path,
+    config: &WorktreeConfig,
+) -> Result<SyncPlan, WorktreeError> {
+    let mut plan = SyncPlan::default();
+
+    for candidate in sync_candidates(repo_root, config)? {
+        let (file, copied) = match candidate {
+            SyncCandidate::Excluded => {
+                plan.stats.skipped += 1;
+                continue;
+            }
+            SyncCandidate::Untracked(file) => (file, &mut plan.stats.untracked_copied),
+            SyncCandidate::Modified(file) => (file, &mut plan.stats.modified_copied),
+        };
+        let in_source = path_present(&repo_root.join(&file));
+        let in_worktree = path_present(&worktree_path.join(&file));
+
+        if !in_source {
+            plan.stats.skipped += 1;
+            if in_worktree {
+                plan.to_delete.push(file);
+            }
+            continue;
+        }
+
+        *copied += 1;
+        if in_worktree {
+            plan.to_overwrite.push(file);
+        } else {
+            plan.to_create.push(file);
+        }
+    }
+
+    // Extra files that only exist in the worktree
+    for file in get_untracked_files(worktree_path)? {
+        if !is_sync_excluded(&file, &config.worktree_dir)
+            && !path_present(&repo_root.join(&file))
+            && !plan.to_delete.contains(&file)
+        {
+            plan.to_delete.push(file);
+        }
+    }
+
+    Ok(plan)
+}
+
+/// A working-directory file considered by the worktree sync.
+enum SyncCandidate {
+    /// Untracked file that will be copied.
+    Untracked(PathBuf),
+    /// Tracked file with unstaged modifications that will be copied.
+    Modified(PathBuf),
+    /// Path under `.git` or the worktree directory, never copied.
+    Excluded,
+}
+
+/// Lists the files the sync processes, untracked first, in sync order.
+fn sync_candidates(
+    repo_root: &Path,
+    config: &WorktreeConfig,
+) -> Result<Vec<SyncCandidate>, WorktreeError> {
+    let classify = |file: PathBuf, copy: fn(PathBuf) -> SyncCandidate| {
+        if is_sync_excluded(&file, &config.worktree_dir) {
+            SyncCandidate::Excluded
+        } else {
+            copy(file)
+        }
+    };
+
+    let mut candidates: Vec<SyncCandidate> = get_untracked_files(repo_root)?
+        .into_iter()
+        .map(|file| classify(file, SyncCandidate::Untracked))
+        .collect();
+    candidates.extend(
+        get_unstaged_modified_files(repo_root)?
+            .into_iter()
+            .map(|file| classify(file, SyncCandidate::Modified)),
+    );
+    Ok(candidates)
+}
+
+/// Returns true if `path` exists, counting dangling symlinks.
+fn path_present(path: &Path) -> bool {
+    path.exists() || path.is_symlink()
+}
+
+/// Returns true if a repo-relative path must never be synced into a worktree:
+/// the `.git` directory and the worktree directory itself.
+fn is_sync_excluded(path: &Path, worktree_dir: &Path) -> bool {
+    let path_str = path.to_string_lossy();
+    if path_str.starts_with(".git/") || path_str == ".git" {
+        return true;
+    }
+    let worktree_dir_str = worktree_dir.to_string_lossy();
+    path_str.starts_with(&*worktree_dir_str)
+        || path_str.starts_with(&format!("{}/", worktree_dir_str))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1000,6 +1095,86 @@ branch refs/heads/ralph/loop-1
         );
     }
 
+    #[test]
+    fn test_sync_dry_run_classifies_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        init_git_repo(temp_dir.path());
+        fs::write(temp_dir.path().join("notes.txt"), "notes").unwrap();
+        Command::new("git")
+            .args(["add", "notes.txt"])
+            .current_dir(temp_dir.path())
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["commit", "-m", "Add notes"])
+            .current_dir(temp_dir.path())
+            .output()
+            .unwrap();
+
+        let config = WorktreeConfig::default();
+        let worktree = create_worktree(temp_dir.path(), "sync-dry-run", &config).unwrap();
+
+        fs::write(temp_dir.path().join("README.md"), "# Modified").unwrap();
+        fs::write(temp_dir.path().join("new.txt"), "new").unwrap();
+        fs::remove_file(temp_dir.path().join("notes.txt")).unwrap();
+
+        let plan =
+            sync_working_directory_to_worktree_dry_run(temp_dir.path(), &worktree.path, &config)
+                .unwrap();
+
+        assert_eq!(plan.to_create, vec![PathBuf::from("new.txt")]);
+        assert_eq!(plan.to_overwrite, vec![PathBuf::from("README.md")]);
+        assert_eq!(plan.to_delete, vec![PathBuf::from("notes.txt")]);
+
+        // Nothing was touched
+        assert!(!worktree.path.join("new.txt").exists());
+        assert_eq!(
+            fs::read_to_string(worktree.path.join("README.md")).unwrap(),
+            "# Test"
+        );
+
+        // Skipped: the deleted notes.txt and the excluded .worktrees/ directory
+        let stats = SyncStats::from_plan(&plan);
+        assert_eq!(stats.untracked_copied, 1);
+        assert_eq!(stats.modified_copied, 1);
+        assert_eq!(stats.skipped, 2);
+    }
+
+    #[test]
+    fn test_sync_dry_run_reports_worktree_extras_and_matches_real_sync() {
+        let temp_dir = TempDir::new().unwrap();
+        init_git_repo(temp_dir.path());
+
+        let config = WorktreeConfig::default();
+        let worktree = create_worktree(temp_dir.path(), "sync-extra", &config).unwrap();
+
+        // The worktree has one extra file and the source one modified file
+        fs::write(worktree.path.join("extra.txt"), "only here").unwrap();
+        fs::write(temp_dir.path().join("README.md"), "# Modified").unwrap();
+        // An untracked file already present in the worktree
+        fs::write(temp_dir.path().join("wip.txt"), "wip v2").unwrap();
+        fs::write(worktree.path.join("wip.txt"), "wip v1").unwrap();
+
+        let plan =
+            sync_working_directory_to_worktree_dry_run(temp_dir.path(), &worktree.path, &config)
+                .unwrap();
+
+        assert!(plan.to_create.is_empty());
+        assert_eq!(
+            plan.to_overwrite,
+            vec![PathBuf::from("wip.txt"), PathBuf::from("README.md")]
+        );
+        assert_eq!(plan.to_delete, vec![PathBuf::from("extra.txt")]);
+
+        // The preview reports what the real sync then reports
+        let stats =
+            sync_working_directory_to_worktree(temp_dir.path(), &worktree.path, &config).unwrap();
+        assert_eq!(SyncStats::from_plan(&plan), stats);
+        assert_eq!(stats.untracked_copied, 1);
+        assert_eq!(stats.modified_copied, 1);
+        assert!(worktree.path.join("extra.txt").exists());
+    }
+
     #[test]
     fn test_sync_respects_gitignore() {
         let temp_dir = TempDir::new().unwrap();
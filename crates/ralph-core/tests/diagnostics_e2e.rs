@@ -84,6 +84,7 @@ fn test_diagnostics_files_contain_valid_jsonl() {
             },
         );
     }
+    collector.flush();
 
     // Verify each file contains valid JSONL (3 lines each)
     let files = vec!["orchestration.jsonl", "performance.jsonl", "errors.jsonl"];
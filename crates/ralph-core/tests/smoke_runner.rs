@@ -1,7 +1,9 @@
 #![cfg(feature = "recording")]
 //! Integration tests for the smoke test replay runner.
 
-use ralph_core::testing::{SmokeRunner, SmokeTestConfig, TerminationReason, list_fixtures};
+use ralph_core::testing::{
+    SmokeRunner, SmokeTestConfig, SmokeTestError, TerminationReason, list_fixtures,
+};
 use std::path::PathBuf;
 
 /// Returns the path to the test fixtures directory.
@@ -172,6 +174,46 @@ fn test_all_discovered_fixtures_are_valid() {
     }
 }
 
+#[test]
+fn test_expected_topics_pass_on_basic_session() {
+    let config = SmokeTestConfig::new(fixtures_dir().join("basic_session.jsonl"))
+        .expect_topics(vec!["build.task".to_string(), "build.done".to_string()]);
+
+    let result = SmokeRunner::run(&config).expect("Expected topics should be emitted");
+    assert!(result.completed_successfully());
+}
+
+#[test]
+fn test_expected_topics_fail_when_topic_missing() {
+    let config = SmokeTestConfig::new(fixtures_dir().join("basic_session.jsonl"))
+        .expect_topics(vec!["build.task".to_string(), "build.complete".to_string()]);
+
+    match SmokeRunner::run(&config) {
+        Err(SmokeTestError::UnexpectedTopics { missing, extra }) => {
+            assert_eq!(missing, vec!["build.complete"]);
+            assert!(extra.contains(&"build.done".to_string()));
+        }
+        other => panic!("Expected UnexpectedTopics, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_run_all_reports_json_per_fixture() {
+    let results = SmokeRunner::run_all(fixtures_dir()).expect("Should run all fixtures");
+    assert_eq!(results.len(), list_fixtures(fixtures_dir()).unwrap().len());
+
+    let basic = results
+        .iter()
+        .find(|r| r.fixture().ends_with("basic_session.jsonl"))
+        .expect("basic_session.jsonl should be run");
+    let json = basic.to_json();
+    let serialized = json.to_string();
+
+    assert!(serialized.contains(r#""termination_reason":"completed""#));
+    assert!(json["event_count"].as_u64().unwrap() > 0);
+    assert_eq!(json["completed"], true);
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // REGRESSION DETECTION TESTS
 // These tests prove the smoke test infrastructure catches bugs and regressions.
@@ -142,6 +142,23 @@ impl EventBus {
         self.pending.remove(hat_id).unwrap_or_default()
     }
 
+    /// Takes a hat's pending events for which `take` returns true.
+    ///
+    /// `take` is called on each event oldest first. Events it rejects stay
+    /// pending, in order, for a later call.
+    pub fn take_pending_where(
+        &mut self,
+        hat_id: &HatId,
+        mut take: impl FnMut(&Event) -> bool,
+    ) -> Vec<Event> {
+        let Some(pending) = self.pending.get_mut(hat_id) else {
+            return Vec::new();
+        };
+        let (taken, kept) = std::mem::take(pending).into_iter().partition(|e| take(e));
+        *pending = kept;
+        taken
+    }
+
     /// Takes all pending human interaction events.
     pub fn take_human_pending(&mut self) -> Vec<Event> {
         std::mem::take(&mut self.human_pending)
@@ -190,6 +207,29 @@ impl EventBus {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_take_pending_where_leaves_remainder() {
+        let mut bus = EventBus::new();
+        bus.register(Hat::new("impl", "Implementer").subscribe("task.*"));
+        for i in 0..4 {
+            bus.publish(Event::new("task.start", format!("task {i}")));
+        }
+
+        let hat_id = HatId::new("impl");
+        let even = bus.take_pending_where(&hat_id, |e| {
+            e.payload.ends_with('0') || e.payload.ends_with('2')
+        });
+        assert_eq!(even.len(), 2);
+        assert_eq!(even[0].payload, "task 0");
+        assert_eq!(even[1].payload, "task 2");
+
+        let rest = bus.take_pending_where(&hat_id, |_| true);
+        assert_eq!(rest.len(), 2);
+        assert_eq!(rest[0].payload, "task 1");
+        assert_eq!(rest[1].payload, "task 3");
+        assert!(!bus.has_pending());
+    }
+
     #[test]
     fn test_publish_to_subscriber() {
         let mut bus = EventBus::new();
@@ -159,8 +159,18 @@ impl Hat {
     /// Checks if this hat is subscribed to the given topic string.
     ///
     /// Zero-allocation variant of `is_subscribed()` for hot paths.
+    ///
+    /// Negated subscriptions (`!pattern`) are evaluated after positive ones:
+    /// a topic matches when any positive pattern matches it and no negated
+    /// pattern excludes it, so `["*", "!human.*"]` means "all except human
+    /// topics" regardless of order.
     pub fn is_subscribed_str(&self, topic: &str) -> bool {
-        self.subscriptions.iter().any(|sub| sub.matches_str(topic))
+        self.subscriptions.iter().any(|sub| sub.matches_str(topic)) && !self.is_excluded(topic)
+    }
+
+    /// Returns true if a negated subscription excludes the topic.
+    fn is_excluded(&self, topic: &str) -> bool {
+        self.subscriptions.iter().any(|sub| sub.excludes_str(topic))
     }
 
     /// Checks if this hat has a specific (non-global-wildcard) subscription for the topic.
@@ -172,13 +182,16 @@ impl Hat {
         self.subscriptions
             .iter()
             .any(|sub| !sub.is_global_wildcard() && sub.matches(topic))
+            && !self.is_excluded(topic.as_str())
     }
 
-    /// Returns true if all subscriptions are global wildcards (`*`).
+    /// Returns true if all positive subscriptions are global wildcards (`*`).
     ///
-    /// Used to identify fallback handlers like Ralph.
+    /// Negated subscriptions are ignored. Used to identify fallback handlers
+    /// like Ralph.
     pub fn is_fallback_only(&self) -> bool {
-        !self.subscriptions.is_empty() && self.subscriptions.iter().all(Topic::is_global_wildcard)
+        let mut positives = self.subscriptions.iter().filter(|sub| !sub.is_negation());
+        positives.clone().next().is_some() && positives.all(Topic::is_global_wildcard)
     }
 }
 
@@ -186,6 +199,30 @@ impl Hat {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_negated_subscriptions() {
+        let hat = Hat::new("builder", "Builder")
+            .subscribe("build.*")
+            .subscribe("!build.blocked");
+        assert!(hat.is_subscribed(&Topic::new("build.done")));
+        assert!(!hat.is_subscribed(&Topic::new("build.blocked")));
+        assert!(hat.has_specific_subscription(&Topic::new("build.done")));
+        assert!(!hat.has_specific_subscription(&Topic::new("build.blocked")));
+
+        // Negations apply after positives regardless of order
+        let catch_all = Hat::new("watcher", "Watcher")
+            .subscribe("!human.*")
+            .subscribe("*");
+        assert!(catch_all.is_subscribed(&Topic::new("build.done")));
+        assert!(!catch_all.is_subscribed(&Topic::new("human.interact")));
+        assert!(catch_all.is_fallback_only());
+
+        // Only negations: matches nothing and is not a fallback
+        let only_negations = Hat::new("none", "None").subscribe("!human.*");
+        assert!(!only_negations.is_subscribed(&Topic::new("build.done")));
+        assert!(!only_negations.is_fallback_only());
+    }
+
     #[test]
     fn test_subscription_matching() {
         let hat = Hat::new("impl", "Implementer")
@@ -26,5 +26,6 @@ pub use hat::{Hat, HatId};
 pub use robot::{CheckinContext, RobotService};
 pub use topic::Topic;
 pub use ux_event::{
-    FrameCapture, TerminalColorMode, TerminalResize, TerminalWrite, TuiFrame, UxEvent,
+    CellChange, FrameCapture, FrameDiff, TerminalColorMode, TerminalResize, TerminalWrite,
+    TuiFrame, UxEvent,
 };
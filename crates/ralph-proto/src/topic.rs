@@ -1,7 +1,8 @@
 //! Topic types for event routing.
 //!
 //! Topics are routing keys used to match events to subscribers.
-//! Supports glob-style patterns like `impl.*` to match `impl.done`.
+//! Supports glob-style patterns like `impl.*` to match `impl.done`, and
+//! negated patterns like `!human.*` that exclude topics from a subscription.
 
 use serde::{Deserialize, Serialize};
 
@@ -30,12 +31,30 @@ impl Topic {
         self.0 == "*"
     }
 
+    /// Returns true if this is a negated pattern (e.g. `!human.*`).
+    ///
+    /// Negated patterns never match on their own; they exclude topics that
+    /// a hat's positive subscriptions would otherwise match.
+    pub fn is_negation(&self) -> bool {
+        self.0.starts_with('!')
+    }
+
+    /// Checks if this negated pattern excludes the given topic string.
+    ///
+    /// Returns false for non-negated patterns.
+    pub fn excludes_str(&self, target: &str) -> bool {
+        self.0
+            .strip_prefix('!')
+            .is_some_and(|pattern| Topic::new(pattern).matches_str(target))
+    }
+
     /// Checks if this topic pattern matches a given topic.
     ///
     /// Pattern rules:
     /// - `*` matches any single segment (e.g., `impl.*` matches `impl.done`)
     /// - Exact match for non-pattern topics
     /// - A single `*` matches everything
+    /// - Negated patterns (`!…`) never match; see [`Topic::excludes_str`]
     pub fn matches(&self, topic: &Topic) -> bool {
         self.matches_str(topic.as_str())
     }
@@ -54,7 +73,11 @@ impl Topic {
 
         // Exact match (most common case for non-wildcard patterns)
         if pattern == target {
-            return true;
+            return !self.is_negation();
+        }
+
+        if self.is_negation() {
+            return false;
         }
 
         // Quick length check: if no wildcards and lengths differ, can't match
@@ -102,6 +125,18 @@ impl std::fmt::Display for Topic {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_negation_excludes_but_never_matches() {
+        let negated = Topic::new("!human.*");
+        assert!(negated.is_negation());
+        assert!(negated.excludes_str("human.interact"));
+        assert!(!negated.excludes_str("build.done"));
+        assert!(!negated.matches_str("human.interact"));
+        assert!(!negated.matches_str("!human.*"));
+
+        assert!(!Topic::new("human.*").excludes_str("human.interact"));
+    }
+
     #[test]
     fn test_exact_match() {
         let pattern = Topic::new("impl.done");
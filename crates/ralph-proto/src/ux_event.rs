@@ -146,6 +146,94 @@ impl TuiFrame {
             offset_ms,
         }
     }
+
+    /// Compares this frame with `other`, cell by cell.
+    ///
+    /// `cells` is read as newline-separated rows of plain text; a cell present
+    /// in only one frame is reported with `None` on the other side.
+    pub fn diff(&self, other: &TuiFrame) -> FrameDiff {
+        let before: Vec<Vec<char>> = self.cells.lines().map(|r| r.chars().collect()).collect();
+        let after: Vec<Vec<char>> = other.cells.lines().map(|r| r.chars().collect()).collect();
+        let empty = Vec::new();
+
+        let mut changes = Vec::new();
+        for row in 0..before.len().max(after.len()) {
+            let old = before.get(row).unwrap_or(&empty);
+            let new = after.get(row).unwrap_or(&empty);
+            for col in 0..old.len().max(new.len()) {
+                let (old_cell, new_cell) = (old.get(col).copied(), new.get(col).copied());
+                if old_cell != new_cell {
+                    changes.push(CellChange {
+                        row,
+                        col,
+                        before: old_cell,
+                        after: new_cell,
+                    });
+                }
+            }
+        }
+
+        FrameDiff {
+            size_changed: (self.width, self.height) != (other.width, other.height),
+            changes,
+        }
+    }
+}
+
+/// A single cell that differs between two frames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellChange {
+    /// Zero-based row index.
+    pub row: usize,
+    /// Zero-based column index.
+    pub col: usize,
+    /// Cell content in the first frame, if present.
+    pub before: Option<char>,
+    /// Cell content in the second frame, if present.
+    pub after: Option<char>,
+}
+
+/// Differences between two TUI frames, produced by [`TuiFrame::diff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FrameDiff {
+    /// True if the frame dimensions differ.
+    pub size_changed: bool,
+    /// Changed cells, in row-major order.
+    pub changes: Vec<CellChange>,
+}
+
+impl FrameDiff {
+    /// Returns true if the frames are identical.
+    pub fn is_empty(&self) -> bool {
+        !self.size_changed && self.changes.is_empty()
+    }
+
+    /// Returns the indices of rows containing at least one changed cell.
+    pub fn changed_rows(&self) -> Vec<usize> {
+        let mut rows: Vec<usize> = self.changes.iter().map(|c| c.row).collect();
+        rows.dedup();
+        rows
+    }
+}
+
+impl std::fmt::Display for FrameDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.size_changed {
+            writeln!(f, "frame size changed")?;
+        }
+        let show = |cell: Option<char>| cell.map_or_else(|| "∅".to_string(), |c| format!("{c:?}"));
+        for change in &self.changes {
+            writeln!(
+                f,
+                "row {}, col {}: {} -> {}",
+                change.row,
+                change.col,
+                show(change.before),
+                show(change.after)
+            )?;
+        }
+        Ok(())
+    }
 }
 
 /// Abstract interface for capturing rendered output.
@@ -192,6 +280,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_frame_diff_identical_frames_is_empty() {
+        let frame = TuiFrame::new(1, 5, 2, "hello\nworld".to_string(), 0);
+        let diff = frame.diff(&frame.clone());
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "");
+    }
+
+    #[test]
+    fn test_frame_diff_reports_single_cell_change() {
+        let before = TuiFrame::new(1, 5, 2, "hello\nworld".to_string(), 0);
+        let after = TuiFrame::new(2, 5, 2, "hello\nwOrld".to_string(), 16);
+        let diff = before.diff(&after);
+
+        assert_eq!(
+            diff.changes,
+            vec![CellChange {
+                row: 1,
+                col: 1,
+                before: Some('o'),
+                after: Some('O'),
+            }]
+        );
+        assert!(!diff.size_changed);
+        assert_eq!(diff.changed_rows(), vec![1]);
+        assert_eq!(diff.to_string(), "row 1, col 1: 'o' -> 'O'\n");
+    }
+
     #[test]
     fn test_terminal_resize_serialization() {
         let event = UxEvent::TerminalResize(TerminalResize::new(120, 30, 500));